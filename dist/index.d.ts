@@ -16,48 +16,753 @@ export type DataSegmented = {
     date: string
 }
 
+export type CloudPosition = 'above' | 'inside' | 'below'
+
+export type IchimokuSignals = {
+    cloud_position: (CloudPosition | null)[]
+    chikou_confirmation: number[]
+    tk_crosses: { index: number, kind: 'bullish' | 'bearish', strength: 'strong' | 'neutral' | 'weak' }[]
+    kumo_breakouts: { index: number, kind: 'bullish' | 'bearish' }[]
+    kumo_twists: { index: number, kind: 'bullish' | 'bearish' }[]
+}
+
 export type IchimokuResponse = {
     tenkan_sen: number[]
     kijun_sen: number[]
     senkou_span_a: number[]
     senkou_span_b: number[]
     chikou_span: number[]
+    future_dates: string[]
+    signals: IchimokuSignals
+}
+
+export type BollingerBandsOptions = {
+    period?: number
+    multiplier?: number
+    upper_multiplier?: number
+    lower_multiplier?: number
+    ma_type?: MovingAverageType
+    deviation?: 'population' | 'sample'
+    squeeze_period?: number
 }
 
 export type BollingerBandResult = {
     middle: number[]
     upper: number[]
     lower: number[]
+    percent_b: number[]
+    bandwidth: number[]
+    squeeze: boolean[]
+}
+
+export type BollingerBandLines = {
+    middle: number[]
+    upper: number[]
+    lower: number[]
+}
+
+export type VolumeFlowResult = {
+    values: number[]
+    signal: number[] | null
+}
+
+export declare class VolumeFlowPoint {
+    readonly value: number
+    readonly signal: number
+    free(): void
+}
+
+export declare class OnBalanceVolumeStream {
+    constructor(signalPeriod?: number, signalType?: MovingAverageType)
+    next(close: number, volume: number): VolumeFlowPoint
+    free(): void
+}
+
+export declare class AccumulationDistributionStream {
+    constructor(signalPeriod?: number, signalType?: MovingAverageType)
+    next(high: number, low: number, close: number, volume: number): VolumeFlowPoint
+    free(): void
+}
+
+export declare class ChaikinMoneyFlowStream {
+    constructor(period?: number, signalPeriod?: number, signalType?: MovingAverageType)
+    next(high: number, low: number, close: number, volume: number): VolumeFlowPoint
+    free(): void
+}
+
+export declare class ChaikinOscillatorStream {
+    constructor(fastPeriod?: number, slowPeriod?: number, signalPeriod?: number, signalType?: MovingAverageType)
+    next(high: number, low: number, close: number, volume: number): VolumeFlowPoint
+    free(): void
+}
+
+export declare class PriceVolumeTrendStream {
+    constructor(signalPeriod?: number, signalType?: MovingAverageType)
+    next(close: number, volume: number): VolumeFlowPoint
+    free(): void
+}
+
+export type SmoothedSeriesResult = {
+    raw: number[]
+    smoothed: number[]
+}
+
+export type KlingerOscillatorResult = {
+    kvo: number[]
+    signal: number[]
+}
+
+export type PercentageVolumeOscillatorResult = {
+    pvo: number[]
+    signal: number[]
+    histogram: number[]
+}
+
+export type VolumeProfileOptions = {
+    bin_count?: number
+    bin_size?: number
+    value_area?: number
+    distribution?: 'uniform' | 'close_weighted'
+    session_offset_minutes?: number
+}
+
+export type VolumeProfileBin = {
+    price_low: number
+    price_high: number
+    volume: number
+}
+
+export type VolumeProfile = {
+    start_index: number
+    end_index: number
+    start_date: string
+    end_date: string
+    bin_size: number
+    total_volume: number
+    point_of_control: number
+    value_area_high: number
+    value_area_low: number
+    high_volume_nodes: number[]
+    low_volume_nodes: number[]
+    bins: VolumeProfileBin[]
+}
+
+export type RollingVolumeProfileResult = {
+    point_of_control: number[]
+    value_area_high: number[]
+    value_area_low: number[]
+}
+
+export type MarketProfileOptions = {
+    tick_size: number
+    period_minutes?: number
+    initial_balance_periods?: number
+    value_area?: number
+    session_offset_minutes?: number
+    letters?: string
+}
+
+export type TpoLevel = {
+    price: number
+    letters: string
+    tpo_count: number
+    single_print: boolean
+    in_value_area: boolean
+    in_initial_balance: boolean
+}
+
+export type TpoProfile = {
+    start_index: number
+    end_index: number
+    start_date: string
+    open: number
+    close: number
+    high: number
+    low: number
+    point_of_control: number
+    value_area_high: number
+    value_area_low: number
+    initial_balance_high: number
+    initial_balance_low: number
+    poor_high: boolean
+    poor_low: boolean
+    single_prints: { low: number, high: number }[]
+    periods: { letter: string, start_date: string, high: number, low: number }[]
+    levels: TpoLevel[]
+}
+
+export type MovingAverageType =
+    | 'sma' | 'ema' | 'wma' | 'smma' | 'rma' | 'dema' | 'tema' | 'trima'
+    | 'hma' | 'zlema' | 'alma' | 'lsma' | 't3' | 'vwma'
+
+export type MovingAverageOptions = {
+    alma_offset?: number
+    alma_sigma?: number
+    t3_volume_factor?: number
+}
+
+export declare class MovingAverageStream {
+    constructor(period: number, kind?: MovingAverageType, options?: MovingAverageOptions)
+    next(value: number, volume?: number): number
+    free(): void
+}
+
+export type MacdResult = {
+    macd: number[]
+    signal: number[]
+    histogram: number[]
+}
+
+export type AdaptiveMovingAverageResult = {
+    values: number[]
+    alpha: number[]
+}
+
+export type KamaResult = {
+    values: number[]
+    efficiency_ratio: number[]
+    alpha: number[]
+}
+
+export type LevelCrossing = {
+    index: number
+    kind: 'enter_overbought' | 'exit_overbought' | 'enter_oversold' | 'exit_oversold'
+    value: number
+}
+
+export type StochasticOptions = {
+    k_period?: number
+    k_smoothing?: number
+    d_period?: number
+    ma_type?: MovingAverageType
+    overbought?: number
+    oversold?: number
+}
+
+export type StochasticResult = {
+    k: number[]
+    d: number[]
+    events: LevelCrossing[]
+}
+
+export type PriceSource = 'open' | 'high' | 'low' | 'close' | 'hl2' | 'hlc3' | 'ohlc4' | 'hlcc4'
+
+export type WilliamsROptions = {
+    period?: number
+    source?: PriceSource
+    signal_period?: number
+    signal_type?: MovingAverageType
+    overbought?: number
+    oversold?: number
+}
+
+export type CciOptions = {
+    period?: number
+    source?: PriceSource
+    constant?: number
+    signal_period?: number
+    signal_type?: MovingAverageType
+    overbought?: number
+    oversold?: number
+}
+
+export type OscillatorResult = {
+    values: number[]
+    signal: number[] | null
+    events: LevelCrossing[]
+}
+
+export type LineCrossing = {
+    index: number
+    kind: 'bullish' | 'bearish'
+}
+
+export type AroonResult = {
+    up: number[]
+    down: number[]
+    oscillator: number[]
+    events: LineCrossing[]
+}
+
+export type VortexResult = {
+    plus: number[]
+    minus: number[]
+    events: LineCrossing[]
+}
+
+export type SuperTrendOptions = {
+    atr_period?: number
+    multiplier?: number
+    source?: PriceSource
+}
+
+export type SuperTrendResult = {
+    supertrend: number[]
+    direction: number[]
+    flips: number[]
+    upper: number[]
+    lower: number[]
+}
+
+export type PivotMethod = 'classic' | 'fibonacci' | 'camarilla' | 'woodie' | 'demark'
+
+export type PivotTimeframe = 'bar' | 'day' | 'week' | 'month'
+
+export type PivotPointsOptions = {
+    method?: PivotMethod
+    timeframe?: PivotTimeframe
+    session_offset_minutes?: number
+}
+
+export type PivotLevels = {
+    pivot: number
+    r1: number
+    s1: number
+    r2: number | null
+    s2: number | null
+    r3: number | null
+    s3: number | null
+    r4: number | null
+    s4: number | null
+}
+
+export type PivotPointsBar = {
+    date: string
+    levels: PivotLevels | null
+    developing: PivotLevels
+}
+
+export type FibonacciOptions = {
+    start?: number
+    end?: number
+    window?: number
+    retracements?: number[]
+    extensions?: number[]
+    tolerance?: number
+}
+
+export type SwingAnchor = {
+    index: number
+    price: number
+    date: string
+}
+
+export type FibonacciLevel = {
+    ratio: number
+    price: number
+}
+
+export type LevelReaction = {
+    index: number
+    ratio: number
+    price: number
+    kind: 'support' | 'resistance'
+}
+
+export type FibonacciResult = {
+    start: SwingAnchor
+    end: SwingAnchor
+    direction: number
+    retracements: FibonacciLevel[]
+    extensions: FibonacciLevel[]
+    time_zones: number[]
+    reactions: LevelReaction[]
+}
+
+export type SwingKind = 'high' | 'low'
+
+export type SwingLabel = 'HH' | 'LH' | 'HL' | 'LL'
+
+export type ZigZagOptions = {
+    mode?: 'percent' | 'absolute' | 'atr'
+    threshold?: number
+    atr_period?: number
+}
+
+export type ZigZagPivot = {
+    index: number
+    date: string
+    price: number
+    kind: SwingKind
+    label: SwingLabel | null
+    confirmed: boolean
+    confirmed_at: number | null
+}
+
+export type SwingPointsOptions = {
+    left?: number
+    right?: number
+    strict?: boolean
+    source?: PriceSource
+}
+
+export type SwingPoint = {
+    index: number
+    date: string
+    price: number
+    kind: SwingKind
+}
+
+export type ImportantLevels = {
+    highest_resistance: number
+    lowest_support: number
+    average_pivot: number
+    supports: SwingPoint[]
+    resistances: SwingPoint[]
+}
+
+export type SupportResistanceOptions = {
+    left?: number
+    right?: number
+    strict?: boolean
+    tolerance_mode?: 'percent' | 'absolute' | 'atr'
+    tolerance?: number
+    atr_period?: number
+    max_zones?: number
+}
+
+export type SupportResistanceZone = {
+    low: number
+    high: number
+    touches: number
+    first_touch: string
+    last_touch: string
+    volume: number
+    role: 'support' | 'resistance'
+    flipped: boolean
+    strength: number
+    distance: number
+}
+
+export type TrendlineOptions = {
+    left?: number
+    right?: number
+    strict?: boolean
+    min_touches?: number
+    tolerance?: number
+    max_anchor_gap?: number
+}
+
+export type Trendline = {
+    kind: 'support' | 'resistance'
+    slope: number
+    intercept: number
+    start: number
+    end: number
+    touches: number
+    touch_indices: number[]
+    broken: LineCrossing | null
+}
+
+export type TrendChannel = {
+    line: number
+    slope: number
+    intercept: number
+    touches: number
+    touch_indices: number[]
+}
+
+export type TrendlineResult = {
+    lines: Trendline[]
+    channels: TrendChannel[]
+}
+
+export type CandlestickOptions = {
+    doji_body_ratio?: number
+    long_shadow_ratio?: number
+    short_shadow_ratio?: number
+    tweezer_tolerance?: number
+    trend_period?: number
+    trend_type?: MovingAverageType
+}
+
+export type CandlestickPattern = 'doji' | 'dragonfly_doji' | 'gravestone_doji' | 'long_legged_doji' | 'hammer' | 'hanging_man'
+    | 'inverted_hammer' | 'shooting_star' | 'marubozu' | 'engulfing' | 'harami' | 'piercing_line' | 'dark_cloud_cover'
+    | 'tweezer_top' | 'tweezer_bottom' | 'inside_bar' | 'outside_bar' | 'morning_star' | 'evening_star'
+    | 'three_white_soldiers' | 'three_black_crows'
+
+export type PatternDirection = 'bullish' | 'bearish' | 'neutral'
+
+export type CandlestickHit = {
+    index: number
+    pattern: CandlestickPattern
+    direction: PatternDirection
+    bars: number
+    confidence: number
+}
+
+export type ChartPatternOptions = {
+    left?: number
+    right?: number
+    strict?: boolean
+    tolerance?: number
+    shoulder_tolerance?: number
+    pole_ratio?: number
+}
+
+export type ChartPattern = 'double_top' | 'double_bottom' | 'triple_top' | 'triple_bottom' | 'head_and_shoulders'
+    | 'inverse_head_and_shoulders' | 'ascending_triangle' | 'descending_triangle' | 'symmetrical_triangle'
+    | 'rising_wedge' | 'falling_wedge' | 'flag' | 'pennant'
+
+export type PatternLine = {
+    slope: number
+    intercept: number
+    start: number
+    end: number
+}
+
+export type ChartPatternHit = {
+    pattern: ChartPattern
+    direction: PatternDirection
+    pivots: SwingPoint[]
+    upper: PatternLine
+    lower: PatternLine
+    breakout: number | null
+    confirmed: boolean
+    target: number | null
+}
+
+export type HarmonicOptions = {
+    mode?: 'percent' | 'absolute' | 'atr'
+    threshold?: number
+    atr_period?: number
+    tolerance?: number
+}
+
+export type HarmonicRatio = {
+    leg: string
+    value: number
+    min: number
+    max: number
+    error: number
+}
+
+export type HarmonicHit = {
+    pattern: 'gartley' | 'bat' | 'butterfly' | 'crab' | 'cypher'
+    direction: PatternDirection
+    points: ZigZagPivot[]
+    ratios: HarmonicRatio[]
+    error: number
+    prz_low: number
+    prz_high: number
+}
+
+export type DivergenceOptions = {
+    left?: number
+    right?: number
+    max_offset?: number
+    min_span?: number
+    max_span?: number
+    hidden?: boolean
+}
+
+export type DivergencePivot = {
+    index: number
+    value: number
+}
+
+export type Divergence = {
+    kind: 'regular_bullish' | 'regular_bearish' | 'hidden_bullish' | 'hidden_bearish'
+    price: [DivergencePivot, DivergencePivot]
+    oscillator: [DivergencePivot, DivergencePivot]
+    span: number
+    strength: number
+}
+
+export type RollingStatistic =
+    | 'sum' | 'mean' | 'variance' | 'std' | 'z_score' | 'skewness' | 'kurtosis'
+    | 'min' | 'max' | 'median' | 'quantile' | 'percent_rank'
+
+export type RollingStatisticOptions = {
+    statistic?: RollingStatistic
+    period?: number
+    quantile?: number
+    deviation?: 'population' | 'sample'
+}
+
+export type LinearRegressionOptions = {
+    period?: number
+    source?: PriceSource
+}
+
+export type LinearRegressionResult = {
+    slope: number[]
+    intercept: number[]
+    value: number[]
+    forecast: number[]
+    r_squared: number[]
+    standard_error: number[]
+}
+
+export type RegressionChannelOptions = {
+    period?: number
+    source?: PriceSource
+    deviations?: number
+}
+
+export type RegressionChannel = {
+    start: number
+    end: number
+    slope: number
+    intercept: number
+    r_squared: number
+    standard_error: number
+    middle: number[]
+    upper: number[]
+    lower: number[]
+    raff_upper: number[]
+    raff_lower: number[]
+}
+
+export type StandardErrorBandsOptions = {
+    period?: number
+    source?: PriceSource
+    deviations?: number
+    smoothing?: number
+}
+
+export type StandardErrorBandsResult = {
+    middle: number[]
+    upper: number[]
+    lower: number[]
+}
+
+export type VolatilityEstimator = 'close_to_close' | 'parkinson' | 'garman_klass' | 'rogers_satchell' | 'yang_zhang'
+
+export type HistoricalVolatilityOptions = {
+    estimator?: VolatilityEstimator
+    period?: number
+    periods_per_year?: number
+    trading_days?: number
+    rank_period?: number
+}
+
+export type HistoricalVolatilityResult = {
+    values: number[]
+    percentile: number[]
+    rank: number[]
+    periods_per_year: number
 }
 
 export declare class Indicator {
     constructor(data: Data[])
-    ichimoku(tenkan: number, kijun: number, senkou: number): IchimokuResponse;
+    ichimoku(tenkan?: number, kijun?: number, senkou?: number, displacement?: number): IchimokuResponse;
     directionalMovementIndex(period: number): Float64Array;
     relativeStrengthIndex(key: keyof Data, period: number): Float64Array;
-    extractImportantLevels(key: keyof Data): any;
-    bollingerBands(period: number, multiplier: number): BollingerBandResult;
+    extractImportantLevels(options?: SwingPointsOptions): ImportantLevels;
+    bollingerBands(options?: BollingerBandsOptions): BollingerBandResult;
+    /** Former positional form: the three bands without the warm-up. */
+    bollingerBands(period: number, multiplier?: number): BollingerBandLines;
     simpleMovingAverage(key: keyof Data, period: number): Float64Array;
     lowHighOpenCloseVolumeDateToArray(data: Data[]): DataSegmented;
     entryExitSignals(key: keyof Data, sma_period: number, ema_period: number, atr_period: number, threshold: number): any;
     stochasticMomentumIndex(period_k: number, period_d: number): void;
-    stochasticOscillator(period: number): Float64Array;
+    stochasticOscillator(options?: StochasticOptions): StochasticResult;
     trendsMeter(period: number): Float64Array;
+    /** EMA seeded with the first value, so it has no warm-up; `movingAverage(data, period, 'ema')` is SMA-seeded instead. */
     exponentialMovingAverage(period: number): Float64Array;
     renkoChart(brickSize: number): Float64Array;
     kagiChart(reversalAmount: number): any;
+    onBalanceVolume(signalPeriod?: number, signalType?: MovingAverageType): VolumeFlowResult;
+    accumulationDistribution(signalPeriod?: number, signalType?: MovingAverageType): VolumeFlowResult;
+    chaikinMoneyFlow(period?: number, signalPeriod?: number, signalType?: MovingAverageType): VolumeFlowResult;
+    chaikinOscillator(fastPeriod?: number, slowPeriod?: number, signalPeriod?: number, signalType?: MovingAverageType): VolumeFlowResult;
+    priceVolumeTrend(signalPeriod?: number, signalType?: MovingAverageType): VolumeFlowResult;
+    moneyFlowIndex(period?: number): Float64Array;
+    easeOfMovement(period?: number, volumeDivisor?: number): SmoothedSeriesResult;
+    forceIndex(period?: number): SmoothedSeriesResult;
+    klingerVolumeOscillator(fastPeriod?: number, slowPeriod?: number, signalPeriod?: number, signalType?: MovingAverageType): KlingerOscillatorResult;
+    negativeVolumeIndex(signalPeriod?: number, signalType?: MovingAverageType, start?: number): VolumeFlowResult;
+    positiveVolumeIndex(signalPeriod?: number, signalType?: MovingAverageType, start?: number): VolumeFlowResult;
+    percentageVolumeOscillator(fastPeriod?: number, slowPeriod?: number, signalPeriod?: number, signalType?: MovingAverageType): PercentageVolumeOscillatorResult;
+    volumeProfile(options?: VolumeProfileOptions, start?: number, end?: number): VolumeProfile;
+    sessionVolumeProfile(options?: VolumeProfileOptions): VolumeProfile[];
+    rollingVolumeProfile(window?: number, options?: VolumeProfileOptions): RollingVolumeProfileResult;
+    marketProfile(options: MarketProfileOptions): TpoProfile[];
+    /** NaN during the warm-up; its `'ema'` is seeded with the SMA of the first `period` values, unlike `exponentialMovingAverage`. */
+    movingAverage(period: number, kind?: MovingAverageType, options?: MovingAverageOptions): number[];
+    macd(fastPeriod?: number, slowPeriod?: number, signalPeriod?: number, kind?: MovingAverageType, signalKind?: MovingAverageType): MacdResult;
+    fractalAdaptiveMovingAverage(period?: number, fastPeriod?: number, slowPeriod?: number): AdaptiveMovingAverageResult;
+    kaufmanAdaptiveMovingAverage(period?: number, fastPeriod?: number, slowPeriod?: number): KamaResult;
+    variableIndexDynamicAverage(period?: number, cmoPeriod?: number): AdaptiveMovingAverageResult;
+    mcginleyDynamic(period?: number, constant?: number): AdaptiveMovingAverageResult;
+    williamsR(options?: WilliamsROptions): OscillatorResult;
+    commodityChannelIndex(options?: CciOptions): OscillatorResult;
+    aroon(period?: number): AroonResult;
+    vortexIndicator(period?: number): VortexResult;
+    supertrend(options?: SuperTrendOptions): SuperTrendResult;
+    pivotPoints(options?: PivotPointsOptions): PivotPointsBar[];
+    fibonacciLevels(options?: FibonacciOptions): FibonacciResult;
+    zigzag(options?: ZigZagOptions): ZigZagPivot[];
+    supportResistanceZones(options?: SupportResistanceOptions): SupportResistanceZone[];
+    trendlines(options?: TrendlineOptions): TrendlineResult;
+    candlestickPatterns(options?: CandlestickOptions): CandlestickHit[];
+    chartPatterns(options?: ChartPatternOptions): ChartPatternHit[];
+    harmonicPatterns(options?: HarmonicOptions): HarmonicHit[];
+    divergence(oscillator: number[], options?: DivergenceOptions): Divergence[];
+    rollingStatistic(options?: RollingStatisticOptions): number[];
+    linearRegression(options?: LinearRegressionOptions): LinearRegressionResult;
+    linearRegressionChannel(options?: RegressionChannelOptions): RegressionChannel;
+    standardErrorBands(options?: StandardErrorBandsOptions): StandardErrorBandsResult;
+    historicalVolatility(options?: HistoricalVolatilityOptions): HistoricalVolatilityResult;
 }
-export declare function ichimoku(data: Data[], tenkan: number, kijun: number, senkou: number): IchimokuResponse;
+export declare function ichimoku(data: Data[], tenkan?: number, kijun?: number, senkou?: number, displacement?: number): IchimokuResponse;
 export declare function directionalMovementIndex(data: Data[], period: number): Float64Array;
 export declare function relativeStrengthIndex(data: Data[], period: number): Float64Array;
-export declare function extractImportantLevels(data: Data[]): any;
-export declare function bollingerBands(data: Data[], period: number, multiplier: number): BollingerBandResult;
+export declare function extractImportantLevels(data: Data[], options?: SwingPointsOptions): ImportantLevels;
+export declare function bollingerBands(data: number[], options?: BollingerBandsOptions): BollingerBandResult;
+/** Former positional form: the three bands without the warm-up. */
+export declare function bollingerBands(data: number[], period: number, multiplier?: number): BollingerBandLines;
 export declare function simpleMovingAverage(data: Data[], period: number): Float64Array;
 export declare function lowHighOpenCloseVolumeDateToArray(data: Data[]): DataSegmented;
 export declare function entryExitSignals(data: Data[], sma_period: number, ema_period: number, atr_period: number, threshold: number): any;
 export declare function stochasticMomentumIndex(data: Data[], period_k: number, period_d: number): void;
-export declare function stochasticOscillator(data: Data[], period: number): Float64Array;
+export declare function stochasticOscillator(data: Data[], options?: StochasticOptions): StochasticResult;
 export declare function trendsMeter(data: Data[], period: number): Float64Array;
+/** EMA seeded with the first value, so it has no warm-up; `movingAverage(data, period, 'ema')` is SMA-seeded instead. */
 export declare function exponentialMovingAverage(data: Data[], period: number): Float64Array;
 export declare function renkoChart(data: Data[], brickSize: number): Float64Array;
 export declare function kagiChart(data: Data[], reversalAmount: number): any;
+
+export declare function onBalanceVolume(data: Data[], signalPeriod?: number, signalType?: MovingAverageType): VolumeFlowResult;
+export declare function accumulationDistribution(data: Data[], signalPeriod?: number, signalType?: MovingAverageType): VolumeFlowResult;
+export declare function chaikinMoneyFlow(data: Data[], period?: number, signalPeriod?: number, signalType?: MovingAverageType): VolumeFlowResult;
+export declare function chaikinOscillator(data: Data[], fastPeriod?: number, slowPeriod?: number, signalPeriod?: number, signalType?: MovingAverageType): VolumeFlowResult;
+export declare function priceVolumeTrend(data: Data[], signalPeriod?: number, signalType?: MovingAverageType): VolumeFlowResult;
+export declare function moneyFlowIndex(data: Data[], period?: number): Float64Array;
+export declare function easeOfMovement(data: Data[], period?: number, volumeDivisor?: number): SmoothedSeriesResult;
+export declare function forceIndex(data: Data[], period?: number): SmoothedSeriesResult;
+export declare function klingerVolumeOscillator(data: Data[], fastPeriod?: number, slowPeriod?: number, signalPeriod?: number, signalType?: MovingAverageType): KlingerOscillatorResult;
+export declare function negativeVolumeIndex(data: Data[], signalPeriod?: number, signalType?: MovingAverageType, start?: number): VolumeFlowResult;
+export declare function positiveVolumeIndex(data: Data[], signalPeriod?: number, signalType?: MovingAverageType, start?: number): VolumeFlowResult;
+export declare function percentageVolumeOscillator(data: Data[], fastPeriod?: number, slowPeriod?: number, signalPeriod?: number, signalType?: MovingAverageType): PercentageVolumeOscillatorResult;
+export declare function volumeProfile(data: Data[], options?: VolumeProfileOptions, start?: number, end?: number): VolumeProfile;
+export declare function sessionVolumeProfile(data: Data[], options?: VolumeProfileOptions): VolumeProfile[];
+export declare function rollingVolumeProfile(data: Data[], window?: number, options?: VolumeProfileOptions): RollingVolumeProfileResult;
+export declare function marketProfile(data: Data[], options: MarketProfileOptions): TpoProfile[];
+/** NaN during the warm-up; its `'ema'` is seeded with the SMA of the first `period` values, unlike `exponentialMovingAverage`. */
+export declare function movingAverage(data: number[], period: number, kind?: MovingAverageType, volumes?: number[], options?: MovingAverageOptions): number[];
+export declare function macd(data: number[], fastPeriod?: number, slowPeriod?: number, signalPeriod?: number, kind?: MovingAverageType, signalKind?: MovingAverageType): MacdResult;
+export declare function kaufmanAdaptiveMovingAverage(data: number[], period?: number, fastPeriod?: number, slowPeriod?: number): KamaResult;
+export declare function variableIndexDynamicAverage(data: number[], period?: number, cmoPeriod?: number): AdaptiveMovingAverageResult;
+export declare function fractalAdaptiveMovingAverage(data: Data[], period?: number, fastPeriod?: number, slowPeriod?: number): AdaptiveMovingAverageResult;
+export declare function mcginleyDynamic(data: number[], period?: number, constant?: number): AdaptiveMovingAverageResult;
+export declare function williamsR(data: Data[], options?: WilliamsROptions): OscillatorResult;
+export declare function commodityChannelIndex(data: Data[], options?: CciOptions): OscillatorResult;
+export declare function aroon(data: Data[], period?: number): AroonResult;
+export declare function vortexIndicator(data: Data[], period?: number): VortexResult;
+export declare function supertrend(data: Data[], options?: SuperTrendOptions): SuperTrendResult;
+export declare function pivotPoints(data: Data[], options?: PivotPointsOptions): PivotPointsBar[];
+export declare function fibonacciLevels(data: Data[], options?: FibonacciOptions): FibonacciResult;
+export declare function zigzag(data: Data[], options?: ZigZagOptions): ZigZagPivot[];
+export declare function supportResistanceZones(data: Data[], options?: SupportResistanceOptions): SupportResistanceZone[];
+export declare function trendlines(data: Data[], options?: TrendlineOptions): TrendlineResult;
+export declare function candlestickPatterns(data: Data[], options?: CandlestickOptions): CandlestickHit[];
+export declare function chartPatterns(data: Data[], options?: ChartPatternOptions): ChartPatternHit[];
+export declare function harmonicPatterns(data: Data[], options?: HarmonicOptions): HarmonicHit[];
+export declare function divergence(prices: number[], oscillator: number[], options?: DivergenceOptions): Divergence[];
+export declare function rollingStatistic(data: number[], options?: RollingStatisticOptions): number[];
+export declare function linearRegression(data: Data[], options?: LinearRegressionOptions): LinearRegressionResult;
+export declare function linearRegressionChannel(data: Data[], options?: RegressionChannelOptions): RegressionChannel;
+export declare function standardErrorBands(data: Data[], options?: StandardErrorBandsOptions): StandardErrorBandsResult;
+export declare function historicalVolatility(data: Data[], options?: HistoricalVolatilityOptions): HistoricalVolatilityResult;
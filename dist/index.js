@@ -1,5 +1,14 @@
 import * as indicator from './node/technical_indicators_wasm.js'
 
+export {
+    OnBalanceVolumeStream,
+    AccumulationDistributionStream,
+    ChaikinMoneyFlowStream,
+    ChaikinOscillatorStream,
+    PriceVolumeTrendStream,
+    MovingAverageStream,
+} from './node/technical_indicators_wasm.js'
+
 export class Indicator {
 
     #data = []
@@ -8,8 +17,8 @@ export class Indicator {
         this.#data = data
     }
 
-    ichimoku(tenkan, kijun, senkou) {
-        return ichimoku(this.#data, tenkan, kijun, senkou)
+    ichimoku(tenkan, kijun, senkou, displacement) {
+        return ichimoku(this.#data, tenkan, kijun, senkou, displacement)
     }
 
     directionalMovementIndex(period) {
@@ -20,12 +29,12 @@ export class Indicator {
         return relativeStrengthIndex(this.#data, period)
     }
 
-    extractImportantLevels() {
-        return extractImportantLevels(this.#data)
+    extractImportantLevels(options) {
+        return extractImportantLevels(this.#data, options)
     }
 
-    bollingerBands(period, multiplier) {
-        return bollingerBands(this.#data, period, multiplier)
+    bollingerBands(options, multiplier) {
+        return bollingerBands(this.#data.map(candle => candle.close), options, multiplier)
     }
 
     simpleMovingAverage(period) {
@@ -44,8 +53,8 @@ export class Indicator {
         return stochasticMomentumIndex(this.#data, period_k, period_d)
     }
 
-    stochasticOscillator(period) {
-        return stochasticOscillator(this.#data, period)
+    stochasticOscillator(options) {
+        return stochasticOscillator(this.#data, options)
     }
 
     trendsMeter(period) {
@@ -67,10 +76,174 @@ export class Indicator {
     kagiChart(reversalAmount) {
         return kagiChart(this.#data, reversalAmount)
     }
+
+    onBalanceVolume(signalPeriod, signalType) {
+        return onBalanceVolume(this.#data, signalPeriod, signalType)
+    }
+
+    accumulationDistribution(signalPeriod, signalType) {
+        return accumulationDistribution(this.#data, signalPeriod, signalType)
+    }
+
+    chaikinMoneyFlow(period, signalPeriod, signalType) {
+        return chaikinMoneyFlow(this.#data, period, signalPeriod, signalType)
+    }
+
+    chaikinOscillator(fastPeriod, slowPeriod, signalPeriod, signalType) {
+        return chaikinOscillator(this.#data, fastPeriod, slowPeriod, signalPeriod, signalType)
+    }
+
+    priceVolumeTrend(signalPeriod, signalType) {
+        return priceVolumeTrend(this.#data, signalPeriod, signalType)
+    }
+
+    moneyFlowIndex(period) {
+        return moneyFlowIndex(this.#data, period)
+    }
+
+    easeOfMovement(period, volumeDivisor) {
+        return easeOfMovement(this.#data, period, volumeDivisor)
+    }
+
+    forceIndex(period) {
+        return forceIndex(this.#data, period)
+    }
+
+    klingerVolumeOscillator(fastPeriod, slowPeriod, signalPeriod, signalType) {
+        return klingerVolumeOscillator(this.#data, fastPeriod, slowPeriod, signalPeriod, signalType)
+    }
+
+    negativeVolumeIndex(signalPeriod, signalType, start) {
+        return negativeVolumeIndex(this.#data, signalPeriod, signalType, start)
+    }
+
+    positiveVolumeIndex(signalPeriod, signalType, start) {
+        return positiveVolumeIndex(this.#data, signalPeriod, signalType, start)
+    }
+
+    percentageVolumeOscillator(fastPeriod, slowPeriod, signalPeriod, signalType) {
+        return percentageVolumeOscillator(this.#data, fastPeriod, slowPeriod, signalPeriod, signalType)
+    }
+
+    volumeProfile(options, start, end) {
+        return volumeProfile(this.#data, options, start, end)
+    }
+
+    sessionVolumeProfile(options) {
+        return sessionVolumeProfile(this.#data, options)
+    }
+
+    rollingVolumeProfile(window, options) {
+        return rollingVolumeProfile(this.#data, window, options)
+    }
+
+    marketProfile(options) {
+        return marketProfile(this.#data, options)
+    }
+
+    movingAverage(period, kind, options) {
+        return movingAverage(this.#data.map(candle => candle.close), period, kind, this.#data.map(candle => candle.volume), options)
+    }
+
+    macd(fastPeriod, slowPeriod, signalPeriod, kind, signalKind) {
+        return macd(this.#data.map(candle => candle.close), fastPeriod, slowPeriod, signalPeriod, kind, signalKind)
+    }
+
+    fractalAdaptiveMovingAverage(period, fastPeriod, slowPeriod) {
+        return fractalAdaptiveMovingAverage(this.#data, period, fastPeriod, slowPeriod)
+    }
+
+    kaufmanAdaptiveMovingAverage(period, fastPeriod, slowPeriod) {
+        return kaufmanAdaptiveMovingAverage(this.#data.map(candle => candle.close), period, fastPeriod, slowPeriod)
+    }
+
+    variableIndexDynamicAverage(period, cmoPeriod) {
+        return variableIndexDynamicAverage(this.#data.map(candle => candle.close), period, cmoPeriod)
+    }
+
+    mcginleyDynamic(period, constant) {
+        return mcginleyDynamic(this.#data.map(candle => candle.close), period, constant)
+    }
+
+    williamsR(options) {
+        return williamsR(this.#data, options)
+    }
+
+    commodityChannelIndex(options) {
+        return commodityChannelIndex(this.#data, options)
+    }
+
+    aroon(period) {
+        return aroon(this.#data, period)
+    }
+
+    vortexIndicator(period) {
+        return vortexIndicator(this.#data, period)
+    }
+
+    supertrend(options) {
+        return supertrend(this.#data, options)
+    }
+
+    pivotPoints(options) {
+        return pivotPoints(this.#data, options)
+    }
+
+    fibonacciLevels(options) {
+        return fibonacciLevels(this.#data, options)
+    }
+
+    zigzag(options) {
+        return zigzag(this.#data, options)
+    }
+
+    supportResistanceZones(options) {
+        return supportResistanceZones(this.#data, options)
+    }
+
+    trendlines(options) {
+        return trendlines(this.#data, options)
+    }
+
+    candlestickPatterns(options) {
+        return candlestickPatterns(this.#data, options)
+    }
+
+    chartPatterns(options) {
+        return chartPatterns(this.#data, options)
+    }
+
+    harmonicPatterns(options) {
+        return harmonicPatterns(this.#data, options)
+    }
+
+    divergence(oscillator, options) {
+        return divergence(this.#data.map(candle => candle.close), oscillator, options)
+    }
+
+    rollingStatistic(options) {
+        return rollingStatistic(this.#data.map(candle => candle.close), options)
+    }
+
+    linearRegression(options) {
+        return linearRegression(this.#data, options)
+    }
+
+    linearRegressionChannel(options) {
+        return linearRegressionChannel(this.#data, options)
+    }
+
+    standardErrorBands(options) {
+        return standardErrorBands(this.#data, options)
+    }
+
+    historicalVolatility(options) {
+        return historicalVolatility(this.#data, options)
+    }
 }
 
-export function ichimoku(data, tenkan, kijun, senkou) {
-    return indicator.ichimoku(data, tenkan, kijun, senkou)
+export function ichimoku(data, tenkan, kijun, senkou, displacement) {
+    return indicator.ichimoku(data, tenkan, kijun, senkou, displacement)
 }
 
 export function directionalMovementIndex(data, period) {
@@ -87,15 +260,16 @@ export function relativeStrengthIndex(data, period) {
     return indicator.relative_strength_index(data, period)
 }
 
-export function extractImportantLevels(data) {
-    return indicator.extract_important_levels(data)
+export function extractImportantLevels(data, options) {
+    return indicator.extract_important_levels(data, options)
 }
 
-export function bollingerBands(data, period, multiplier) {
+export function bollingerBands(data, options, multiplier) {
+    const period = typeof options === 'number' ? options : options?.period
     if (period <= 0) {
         throw new Error('Period must be greater than 0.');
     }
-    return indicator.bollinger_bands(data, period, multiplier)
+    return indicator.bollinger_bands(data, options, multiplier)
 }
 
 export function simpleMovingAverage(data, period) {
@@ -114,8 +288,8 @@ export function stochasticMomentumIndex(data, period_k, period_d) {
     return Array.from(indicator.stochastic_momentum_index(data, period_k, period_d))
 }
 
-export function stochasticOscillator(data, period) {
-    return indicator.stochastic_oscillator(data, period)
+export function stochasticOscillator(data, options) {
+    return indicator.stochastic_oscillator(data, options)
 }
 
 export function trendsMeter(data, period) {
@@ -139,4 +313,168 @@ export function kagiChart(data, reversalAmount) {
 
 export function parabolicSar(data, start, increment, max_value) {
     return Array.from(indicator.parabolic_sar(data, start, increment, max_value))
-}
\ No newline at end of file
+}
+
+export function onBalanceVolume(data, signalPeriod, signalType) {
+    return indicator.on_balance_volume(data, signalPeriod, signalType)
+}
+
+export function accumulationDistribution(data, signalPeriod, signalType) {
+    return indicator.accumulation_distribution(data, signalPeriod, signalType)
+}
+
+export function chaikinMoneyFlow(data, period, signalPeriod, signalType) {
+    return indicator.chaikin_money_flow(data, period, signalPeriod, signalType)
+}
+
+export function chaikinOscillator(data, fastPeriod, slowPeriod, signalPeriod, signalType) {
+    return indicator.chaikin_oscillator(data, fastPeriod, slowPeriod, signalPeriod, signalType)
+}
+
+export function priceVolumeTrend(data, signalPeriod, signalType) {
+    return indicator.price_volume_trend(data, signalPeriod, signalType)
+}
+
+export function moneyFlowIndex(data, period) {
+    return indicator.money_flow_index(data, period)
+}
+
+export function easeOfMovement(data, period, volumeDivisor) {
+    return indicator.ease_of_movement(data, period, volumeDivisor)
+}
+
+export function forceIndex(data, period) {
+    return indicator.force_index(data, period)
+}
+
+export function klingerVolumeOscillator(data, fastPeriod, slowPeriod, signalPeriod, signalType) {
+    return indicator.klinger_volume_oscillator(data, fastPeriod, slowPeriod, signalPeriod, signalType)
+}
+
+export function negativeVolumeIndex(data, signalPeriod, signalType, start) {
+    return indicator.negative_volume_index(data, signalPeriod, signalType, start)
+}
+
+export function positiveVolumeIndex(data, signalPeriod, signalType, start) {
+    return indicator.positive_volume_index(data, signalPeriod, signalType, start)
+}
+
+export function percentageVolumeOscillator(data, fastPeriod, slowPeriod, signalPeriod, signalType) {
+    return indicator.percentage_volume_oscillator(data, fastPeriod, slowPeriod, signalPeriod, signalType)
+}
+
+export function volumeProfile(data, options, start, end) {
+    return indicator.volume_profile(data, options, start, end)
+}
+
+export function sessionVolumeProfile(data, options) {
+    return indicator.session_volume_profile(data, options)
+}
+
+export function rollingVolumeProfile(data, window, options) {
+    return indicator.rolling_volume_profile(data, window, options)
+}
+
+export function marketProfile(data, options) {
+    return indicator.market_profile(data, options)
+}
+
+export function movingAverage(data, period, kind, volumes, options) {
+    return Array.from(indicator.moving_average(data, period, kind, volumes, options))
+}
+
+export function macd(data, fastPeriod, slowPeriod, signalPeriod, kind, signalKind) {
+    return indicator.macd(data, fastPeriod, slowPeriod, signalPeriod, kind, signalKind)
+}
+
+export function kaufmanAdaptiveMovingAverage(data, period, fastPeriod, slowPeriod) {
+    return indicator.kaufman_adaptive_moving_average(data, period, fastPeriod, slowPeriod)
+}
+
+export function variableIndexDynamicAverage(data, period, cmoPeriod) {
+    return indicator.variable_index_dynamic_average(data, period, cmoPeriod)
+}
+
+export function fractalAdaptiveMovingAverage(data, period, fastPeriod, slowPeriod) {
+    return indicator.fractal_adaptive_moving_average(data, period, fastPeriod, slowPeriod)
+}
+
+export function mcginleyDynamic(data, period, constant) {
+    return indicator.mcginley_dynamic(data, period, constant)
+}
+
+export function williamsR(data, options) {
+    return indicator.williams_r(data, options)
+}
+
+export function commodityChannelIndex(data, options) {
+    return indicator.commodity_channel_index(data, options)
+}
+
+export function aroon(data, period) {
+    return indicator.aroon(data, period)
+}
+
+export function vortexIndicator(data, period) {
+    return indicator.vortex_indicator(data, period)
+}
+
+export function supertrend(data, options) {
+    return indicator.supertrend(data, options)
+}
+
+export function pivotPoints(data, options) {
+    return indicator.pivot_points(data, options)
+}
+
+export function fibonacciLevels(data, options) {
+    return indicator.fibonacci_levels(data, options)
+}
+
+export function zigzag(data, options) {
+    return indicator.zigzag(data, options)
+}
+
+export function supportResistanceZones(data, options) {
+    return indicator.support_resistance_zones(data, options)
+}
+
+export function trendlines(data, options) {
+    return indicator.trendlines(data, options)
+}
+
+export function candlestickPatterns(data, options) {
+    return indicator.candlestick_patterns(data, options)
+}
+
+export function chartPatterns(data, options) {
+    return indicator.chart_patterns(data, options)
+}
+
+export function harmonicPatterns(data, options) {
+    return indicator.harmonic_patterns(data, options)
+}
+
+export function divergence(prices, oscillator, options) {
+    return indicator.divergence(prices, oscillator, options)
+}
+
+export function rollingStatistic(data, options) {
+    return Array.from(indicator.rolling_statistic(data, options))
+}
+
+export function linearRegression(data, options) {
+    return indicator.linear_regression(data, options)
+}
+
+export function linearRegressionChannel(data, options) {
+    return indicator.linear_regression_channel(data, options)
+}
+
+export function standardErrorBands(data, options) {
+    return indicator.standard_error_bands(data, options)
+}
+
+export function historicalVolatility(data, options) {
+    return indicator.historical_volatility(data, options)
+}
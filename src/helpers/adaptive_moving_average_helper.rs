@@ -0,0 +1,325 @@
+use std::collections::VecDeque;
+
+use crate::structs::adaptive_moving_average_struct::{AdaptiveMovingAverageResult, KamaResult};
+
+/// Keeps the last `len` values and returns `true` once the window is full.
+fn push_window(window: &mut VecDeque<f64>, value: f64, len: usize) -> bool {
+    window.push_back(value);
+    if window.len() > len {
+        window.pop_front();
+    }
+    window.len() == len
+}
+
+/// Sums of the upward and downward moves between consecutive values of the window.
+fn directional_moves(window: &VecDeque<f64>) -> (f64, f64) {
+    window
+        .iter()
+        .zip(window.iter().skip(1))
+        .fold((0.0, 0.0), |(up, down), (previous, current)| {
+            let change = current - previous;
+            if change > 0.0 {
+                (up + change, down)
+            } else {
+                (up, down - change)
+            }
+        })
+}
+
+/// Running Kaufman Adaptive Moving Average.
+///
+/// The efficiency ratio (net move over the sum of moves across `period` changes) scales the
+/// smoothing between the `fast_period` and `slow_period` EMA constants. The first value is
+/// emitted once `period` changes are available; NaN inputs return NaN and are skipped.
+#[derive(Clone, Debug)]
+pub struct KamaState {
+    period: usize,
+    fast_alpha: f64,
+    slow_alpha: f64,
+    window: VecDeque<f64>,
+    value: f64,
+}
+
+impl KamaState {
+    pub fn new(period: usize, fast_period: usize, slow_period: usize) -> Self {
+        KamaState {
+            period,
+            fast_alpha: 2.0 / (fast_period as f64 + 1.0),
+            slow_alpha: 2.0 / (slow_period as f64 + 1.0),
+            window: VecDeque::with_capacity(period + 2),
+            value: f64::NAN,
+        }
+    }
+
+    /// Returns `(kama, efficiency_ratio, alpha)`.
+    pub fn next(&mut self, value: f64) -> (f64, f64, f64) {
+        if value.is_nan() || !push_window(&mut self.window, value, self.period + 1) {
+            return (f64::NAN, f64::NAN, f64::NAN);
+        }
+
+        let (up, down) = directional_moves(&self.window);
+        let change = (value - self.window[0]).abs();
+        let efficiency_ratio = if up + down == 0.0 { 0.0 } else { change / (up + down) };
+        let alpha = (efficiency_ratio * (self.fast_alpha - self.slow_alpha) + self.slow_alpha).powi(2);
+
+        if self.value.is_nan() {
+            self.value = self.window[self.period - 1];
+        }
+        self.value += alpha * (value - self.value);
+        (self.value, efficiency_ratio, alpha)
+    }
+}
+
+/// Running Chande Variable Index Dynamic Average.
+///
+/// An EMA of `period` whose factor is scaled by the absolute Chande Momentum Oscillator
+/// over `cmo_period` changes. Returns `(vidya, alpha)`.
+#[derive(Clone, Debug)]
+pub struct VidyaState {
+    cmo_period: usize,
+    base_alpha: f64,
+    window: VecDeque<f64>,
+    value: f64,
+}
+
+impl VidyaState {
+    pub fn new(period: usize, cmo_period: usize) -> Self {
+        VidyaState {
+            cmo_period,
+            base_alpha: 2.0 / (period as f64 + 1.0),
+            window: VecDeque::with_capacity(cmo_period + 2),
+            value: f64::NAN,
+        }
+    }
+
+    pub fn next(&mut self, value: f64) -> (f64, f64) {
+        if value.is_nan() || !push_window(&mut self.window, value, self.cmo_period + 1) {
+            return (f64::NAN, f64::NAN);
+        }
+
+        let (up, down) = directional_moves(&self.window);
+        let cmo = if up + down == 0.0 { 0.0 } else { (up - down) / (up + down) };
+        let alpha = self.base_alpha * cmo.abs();
+
+        if self.value.is_nan() {
+            self.value = self.window[self.cmo_period - 1];
+        }
+        self.value += alpha * (value - self.value);
+        (self.value, alpha)
+    }
+}
+
+/// Running Ehlers Fractal Adaptive Moving Average.
+///
+/// The fractal dimension of the last `period` bars (`period` must be even) sets the smoothing
+/// factor, which is then mapped between the `fast_period` and `slow_period` EMA constants
+/// (1 and 198 give Ehlers' original filter). The first value is the price of the bar that fills
+/// the window. Returns `(frama, alpha)`.
+#[derive(Clone, Debug)]
+pub struct FramaState {
+    period: usize,
+    fast_period: f64,
+    slow_period: f64,
+    highs: VecDeque<f64>,
+    lows: VecDeque<f64>,
+    dimension: f64,
+    value: f64,
+}
+
+impl FramaState {
+    pub fn new(period: usize, fast_period: usize, slow_period: usize) -> Self {
+        FramaState {
+            period,
+            fast_period: fast_period as f64,
+            slow_period: slow_period as f64,
+            highs: VecDeque::with_capacity(period + 1),
+            lows: VecDeque::with_capacity(period + 1),
+            dimension: 1.0,
+            value: f64::NAN,
+        }
+    }
+
+    /// Range of the bars `from..to` of the window, divided by their count.
+    fn slope(&self, from: usize, to: usize) -> f64 {
+        let high = self.highs.range(from..to).cloned().fold(f64::NEG_INFINITY, f64::max);
+        let low = self.lows.range(from..to).cloned().fold(f64::INFINITY, f64::min);
+        (high - low) / (to - from) as f64
+    }
+
+    pub fn next(&mut self, high: f64, low: f64, price: f64) -> (f64, f64) {
+        if high.is_nan() || low.is_nan() || price.is_nan() {
+            return (f64::NAN, f64::NAN);
+        }
+        push_window(&mut self.lows, low, self.period);
+        if !push_window(&mut self.highs, high, self.period) {
+            return (f64::NAN, f64::NAN);
+        }
+
+        let half = self.period / 2;
+        let n1 = self.slope(0, half);
+        let n2 = self.slope(half, self.period);
+        let n3 = self.slope(0, self.period);
+        // A flat half carries no information: keep the previous dimension.
+        if n1 > 0.0 && n2 > 0.0 && n3 > 0.0 {
+            self.dimension = ((n1 + n2).ln() - n3.ln()) / std::f64::consts::LN_2;
+        }
+
+        let slowest = 2.0 / (self.slow_period + 1.0);
+        let raw_alpha = (slowest.ln() * (self.dimension - 1.0)).exp().clamp(slowest, 1.0);
+        let raw_length = (2.0 - raw_alpha) / raw_alpha;
+        let length = (self.slow_period - self.fast_period) * (raw_length - 1.0) / (self.slow_period - 1.0)
+            + self.fast_period;
+        let alpha = 2.0 / (length + 1.0);
+
+        self.value = if self.value.is_nan() { price } else { self.value + alpha * (price - self.value) };
+        (self.value, alpha)
+    }
+}
+
+/// Running McGinley Dynamic.
+///
+/// Seeded with the SMA of the first `period` values, then
+/// `md += (price - md) / (constant * period * (price / md)^4)`. The factor is capped at 1 so
+/// that a sudden drop cannot make the line overshoot the price. Returns `(md, alpha)`.
+#[derive(Clone, Debug)]
+pub struct McGinleyState {
+    period: usize,
+    constant: f64,
+    count: usize,
+    seed_sum: f64,
+    value: f64,
+}
+
+impl McGinleyState {
+    pub fn new(period: usize, constant: f64) -> Self {
+        McGinleyState { period, constant, count: 0, seed_sum: 0.0, value: f64::NAN }
+    }
+
+    pub fn next(&mut self, price: f64) -> (f64, f64) {
+        if price.is_nan() {
+            return (f64::NAN, f64::NAN);
+        }
+        if self.count < self.period {
+            self.count += 1;
+            self.seed_sum += price;
+            if self.count == self.period {
+                self.value = self.seed_sum / self.period as f64;
+            }
+            return (self.value, f64::NAN);
+        }
+
+        let alpha = if self.value == 0.0 {
+            1.0
+        } else {
+            (1.0 / (self.constant * self.period as f64 * (price / self.value).powi(4))).min(1.0)
+        };
+        self.value += alpha * (price - self.value);
+        (self.value, alpha)
+    }
+}
+
+fn collect_pairs(pairs: impl Iterator<Item = (f64, f64)>) -> AdaptiveMovingAverageResult {
+    let (values, alpha) = pairs.unzip();
+    AdaptiveMovingAverageResult { values, alpha }
+}
+
+pub fn kaufman_adaptive_moving_average(
+    prices: &[f64],
+    period: usize,
+    fast_period: usize,
+    slow_period: usize,
+) -> KamaResult {
+    let mut state = KamaState::new(period, fast_period, slow_period);
+    let mut result = KamaResult {
+        values: Vec::with_capacity(prices.len()),
+        efficiency_ratio: Vec::with_capacity(prices.len()),
+        alpha: Vec::with_capacity(prices.len()),
+    };
+    for &price in prices {
+        let (value, efficiency_ratio, alpha) = state.next(price);
+        result.values.push(value);
+        result.efficiency_ratio.push(efficiency_ratio);
+        result.alpha.push(alpha);
+    }
+    result
+}
+
+pub fn variable_index_dynamic_average(prices: &[f64], period: usize, cmo_period: usize) -> AdaptiveMovingAverageResult {
+    let mut state = VidyaState::new(period, cmo_period);
+    collect_pairs(prices.iter().map(|&price| state.next(price)))
+}
+
+pub fn fractal_adaptive_moving_average(
+    highs: &[f64],
+    lows: &[f64],
+    prices: &[f64],
+    period: usize,
+    fast_period: usize,
+    slow_period: usize,
+) -> AdaptiveMovingAverageResult {
+    let mut state = FramaState::new(period, fast_period, slow_period);
+    collect_pairs((0..prices.len()).map(|i| state.next(highs[i], lows[i], prices[i])))
+}
+
+pub fn mcginley_dynamic(prices: &[f64], period: usize, constant: f64) -> AdaptiveMovingAverageResult {
+    let mut state = McGinleyState::new(period, constant);
+    collect_pairs(prices.iter().map(|&price| state.next(price)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kama_follows_a_trend_with_efficiency_one() {
+        let prices: Vec<f64> = (0..10).map(|i| i as f64).collect();
+        let result = kaufman_adaptive_moving_average(&prices, 3, 2, 30);
+
+        assert!(result.values[2].is_nan());
+        assert_eq!(result.efficiency_ratio[3], 1.0);
+        // Seeded with the previous price (2), moved by the fast constant squared (2/3)^2.
+        let alpha = (2.0f64 / 3.0).powi(2);
+        assert!((result.alpha[3] - alpha).abs() < 1e-12);
+        assert!((result.values[3] - (2.0 + alpha)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_kama_efficiency_ratio_in_a_range() {
+        let prices = [1.0, 2.0, 1.0, 2.0, 1.0];
+        let result = kaufman_adaptive_moving_average(&prices, 4, 2, 30);
+        assert_eq!(result.efficiency_ratio[4], 0.0);
+        assert!((result.alpha[4] - (2.0f64 / 31.0).powi(2)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_vidya_alpha_scales_with_momentum() {
+        let result = variable_index_dynamic_average(&[1.0, 2.0, 3.0, 2.0], 3, 2);
+        assert!(result.values[1].is_nan());
+        // Two up moves: |CMO| = 1, full EMA factor.
+        assert_eq!(result.alpha[2], 0.5);
+        assert_eq!(result.values[2], 2.5);
+        // One up, one down: CMO = 0, the average stays put.
+        assert_eq!(result.alpha[3], 0.0);
+        assert_eq!(result.values[3], 2.5);
+    }
+
+    #[test]
+    fn test_frama_on_a_straight_line_uses_the_fastest_factor() {
+        // A trend has a fractal dimension of 1, which gives alpha = 1 with Ehlers' constants.
+        let prices: Vec<f64> = (0..8).map(|i| i as f64).collect();
+        let result = fractal_adaptive_moving_average(&prices, &prices, &prices, 4, 1, 198);
+        assert!(result.values[2].is_nan());
+        assert!((result.alpha[7] - 1.0).abs() < 1e-9);
+        assert!((result.values[7] - 7.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mcginley_dynamic() {
+        let result = mcginley_dynamic(&[10.0, 10.0, 11.0], 2, 0.6);
+        assert!(result.values[0].is_nan());
+        assert_eq!(result.values[1], 10.0);
+        let alpha = 1.0 / (0.6 * 2.0 * 1.1f64.powi(4));
+        assert!((result.alpha[2] - alpha).abs() < 1e-12);
+        assert!((result.values[2] - (10.0 + alpha)).abs() < 1e-12);
+    }
+}
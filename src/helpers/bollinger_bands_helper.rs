@@ -1,54 +1,104 @@
-use crate::bollinger_bands_struct::BollingerBandsResult;
+use crate::bollinger_bands_struct::{BollingerBandsOptions, BollingerBandsResult};
+use crate::helpers::moving_average_helper::MovingAverageState;
+use crate::helpers::rolling_statistics_helper::{rolling_min, RollingVariance};
 
 /// Fonction helper qui calcule les Bollinger Bands à partir d'un slice de f64.
 ///
-/// On utilise ici un algorithme à fenêtre glissante pour éviter de recalculer
-/// la somme et la somme des carrés à chaque fenêtre.
-///
-/// Retourne une instance de `BollingerBandsResult` contenant 3 vecteurs.
-pub fn compute_bollinger_bands(prices: &[f64], period: usize, multiplier: f64) -> BollingerBandsResult {
-    let n = period as f64;
-    // Pré-allouer la taille des vecteurs de sortie pour éviter des réallocations
-    let out_size = prices.len() - period + 1;
-    let mut middle = Vec::with_capacity(out_size);
-    let mut upper = Vec::with_capacity(out_size);
-    let mut lower = Vec::with_capacity(out_size);
-
-    // Calculer la somme et la somme des carrés pour la première fenêtre
-    let (mut sum, mut sum_sq) = prices[..period].iter().fold((0.0, 0.0), |(s, s_sq), &p| {
-        (s + p, s_sq + p * p)
-    });
-
-    // Calculer la moyenne et l’écart-type pour la fenêtre initiale
-    let ma = sum / n;
-    let stdev = calculate_std(sum, sum_sq, n);
-    middle.push(ma);
-    upper.push(ma + multiplier * stdev);
-    lower.push(ma - multiplier * stdev);
-
-    // Parcourir le reste des données en glissant la fenêtre
-    for i in period..prices.len() {
-        let old = prices[i - period];
-        let new = prices[i];
-
-        // Mettre à jour la somme et la somme des carrés de la fenêtre
-        sum = sum - old + new;
-        sum_sq = sum_sq - old * old + new * new;
-
-        let ma = sum / n;
-        let stdev = calculate_std(sum, sum_sq, n);
-        middle.push(ma);
-        upper.push(ma + multiplier * stdev);
-        lower.push(ma - multiplier * stdev);
+/// La bande centrale est la moyenne mobile `ma_type` des prix, l'écart-type celui des `period`
+/// derniers prix. Tous les vecteurs sont alignés sur les prix (NaN pendant le warm-up) ; %B vaut
+/// 0.5 quand les bandes sont confondues et le squeeze attend `squeeze_period` largeurs.
+pub fn compute_bollinger_bands(prices: &[f64], options: &BollingerBandsOptions) -> BollingerBandsResult {
+    let len = prices.len();
+    let mut average = MovingAverageState::new(options.ma_type, options.period);
+    let mut variance = RollingVariance::new(options.period);
+    let mut result = BollingerBandsResult {
+        middle: Vec::with_capacity(len),
+        upper: Vec::with_capacity(len),
+        lower: Vec::with_capacity(len),
+        percent_b: Vec::with_capacity(len),
+        bandwidth: Vec::with_capacity(len),
+        squeeze: Vec::new(),
+    };
+
+    for &price in prices {
+        let ma = average.next(price);
+        let stdev = if variance.push(price) { variance.variance(options.deviation).sqrt() } else { f64::NAN };
+        let upper = ma + options.upper_multiplier() * stdev;
+        let lower = ma - options.lower_multiplier() * stdev;
+        let bandwidth = if ma == 0.0 { 0.0 } else { (upper - lower) / ma };
+
+        result.middle.push(ma);
+        result.upper.push(upper);
+        result.lower.push(lower);
+        result.percent_b.push(if upper == lower { 0.5 } else { (price - lower) / (upper - lower) });
+        result.bandwidth.push(bandwidth);
     }
 
-    BollingerBandsResult { middle, upper, lower }
+    // Squeeze : la largeur courante est la plus faible des `squeeze_period` dernières.
+    result.squeeze = if options.squeeze_period == 0 {
+        vec![false; len]
+    } else {
+        let lowest = rolling_min(&result.bandwidth, options.squeeze_period);
+        result.bandwidth.iter().zip(&lowest).map(|(width, low)| width <= low).collect()
+    };
+
+    result
 }
 
-/// Fonction helper pour calculer l'écart-type à partir de la somme et la somme des carrés.
-/// La variance est calculée selon la formule : variance = (sum_sq - (sum²)/n) / n
-#[inline(always)]
-fn calculate_std(sum: f64, sum_sq: f64, n: f64) -> f64 {
-    let variance = (sum_sq - (sum * sum) / n) / n;
-    variance.sqrt()
-}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::moving_average_struct::MovingAverageType;
+    use crate::structs::rolling_statistics_struct::DeviationType;
+
+    fn options(period: usize) -> BollingerBandsOptions {
+        BollingerBandsOptions { period, squeeze_period: 3, ..BollingerBandsOptions::default() }
+    }
+
+    #[test]
+    fn test_bands_are_aligned_with_the_prices() {
+        let prices: Vec<f64> = (1..=5).map(|p| p as f64).collect();
+        let result = compute_bollinger_bands(&prices, &options(3));
+
+        assert_eq!(result.middle.len(), 5);
+        assert!(result.middle[1].is_nan() && result.upper[1].is_nan() && result.percent_b[1].is_nan());
+        assert_eq!(result.middle[2], 2.0);
+        let stdev = (2.0f64 / 3.0).sqrt();
+        assert!((result.upper[2] - (2.0 + 2.0 * stdev)).abs() < 1e-12);
+        assert!((result.lower[4] - (4.0 - 2.0 * stdev)).abs() < 1e-12);
+        assert!((result.bandwidth[2] - 4.0 * stdev / 2.0).abs() < 1e-12);
+        // (3 - lower) / (upper - lower) with the price one unit above the middle.
+        assert!((result.percent_b[2] - (0.5 + 1.0 / (4.0 * stdev))).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sample_deviation_and_asymmetric_multipliers() {
+        let opts = BollingerBandsOptions {
+            deviation: DeviationType::Sample,
+            upper_multiplier: Some(1.0),
+            lower_multiplier: Some(3.0),
+            ..options(3)
+        };
+        let result = compute_bollinger_bands(&[1.0, 2.0, 3.0], &opts);
+        assert_eq!(result.upper[2], 3.0);
+        assert_eq!(result.lower[2], -1.0);
+    }
+
+    #[test]
+    fn test_squeeze_flags_the_narrowest_bandwidth() {
+        let prices = [10.0, 12.0, 10.0, 12.0, 11.0, 11.5, 10.9, 11.2];
+        let result = compute_bollinger_bands(&prices, &options(2));
+        // The first window still holds a NaN, then a flat bandwidth counts as its own low.
+        assert!(!result.squeeze[2] && result.squeeze[3]);
+        assert!(result.squeeze[4] && result.squeeze[5] && result.squeeze[7]);
+        assert!(!result.squeeze[6]);
+    }
+
+    #[test]
+    fn test_middle_band_follows_the_moving_average_type() {
+        let prices = [1.0, 2.0, 3.0, 4.0];
+        let opts = BollingerBandsOptions { ma_type: MovingAverageType::Wma, ..options(3) };
+        let result = compute_bollinger_bands(&prices, &opts);
+        assert!((result.middle[2] - 14.0 / 6.0).abs() < 1e-12);
+    }
+}
@@ -1,3 +1,7 @@
+use crate::helpers::directional_movement_index_helper::true_range;
+use crate::helpers::moving_average_helper::moving_average;
+use crate::structs::moving_average_struct::MovingAverageType;
+
 pub fn calculate_atr(prices: &[f64], period: usize) -> Vec<f64> {
     let mut atr_values = Vec::new();
 
@@ -32,3 +36,32 @@ pub fn calculate_atr(prices: &[f64], period: usize) -> Vec<f64> {
 
     atr_values
 }
+
+/// Wilder's Average True Range on OHLC candles.
+///
+/// The first true range is the high–low range of the first candle; the average is seeded with the
+/// SMA of the first `period` true ranges, so the first `period - 1` values are NaN.
+pub fn average_true_range(highs: &[f64], lows: &[f64], closes: &[f64], period: usize) -> Vec<f64> {
+    let true_ranges: Vec<f64> = (0..closes.len())
+        .map(|i| if i == 0 { highs[0] - lows[0] } else { true_range(highs, lows, closes, i) })
+        .collect();
+    moving_average(&true_ranges, period, MovingAverageType::Smma)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_average_true_range() {
+        let highs = [10.0, 12.0, 11.0];
+        let lows = [8.0, 10.0, 9.0];
+        let closes = [9.0, 11.0, 9.5];
+        let atr = average_true_range(&highs, &lows, &closes, 2);
+
+        assert!(atr[0].is_nan());
+        // True ranges 2, 3 (gap above the previous close) and 2.
+        assert_eq!(atr[1], 2.5);
+        assert_eq!(atr[2], 2.25);
+    }
+}
@@ -0,0 +1,351 @@
+use crate::helpers::moving_average_helper::moving_average;
+use crate::low_high_open_close_volume_date_to_array_helper::MarketDataResult;
+use crate::structs::candlestick_pattern_struct::{
+    CandlestickHit, CandlestickOptions, CandlestickPattern, PatternDirection,
+};
+
+/// A pattern found on a candle, with its direction and shape quality.
+type PatternMatch = (CandlestickPattern, PatternDirection, f64);
+
+#[derive(Clone, Copy, Debug)]
+struct Candle {
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+}
+
+impl Candle {
+    fn body(&self) -> f64 {
+        (self.close - self.open).abs()
+    }
+
+    fn range(&self) -> f64 {
+        self.high - self.low
+    }
+
+    fn upper_shadow(&self) -> f64 {
+        self.high - self.open.max(self.close)
+    }
+
+    fn lower_shadow(&self) -> f64 {
+        self.open.min(self.close) - self.low
+    }
+
+    fn body_top(&self) -> f64 {
+        self.open.max(self.close)
+    }
+
+    fn body_bottom(&self) -> f64 {
+        self.open.min(self.close)
+    }
+
+    fn midpoint(&self) -> f64 {
+        (self.open + self.close) / 2.0
+    }
+
+    fn is_bullish(&self) -> bool {
+        self.close > self.open
+    }
+
+    fn is_bearish(&self) -> bool {
+        self.close < self.open
+    }
+
+    /// Body covering at least half of the range.
+    fn is_long(&self) -> bool {
+        self.body() >= self.range() / 2.0
+    }
+}
+
+/// Trend on every candle: 1 when the close is above its moving average, -1 below, 0 when
+/// unknown (no `trend_period`, warm-up or equality).
+pub fn trend_context(closes: &[f64], options: &CandlestickOptions) -> Vec<i8> {
+    let Some(period) = options.trend_period else {
+        return vec![0; closes.len()];
+    };
+    moving_average(closes, period, options.trend_type)
+        .iter()
+        .zip(closes)
+        .map(|(&average, &close)| {
+            if close > average {
+                1
+            } else if close < average {
+                -1
+            } else {
+                0
+            }
+        })
+        .collect()
+}
+
+/// Confidence multiplier of a reversal pattern given the trend before it.
+fn context_factor(direction: PatternDirection, trend: i8) -> f64 {
+    let against = match direction {
+        PatternDirection::Bullish => -1,
+        PatternDirection::Bearish => 1,
+        PatternDirection::Neutral => return 1.0,
+    };
+    if trend == against {
+        1.0
+    } else if trend == 0 {
+        0.75
+    } else {
+        0.5
+    }
+}
+
+fn single_bar_patterns(candle: &Candle, trend: i8, options: &CandlestickOptions) -> Vec<PatternMatch> {
+    let range = candle.range();
+    if range <= 0.0 {
+        return Vec::new();
+    }
+    let (body, upper, lower) = (candle.body(), candle.upper_shadow(), candle.lower_shadow());
+    let short_shadow = options.short_shadow_ratio * range;
+    let mut hits = Vec::new();
+
+    if body <= options.doji_body_ratio * range {
+        let quality = 0.5 + 0.5 * (1.0 - body / (options.doji_body_ratio * range));
+        let (pattern, direction) = if upper <= short_shadow {
+            (CandlestickPattern::DragonflyDoji, PatternDirection::Bullish)
+        } else if lower <= short_shadow {
+            (CandlestickPattern::GravestoneDoji, PatternDirection::Bearish)
+        } else if upper >= range / 3.0 && lower >= range / 3.0 {
+            (CandlestickPattern::LongLeggedDoji, PatternDirection::Neutral)
+        } else {
+            (CandlestickPattern::Doji, PatternDirection::Neutral)
+        };
+        hits.push((pattern, direction, quality));
+        return hits;
+    }
+
+    if lower >= options.long_shadow_ratio * body && upper <= short_shadow {
+        hits.push(if trend == 1 {
+            (CandlestickPattern::HangingMan, PatternDirection::Bearish, 1.0)
+        } else {
+            (CandlestickPattern::Hammer, PatternDirection::Bullish, 1.0)
+        });
+    }
+    if upper >= options.long_shadow_ratio * body && lower <= short_shadow {
+        hits.push(if trend == -1 {
+            (CandlestickPattern::InvertedHammer, PatternDirection::Bullish, 1.0)
+        } else {
+            (CandlestickPattern::ShootingStar, PatternDirection::Bearish, 1.0)
+        });
+    }
+    if upper <= short_shadow && lower <= short_shadow {
+        let direction = if candle.is_bullish() { PatternDirection::Bullish } else { PatternDirection::Bearish };
+        hits.push((CandlestickPattern::Marubozu, direction, 1.0));
+    }
+
+    hits
+}
+
+fn two_bar_patterns(previous: &Candle, current: &Candle, options: &CandlestickOptions) -> Vec<PatternMatch> {
+    let mut hits = Vec::new();
+    let opposite =
+        (previous.is_bullish() && current.is_bearish()) || (previous.is_bearish() && current.is_bullish());
+    let direction = if current.is_bullish() { PatternDirection::Bullish } else { PatternDirection::Bearish };
+
+    if opposite
+        && current.body() > previous.body()
+        && current.body_top() >= previous.body_top()
+        && current.body_bottom() <= previous.body_bottom()
+    {
+        hits.push((CandlestickPattern::Engulfing, direction, 1.0));
+    }
+    if opposite
+        && previous.is_long()
+        && current.body() < previous.body()
+        && current.body_top() <= previous.body_top()
+        && current.body_bottom() >= previous.body_bottom()
+    {
+        hits.push((CandlestickPattern::Harami, direction, 1.0));
+    }
+    if previous.is_bearish()
+        && previous.is_long()
+        && current.is_bullish()
+        && current.open < previous.close
+        && current.close > previous.midpoint()
+        && current.close < previous.open
+    {
+        hits.push((CandlestickPattern::PiercingLine, PatternDirection::Bullish, 1.0));
+    }
+    if previous.is_bullish()
+        && previous.is_long()
+        && current.is_bearish()
+        && current.open > previous.close
+        && current.close < previous.midpoint()
+        && current.close > previous.open
+    {
+        hits.push((CandlestickPattern::DarkCloudCover, PatternDirection::Bearish, 1.0));
+    }
+
+    let tolerance = options.tweezer_tolerance * previous.range().max(current.range());
+    if previous.is_bullish() && current.is_bearish() && (previous.high - current.high).abs() <= tolerance {
+        hits.push((CandlestickPattern::TweezerTop, PatternDirection::Bearish, 1.0));
+    }
+    if previous.is_bearish() && current.is_bullish() && (previous.low - current.low).abs() <= tolerance {
+        hits.push((CandlestickPattern::TweezerBottom, PatternDirection::Bullish, 1.0));
+    }
+
+    if current.high < previous.high && current.low > previous.low {
+        hits.push((CandlestickPattern::InsideBar, PatternDirection::Neutral, 1.0));
+    }
+    if current.high > previous.high && current.low < previous.low {
+        hits.push((CandlestickPattern::OutsideBar, direction, 1.0));
+    }
+
+    hits
+}
+
+fn three_bar_patterns(first: &Candle, second: &Candle, third: &Candle) -> Vec<PatternMatch> {
+    let mut hits = Vec::new();
+    let small_middle = second.body() <= first.body() / 3.0;
+
+    if first.is_bearish()
+        && first.is_long()
+        && small_middle
+        && second.body_top() <= first.close
+        && third.is_bullish()
+        && third.close > first.midpoint()
+    {
+        hits.push((CandlestickPattern::MorningStar, PatternDirection::Bullish, 1.0));
+    }
+    if first.is_bullish()
+        && first.is_long()
+        && small_middle
+        && second.body_bottom() >= first.close
+        && third.is_bearish()
+        && third.close < first.midpoint()
+    {
+        hits.push((CandlestickPattern::EveningStar, PatternDirection::Bearish, 1.0));
+    }
+
+    let candles = [first, second, third];
+    let advancing = candles.windows(2).all(|pair| {
+        pair[1].close > pair[0].close && pair[1].open >= pair[0].open && pair[1].open <= pair[0].close
+    });
+    if candles.iter().all(|candle| candle.is_bullish() && candle.is_long()) && advancing {
+        hits.push((CandlestickPattern::ThreeWhiteSoldiers, PatternDirection::Bullish, 1.0));
+    }
+    let declining = candles.windows(2).all(|pair| {
+        pair[1].close < pair[0].close && pair[1].open <= pair[0].open && pair[1].open >= pair[0].close
+    });
+    if candles.iter().all(|candle| candle.is_bearish() && candle.is_long()) && declining {
+        hits.push((CandlestickPattern::ThreeBlackCrows, PatternDirection::Bearish, 1.0));
+    }
+
+    hits
+}
+
+/// Candlestick patterns completed on every candle, in candle order.
+///
+/// `trends[i]` is the trend on candle `i` (see `trend_context`); a pattern is judged against the
+/// trend on the candle before it. Marubozu and inside/outside bars don't depend on the trend.
+pub fn candlestick_patterns(
+    market_data: &MarketDataResult,
+    trends: &[i8],
+    options: &CandlestickOptions,
+) -> Vec<CandlestickHit> {
+    let candles: Vec<Candle> = (0..market_data.closes.len())
+        .map(|i| Candle {
+            open: market_data.opens[i],
+            high: market_data.highs[i],
+            low: market_data.lows[i],
+            close: market_data.closes[i],
+        })
+        .collect();
+    let mut hits = Vec::new();
+
+    for i in 0..candles.len() {
+        let trend_before = |bars: usize| if i >= bars { trends[i - bars] } else { 0 };
+
+        let mut found: Vec<(PatternMatch, usize)> = single_bar_patterns(&candles[i], trend_before(1), options)
+            .into_iter()
+            .map(|hit| (hit, 1))
+            .collect();
+        if i >= 1 {
+            let hits = two_bar_patterns(&candles[i - 1], &candles[i], options);
+            found.extend(hits.into_iter().map(|hit| (hit, 2)));
+        }
+        if i >= 2 {
+            let hits = three_bar_patterns(&candles[i - 2], &candles[i - 1], &candles[i]);
+            found.extend(hits.into_iter().map(|hit| (hit, 3)));
+        }
+
+        for ((pattern, direction, quality), bars) in found {
+            let factor = match pattern {
+                CandlestickPattern::Marubozu | CandlestickPattern::InsideBar | CandlestickPattern::OutsideBar => 1.0,
+                _ => context_factor(direction, trend_before(bars)),
+            };
+            hits.push(CandlestickHit { index: i, pattern, direction, bars, confidence: quality * factor });
+        }
+    }
+
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::low_high_open_close_volume_date_to_array_helper::candles;
+
+    fn patterns_at(hits: &[CandlestickHit], index: usize) -> Vec<CandlestickPattern> {
+        hits.iter().filter(|hit| hit.index == index).map(|hit| hit.pattern).collect()
+    }
+
+    #[test]
+    fn test_single_bar_patterns() {
+        let options = CandlestickOptions::default();
+        let data = candles(&[
+            (10.0, 10.1, 8.0, 10.05),  // dragonfly doji
+            (10.0, 10.6, 7.0, 10.5),   // hammer
+            (10.0, 11.0, 10.0, 11.0),  // bullish marubozu
+        ]);
+        let hits = candlestick_patterns(&data, &[0, 0, 0], &options);
+
+        assert_eq!(patterns_at(&hits, 0), vec![CandlestickPattern::DragonflyDoji]);
+        assert!(patterns_at(&hits, 1).contains(&CandlestickPattern::Hammer));
+        assert!(patterns_at(&hits, 2).contains(&CandlestickPattern::Marubozu));
+    }
+
+    #[test]
+    fn test_hammer_after_an_uptrend_is_a_hanging_man() {
+        let options = CandlestickOptions::default();
+        let data = candles(&[(9.0, 10.0, 9.0, 10.0), (10.0, 10.6, 7.0, 10.5)]);
+        let hits = candlestick_patterns(&data, &[1, 1], &options);
+
+        let hit = hits.iter().find(|hit| hit.pattern == CandlestickPattern::HangingMan).unwrap();
+        assert_eq!(hit.direction, PatternDirection::Bearish);
+        assert_eq!(hit.confidence, 1.0);
+    }
+
+    #[test]
+    fn test_engulfing_confidence_follows_the_trend() {
+        let options = CandlestickOptions::default();
+        let data = candles(&[(10.5, 10.6, 9.9, 10.0), (10.0, 10.2, 8.8, 9.0), (8.9, 10.5, 8.7, 10.4)]);
+
+        let after_downtrend = candlestick_patterns(&data, &[-1; 3], &options);
+        let engulfing = after_downtrend.iter().find(|hit| hit.pattern == CandlestickPattern::Engulfing).unwrap();
+        assert_eq!(engulfing.direction, PatternDirection::Bullish);
+        assert_eq!(engulfing.bars, 2);
+        assert_eq!(engulfing.confidence, 1.0);
+
+        let after_uptrend = candlestick_patterns(&data, &[1; 3], &options);
+        let engulfing = after_uptrend.iter().find(|hit| hit.pattern == CandlestickPattern::Engulfing).unwrap();
+        assert_eq!(engulfing.confidence, 0.5);
+    }
+
+    #[test]
+    fn test_three_bar_patterns() {
+        let options = CandlestickOptions::default();
+        let star = candles(&[(12.0, 12.1, 9.9, 10.0), (9.8, 10.0, 9.5, 9.7), (9.9, 11.6, 9.8, 11.5)]);
+        let hits = candlestick_patterns(&star, &[0; 3], &options);
+        assert!(patterns_at(&hits, 2).contains(&CandlestickPattern::MorningStar));
+
+        let soldiers = candles(&[(10.0, 11.1, 9.9, 11.0), (10.5, 12.1, 10.4, 12.0), (11.5, 13.1, 11.4, 13.0)]);
+        let hits = candlestick_patterns(&soldiers, &[0; 3], &options);
+        assert!(patterns_at(&hits, 2).contains(&CandlestickPattern::ThreeWhiteSoldiers));
+    }
+}
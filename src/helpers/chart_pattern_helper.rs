@@ -0,0 +1,318 @@
+use crate::structs::candlestick_pattern_struct::PatternDirection;
+use crate::structs::chart_pattern_struct::{ChartPattern, ChartPatternHit, ChartPatternOptions, PatternLine};
+use crate::structs::swing_points_struct::{SwingKind, SwingPoint};
+
+/// Difference between two prices in percent of the larger one.
+fn relative_difference(a: f64, b: f64) -> f64 {
+    let scale = a.abs().max(b.abs());
+    if scale == 0.0 { 0.0 } else { (a - b).abs() / scale * 100.0 }
+}
+
+/// A pattern before its breakout is looked for.
+struct Candidate<'a> {
+    pattern: ChartPattern,
+    direction: PatternDirection,
+    pivots: &'a [SwingPoint],
+    upper: PatternLine,
+    lower: PatternLine,
+    /// Measured move added to (removed from) the breakout level.
+    height: f64,
+}
+
+/// Double top or bottom: two equal extremes around a pullback deeper than the tolerance.
+fn double_extreme<'a>(pivots: &'a [SwingPoint], options: &ChartPatternOptions) -> Option<Candidate<'a>> {
+    let [first, middle, last] = pivots else { return None };
+    if relative_difference(first.price, last.price) > options.tolerance {
+        return None;
+    }
+    let (start, end) = (first.index, last.index);
+
+    if first.kind == SwingKind::High {
+        let top = first.price.max(last.price);
+        (relative_difference(top, middle.price) > options.tolerance).then(|| Candidate {
+            pattern: ChartPattern::DoubleTop,
+            direction: PatternDirection::Bearish,
+            pivots,
+            upper: PatternLine::horizontal(top, start, end),
+            lower: PatternLine::horizontal(middle.price, start, end),
+            height: top - middle.price,
+        })
+    } else {
+        let bottom = first.price.min(last.price);
+        (relative_difference(bottom, middle.price) > options.tolerance).then(|| Candidate {
+            pattern: ChartPattern::DoubleBottom,
+            direction: PatternDirection::Bullish,
+            pivots,
+            upper: PatternLine::horizontal(middle.price, start, end),
+            lower: PatternLine::horizontal(bottom, start, end),
+            height: middle.price - bottom,
+        })
+    }
+}
+
+/// Triple top or bottom, or (inverse) head and shoulders, on five alternating pivots.
+fn five_point_reversal<'a>(pivots: &'a [SwingPoint], options: &ChartPatternOptions) -> Option<Candidate<'a>> {
+    let [left, first_trough, head, second_trough, right] = pivots else { return None };
+    let top = left.kind == SwingKind::High;
+    // Prices seen from above for a top and from below for a bottom.
+    let sign = if top { 1.0 } else { -1.0 };
+    let (start, end) = (left.index, right.index);
+    let neckline = PatternLine {
+        start,
+        end,
+        ..PatternLine::through(first_trough.index, first_trough.price, second_trough.index, second_trough.price)
+    };
+
+    let highest = left.price.max(head.price).max(right.price);
+    let lowest = left.price.min(head.price).min(right.price);
+    // The most and least extreme of the three peaks (troughs for a bottom).
+    let (most, least) = if top { (highest, lowest) } else { (lowest, highest) };
+    let above_neckline = pivots
+        .iter()
+        .step_by(2)
+        .all(|point| sign * (point.price - neckline.at(point.index)) > 0.0);
+    if !above_neckline {
+        return None;
+    }
+
+    let (pattern, direction) = if relative_difference(most, least) <= options.tolerance {
+        if top {
+            (ChartPattern::TripleTop, PatternDirection::Bearish)
+        } else {
+            (ChartPattern::TripleBottom, PatternDirection::Bullish)
+        }
+    } else if sign * head.price > sign * left.price
+        && sign * head.price > sign * right.price
+        && relative_difference(head.price, left.price) > options.tolerance
+        && relative_difference(head.price, right.price) > options.tolerance
+        && relative_difference(left.price, right.price) <= options.shoulder_tolerance
+    {
+        if top {
+            (ChartPattern::HeadAndShoulders, PatternDirection::Bearish)
+        } else {
+            (ChartPattern::InverseHeadAndShoulders, PatternDirection::Bullish)
+        }
+    } else {
+        return None;
+    };
+
+    let extreme_line = PatternLine::horizontal(most, start, end);
+    let height = (most - neckline.at(head.index)).abs();
+    let (upper, lower) = if top { (extreme_line, neckline) } else { (neckline, extreme_line) };
+    Some(Candidate { pattern, direction, pivots, upper, lower, height })
+}
+
+/// -1, 0 or 1 as `line` falls, stays flat (within the tolerance over the pattern) or rises.
+fn slope_sign(line: &PatternLine, options: &ChartPatternOptions) -> i8 {
+    let change = relative_difference(line.at(line.start), line.at(line.end));
+    if change <= options.tolerance {
+        0
+    } else if line.slope > 0.0 {
+        1
+    } else {
+        -1
+    }
+}
+
+/// Triangles, wedges, flags and pennants on four alternating pivots; `pole` is the pivot before
+/// them, the start of the move into the consolidation.
+fn consolidation<'a>(
+    pivots: &'a [SwingPoint],
+    pole: Option<&SwingPoint>,
+    options: &ChartPatternOptions,
+) -> Option<Candidate<'a>> {
+    if pivots.len() != 4 {
+        return None;
+    }
+    let highs: Vec<&SwingPoint> = pivots.iter().filter(|point| point.kind == SwingKind::High).collect();
+    let lows: Vec<&SwingPoint> = pivots.iter().filter(|point| point.kind == SwingKind::Low).collect();
+    let (start, end) = (pivots[0].index, pivots[3].index);
+    let line = |points: &[&SwingPoint]| PatternLine {
+        start,
+        end,
+        ..PatternLine::through(points[0].index, points[0].price, points[1].index, points[1].price)
+    };
+    let (upper, lower) = (line(&highs), line(&lows));
+    let (start_width, end_width) = (upper.at(start) - lower.at(start), upper.at(end) - lower.at(end));
+    if start_width <= 0.0 || end_width <= 0.0 {
+        return None;
+    }
+    let (upper_slope, lower_slope) = (slope_sign(&upper, options), slope_sign(&lower, options));
+    let converging = end_width < start_width;
+
+    if let Some(pole) = pole {
+        let pole_height = pivots[0].price - pole.price;
+        if pole_height.abs() >= options.pole_ratio * start_width {
+            let direction = if pole_height > 0.0 { PatternDirection::Bullish } else { PatternDirection::Bearish };
+            let against_pole = if pole_height > 0.0 {
+                upper_slope <= 0 && lower_slope <= 0
+            } else {
+                upper_slope >= 0 && lower_slope >= 0
+            };
+            let pattern = if upper_slope < 0 && lower_slope > 0 {
+                Some(ChartPattern::Pennant)
+            } else if against_pole {
+                Some(ChartPattern::Flag)
+            } else {
+                None
+            };
+            if let Some(pattern) = pattern {
+                return Some(Candidate { pattern, direction, pivots, upper, lower, height: pole_height.abs() });
+            }
+        }
+    }
+
+    let (pattern, direction) = match (upper_slope, lower_slope) {
+        (0, 1) => (ChartPattern::AscendingTriangle, PatternDirection::Bullish),
+        (-1, 0) => (ChartPattern::DescendingTriangle, PatternDirection::Bearish),
+        (-1, 1) => (ChartPattern::SymmetricalTriangle, PatternDirection::Neutral),
+        (1, 1) if converging => (ChartPattern::RisingWedge, PatternDirection::Bearish),
+        (-1, -1) if converging => (ChartPattern::FallingWedge, PatternDirection::Bullish),
+        _ => return None,
+    };
+    Some(Candidate { pattern, direction, pivots, upper, lower, height: start_width })
+}
+
+/// Looks for the breakout of a candidate and measures its target.
+fn complete(candidate: Candidate, closes: &[f64]) -> ChartPatternHit {
+    let from = candidate.pivots[candidate.pivots.len() - 1].index + 1;
+    let (upper, lower) = (&candidate.upper, &candidate.lower);
+    let breakout = (from..closes.len()).find_map(|i| {
+        if closes[i] > upper.at(i) {
+            Some((i, PatternDirection::Bullish))
+        } else if closes[i] < lower.at(i) {
+            Some((i, PatternDirection::Bearish))
+        } else {
+            None
+        }
+    });
+
+    let direction = match (candidate.direction, breakout) {
+        (PatternDirection::Neutral, Some((_, side))) => side,
+        (direction, _) => direction,
+    };
+    let confirmed = breakout.is_some_and(|(_, side)| side == direction);
+    let anchor = match breakout {
+        Some((index, _)) if confirmed => index,
+        _ => closes.len() - 1,
+    };
+    let target = match direction {
+        PatternDirection::Bullish => Some(upper.at(anchor) + candidate.height),
+        PatternDirection::Bearish => Some(lower.at(anchor) - candidate.height),
+        PatternDirection::Neutral => None,
+    };
+    let end = breakout.map_or(closes.len() - 1, |(index, _)| index);
+
+    ChartPatternHit {
+        pattern: candidate.pattern,
+        direction,
+        pivots: candidate.pivots.to_vec(),
+        upper: PatternLine { end, ..candidate.upper },
+        lower: PatternLine { end, ..candidate.lower },
+        breakout: breakout.map(|(index, _)| index),
+        confirmed,
+        target,
+    }
+}
+
+/// Chart patterns on alternating swing points, ordered by their last pivot.
+///
+/// Double tops and bottoms that are part of a triple top or bottom are not reported on their own.
+pub fn chart_patterns(pivots: &[SwingPoint], closes: &[f64], options: &ChartPatternOptions) -> Vec<ChartPatternHit> {
+    let mut candidates: Vec<Candidate> = Vec::new();
+    for k in 0..pivots.len() {
+        let window = |n: usize| pivots.get(k..k + n);
+        candidates.extend(window(3).and_then(|w| double_extreme(w, options)));
+        candidates.extend(window(5).and_then(|w| five_point_reversal(w, options)));
+        candidates.extend(window(4).and_then(|w| consolidation(w, k.checked_sub(1).map(|p| &pivots[p]), options)));
+    }
+
+    let triples: Vec<&[SwingPoint]> = candidates
+        .iter()
+        .filter(|c| matches!(c.pattern, ChartPattern::TripleTop | ChartPattern::TripleBottom))
+        .map(|c| c.pivots)
+        .collect();
+    candidates.retain(|candidate| {
+        !matches!(candidate.pattern, ChartPattern::DoubleTop | ChartPattern::DoubleBottom)
+            || !triples.iter().any(|triple| {
+                candidate.pivots.iter().all(|pivot| triple.iter().any(|other| other.index == pivot.index))
+            })
+    });
+    candidates.sort_by_key(|candidate| candidate.pivots[candidate.pivots.len() - 1].index);
+
+    candidates.into_iter().map(|candidate| complete(candidate, closes)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pivots(points: &[(usize, f64)]) -> Vec<SwingPoint> {
+        let first_high = points[0].1 > points[1].1;
+        points
+            .iter()
+            .enumerate()
+            .map(|(i, &(index, price))| SwingPoint {
+                index,
+                date: index.to_string(),
+                price,
+                kind: if (i % 2 == 0) == first_high { SwingKind::High } else { SwingKind::Low },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_double_top_breakout_and_target() {
+        let points = pivots(&[(2, 110.0), (5, 100.0), (8, 110.5)]);
+        let mut closes = vec![105.0; 12];
+        closes[10] = 99.0;
+        let hits = chart_patterns(&points, &closes, &ChartPatternOptions::default());
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].pattern, ChartPattern::DoubleTop);
+        assert_eq!(hits[0].breakout, Some(10));
+        assert!(hits[0].confirmed);
+        assert_eq!(hits[0].target, Some(89.5));
+    }
+
+    #[test]
+    fn test_head_and_shoulders() {
+        let points = pivots(&[(2, 110.0), (4, 100.0), (6, 120.0), (8, 102.0), (10, 111.0)]);
+        let closes = vec![105.0; 12];
+        let hits = chart_patterns(&points, &closes, &ChartPatternOptions::default());
+
+        let hit = hits.iter().find(|hit| hit.pattern == ChartPattern::HeadAndShoulders).unwrap();
+        assert_eq!(hit.direction, PatternDirection::Bearish);
+        assert!(hit.breakout.is_none());
+        // Neckline at the head: 101, so the height is 19; projected from the neckline on the last
+        // candle (103.5).
+        assert!((hit.lower.at(6) - 101.0).abs() < 1e-9);
+        assert!((hit.target.unwrap() - (103.5 - 19.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_triple_bottom_hides_its_double_bottoms() {
+        let points = pivots(&[(2, 90.0), (4, 100.0), (6, 90.5), (8, 101.0), (10, 90.2)]);
+        let hits = chart_patterns(&points, &[95.0; 12], &ChartPatternOptions::default());
+        let patterns: Vec<ChartPattern> = hits.iter().map(|hit| hit.pattern).collect();
+        assert_eq!(patterns, vec![ChartPattern::TripleBottom]);
+    }
+
+    #[test]
+    fn test_ascending_triangle_and_bull_flag() {
+        let triangle = pivots(&[(2, 100.0), (4, 90.0), (6, 100.5), (8, 95.0)]);
+        let hits = chart_patterns(&triangle, &[97.0; 12], &ChartPatternOptions::default());
+        let hit = hits.iter().find(|hit| hit.pattern == ChartPattern::AscendingTriangle).unwrap();
+        assert_eq!(hit.direction, PatternDirection::Bullish);
+
+        // A 30-point pole into a slightly falling 5-point channel.
+        let flag = pivots(&[(0, 70.0), (2, 100.0), (4, 95.0), (6, 98.0), (8, 93.0)]);
+        let mut closes = vec![95.0; 12];
+        closes[10] = 99.0;
+        let hits = chart_patterns(&flag, &closes, &ChartPatternOptions::default());
+        let hit = hits.iter().find(|hit| hit.pattern == ChartPattern::Flag).unwrap();
+        assert_eq!(hit.direction, PatternDirection::Bullish);
+        assert!(hit.confirmed);
+        assert!((hit.target.unwrap() - (hit.upper.at(10) + 30.0)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::structs::crossover_struct::{LevelCrossing, LevelCrossingKind, LineCrossing, LineCrossingKind};
+
+/// Overbought/oversold crossings of `values`, in order.
+///
+/// A level is entered when the value moves strictly beyond it and exited when it comes back to or
+/// inside it. NaN values (warm-up) are skipped and never produce an event.
+pub fn level_crossings(values: &[f64], overbought: f64, oversold: f64) -> Vec<LevelCrossing> {
+    let mut crossings = Vec::new();
+    let mut previous: Option<f64> = None;
+
+    for (index, &value) in values.iter().enumerate() {
+        if value.is_nan() {
+            continue;
+        }
+        if let Some(previous) = previous {
+            let mut push = |kind| crossings.push(LevelCrossing { index, kind, value });
+            if previous <= overbought && value > overbought {
+                push(LevelCrossingKind::EnterOverbought);
+            } else if previous > overbought && value <= overbought {
+                push(LevelCrossingKind::ExitOverbought);
+            }
+            if previous >= oversold && value < oversold {
+                push(LevelCrossingKind::EnterOversold);
+            } else if previous < oversold && value >= oversold {
+                push(LevelCrossingKind::ExitOversold);
+            }
+        }
+        previous = Some(value);
+    }
+
+    crossings
+}
+
+/// Crossings of `first` over `second`, in order.
+///
+/// Touching lines don't cross: the first line has to move from strictly below (above) to strictly
+/// above (below), possibly through bars where both are equal. Bars where either line is NaN are skipped.
+pub fn line_crossings(first: &[f64], second: &[f64]) -> Vec<LineCrossing> {
+    let mut crossings = Vec::new();
+    let mut previous_side = 0.0;
+
+    for (index, (a, b)) in first.iter().zip(second).enumerate() {
+        let difference = a - b;
+        if difference.is_nan() || difference == 0.0 {
+            continue;
+        }
+        let side = difference.signum();
+        if previous_side != 0.0 && side != previous_side {
+            let kind = if side > 0.0 { LineCrossingKind::Bullish } else { LineCrossingKind::Bearish };
+            crossings.push(LineCrossing { index, kind });
+        }
+        previous_side = side;
+    }
+
+    crossings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_crossings() {
+        let values = [f64::NAN, 50.0, 85.0, 90.0, 80.0, 10.0, 30.0];
+        let kinds: Vec<(usize, LevelCrossingKind)> = level_crossings(&values, 80.0, 20.0)
+            .into_iter()
+            .map(|crossing| (crossing.index, crossing.kind))
+            .collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                (2, LevelCrossingKind::EnterOverbought),
+                (4, LevelCrossingKind::ExitOverbought),
+                (5, LevelCrossingKind::EnterOversold),
+                (6, LevelCrossingKind::ExitOversold),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_line_crossings() {
+        let first = [1.0, 2.0, 3.0, 3.0, 5.0, 1.0];
+        let second = [2.0, f64::NAN, 3.0, 3.0, 4.0, 2.0];
+        assert_eq!(
+            line_crossings(&first, &second),
+            vec![
+                LineCrossing { index: 4, kind: LineCrossingKind::Bullish },
+                LineCrossing { index: 5, kind: LineCrossingKind::Bearish },
+            ]
+        );
+    }
+}
@@ -0,0 +1,244 @@
+pub const MILLIS_PER_MINUTE: i64 = 60_000;
+pub const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// Days since 1970-01-01 for a proleptic Gregorian date (Howard Hinnant's `days_from_civil`).
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Inverse of `days_from_civil`: returns `(year, month, day)`.
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+fn parse_number(value: &str) -> Option<i64> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+/// Parses an ISO 8601 date or date-time into milliseconds since the Unix epoch (UTC).
+///
+/// Accepted forms: `YYYY-MM-DD`, `YYYY-MM-DDTHH:MM`, `YYYY-MM-DDTHH:MM:SS[.fff]`,
+/// with a space allowed instead of `T` and an optional `Z` or `±HH:MM` offset.
+/// A plain integer is taken as milliseconds since the epoch. Returns `None` on anything else.
+pub fn parse_timestamp(value: &str) -> Option<i64> {
+    let value = value.trim();
+    if let Some(millis) = parse_number(value) {
+        return Some(millis);
+    }
+
+    let (date, time) = match value.find(['T', ' ']) {
+        Some(position) => (&value[..position], &value[position + 1..]),
+        None => (value, ""),
+    };
+
+    let mut date_parts = date.split('-');
+    let year = parse_number(date_parts.next()?)?;
+    let month = parse_number(date_parts.next()?)? as u32;
+    let day = parse_number(date_parts.next()?)? as u32;
+    if date_parts.next().is_some() || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let mut millis = days_from_civil(year, month, day) * MILLIS_PER_DAY;
+    if time.is_empty() {
+        return Some(millis);
+    }
+
+    // Split off the timezone designator.
+    let (clock, offset_minutes) = if let Some(clock) = time.strip_suffix('Z') {
+        (clock, 0)
+    } else if let Some(position) = time.rfind(['+', '-']) {
+        let sign = if time.as_bytes()[position] == b'-' { -1 } else { 1 };
+        let mut offset = time[position + 1..].split(':');
+        let hours = parse_number(offset.next()?)?;
+        let minutes = offset.next().map_or(Some(0), parse_number)?;
+        (&time[..position], sign * (hours * 60 + minutes))
+    } else {
+        (time, 0)
+    };
+
+    let mut clock_parts = clock.split(':');
+    let hours = parse_number(clock_parts.next()?)?;
+    let minutes = parse_number(clock_parts.next()?)?;
+    let (seconds, fraction) = match clock_parts.next() {
+        Some(seconds) => match seconds.split_once('.') {
+            Some((whole, fraction)) => (parse_number(whole)?, fraction),
+            None => (parse_number(seconds)?, ""),
+        },
+        None => (0, ""),
+    };
+    if hours > 23 || minutes > 59 || seconds > 60 {
+        return None;
+    }
+
+    let fraction_millis = if fraction.is_empty() {
+        0
+    } else {
+        let digits: String = fraction.chars().chain("000".chars()).take(3).collect();
+        parse_number(&digits)?
+    };
+
+    millis += ((hours * 60 + minutes) * 60 + seconds) * 1000 + fraction_millis;
+    Some(millis - offset_minutes * MILLIS_PER_MINUTE)
+}
+
+/// Formats milliseconds since the Unix epoch as an ISO 8601 UTC string.
+/// Midnight timestamps are formatted as a plain date (`YYYY-MM-DD`), matching daily inputs.
+pub fn format_timestamp(millis: i64) -> String {
+    let days = millis.div_euclid(MILLIS_PER_DAY);
+    let time = millis.rem_euclid(MILLIS_PER_DAY);
+    let (year, month, day) = civil_from_days(days);
+
+    if time == 0 {
+        return format!("{:04}-{:02}-{:02}", year, month, day);
+    }
+
+    let seconds = time / 1000;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        (seconds / 60) % 60,
+        seconds % 60,
+        time % 1000
+    )
+}
+
+/// Session number of a timestamp: the UTC day it falls in, after shifting the day boundary
+/// by `session_offset_minutes` (e.g. `-420` for sessions opening at 17:00 UTC).
+pub fn session_key(millis: i64, session_offset_minutes: i64) -> i64 {
+    (millis - session_offset_minutes * MILLIS_PER_MINUTE).div_euclid(MILLIS_PER_DAY)
+}
+
+/// Week number of a session key (days since the epoch), with weeks starting on Monday.
+pub fn week_key(day: i64) -> i64 {
+    // 1970-01-01 was a Thursday, three days after the Monday that starts its week.
+    (day + 3).div_euclid(7)
+}
+
+/// Month number of a session key (days since the epoch), counted from year 0.
+pub fn month_key(day: i64) -> i64 {
+    let (year, month, _) = civil_from_days(day);
+    year * 12 + month as i64 - 1
+}
+
+/// Splits a series of timestamps into consecutive sessions.
+/// Returns the inclusive `(start, end)` index range of each session, in order.
+pub fn session_ranges(timestamps: &[i64], session_offset_minutes: i64) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+
+    for i in 1..=timestamps.len() {
+        if i == timestamps.len()
+            || session_key(timestamps[i], session_offset_minutes) != session_key(timestamps[start], session_offset_minutes)
+        {
+            ranges.push((start, i - 1));
+            start = i;
+        }
+    }
+
+    ranges
+}
+
+/// Parses every date of a series, failing on the first one that can't be parsed.
+pub fn parse_timestamps(dates: &[String]) -> Result<Vec<i64>, String> {
+    dates
+        .iter()
+        .map(|date| parse_timestamp(date).ok_or_else(|| format!("Invalid date: {}", date)))
+        .collect()
+}
+
+/// Typical spacing of a series of timestamps: the median of the positive gaps between
+/// consecutive bars, so that weekends and missing bars don't skew it. `None` with fewer than two bars.
+pub fn bar_interval(timestamps: &[i64]) -> Option<i64> {
+    let mut gaps: Vec<i64> = timestamps
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .filter(|&gap| gap > 0)
+        .collect();
+    if gaps.is_empty() {
+        return None;
+    }
+    gaps.sort_unstable();
+    Some(gaps[gaps.len() / 2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(parse_timestamp("1970-01-01"), Some(0));
+        assert_eq!(parse_timestamp("2024-01-02"), Some(1_704_153_600_000));
+        assert_eq!(parse_timestamp("2024-01-02T10:30:00Z"), Some(1_704_191_400_000));
+        assert_eq!(parse_timestamp("2024-01-02 10:30"), Some(1_704_191_400_000));
+        assert_eq!(parse_timestamp("2024-01-02T12:30:00+02:00"), Some(1_704_191_400_000));
+        assert_eq!(parse_timestamp("2024-01-02T10:30:00.250Z"), Some(1_704_191_400_250));
+        assert_eq!(parse_timestamp("not a date"), None);
+        assert_eq!(parse_timestamp("2024-13-01"), None);
+    }
+
+    #[test]
+    fn test_format_timestamp_round_trip() {
+        assert_eq!(format_timestamp(1_704_153_600_000), "2024-01-02");
+        assert_eq!(format_timestamp(1_704_191_400_250), "2024-01-02T10:30:00.250Z");
+        for days in [-1000, 0, 365, 11_016, 20_000] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn test_session_key_with_offset() {
+        let evening = parse_timestamp("2024-01-02T18:00:00Z").unwrap();
+        let morning = parse_timestamp("2024-01-03T09:00:00Z").unwrap();
+        assert_ne!(session_key(evening, 0), session_key(morning, 0));
+        assert_eq!(session_key(evening, -420), session_key(morning, -420));
+    }
+
+    #[test]
+    fn test_session_ranges() {
+        let timestamps: Vec<i64> = ["2024-01-02T10:00Z", "2024-01-02T11:00Z", "2024-01-03T10:00Z"]
+            .iter()
+            .map(|date| parse_timestamp(date).unwrap())
+            .collect();
+        assert_eq!(session_ranges(&timestamps, 0), vec![(0, 1), (2, 2)]);
+        assert!(session_ranges(&[], 0).is_empty());
+    }
+
+    #[test]
+    fn test_week_and_month_keys() {
+        let monday = days_from_civil(2024, 1, 1);
+        assert_eq!(week_key(monday), week_key(monday + 6));
+        assert_ne!(week_key(monday), week_key(monday - 1));
+        assert_eq!(month_key(days_from_civil(2024, 1, 31)) + 1, month_key(days_from_civil(2024, 2, 1)));
+    }
+
+    #[test]
+    fn test_bar_interval_ignores_gaps() {
+        let day = MILLIS_PER_DAY;
+        assert_eq!(bar_interval(&[0, day, 2 * day, 5 * day, 6 * day]), Some(day));
+        assert_eq!(bar_interval(&[0]), None);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::helpers::swing_points_helper::swing_points;
+use crate::structs::divergence_struct::{Divergence, DivergenceKind, DivergenceOptions, DivergencePivot};
+
+/// Oscillator pivot closest to the price pivot `index`, within `max_offset` bars.
+fn matching_pivot(pivots: &[usize], index: usize, max_offset: usize) -> Option<usize> {
+    pivots
+        .iter()
+        .cloned()
+        .filter(|&pivot| pivot.abs_diff(index) <= max_offset)
+        .min_by_key(|&pivot| pivot.abs_diff(index))
+}
+
+/// Range of the non-NaN values between `from` and `to`, both included.
+fn range(values: &[f64], from: usize, to: usize) -> f64 {
+    let (min, max) = values[from..=to]
+        .iter()
+        .filter(|value| !value.is_nan())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| (min.min(value), max.max(value)));
+    max - min
+}
+
+/// Divergences of one pivot kind: `lows` selects the swing lows (bullish divergences).
+fn divergences_of_kind(
+    prices: &[f64],
+    oscillator: &[f64],
+    price_pivots: &[usize],
+    oscillator_pivots: &[usize],
+    lows: bool,
+    options: &DivergenceOptions,
+) -> Vec<Divergence> {
+    let mut divergences = Vec::new();
+
+    for pair in price_pivots.windows(2) {
+        let (first, second) = (pair[0], pair[1]);
+        let span = second - first;
+        if span < options.min_span || span > options.max_span {
+            continue;
+        }
+        let (Some(first_oscillator), Some(second_oscillator)) = (
+            matching_pivot(oscillator_pivots, first, options.max_offset),
+            matching_pivot(oscillator_pivots, second, options.max_offset),
+        ) else {
+            continue;
+        };
+        if first_oscillator >= second_oscillator {
+            continue;
+        }
+
+        let price_change = prices[second] - prices[first];
+        let oscillator_change = oscillator[second_oscillator] - oscillator[first_oscillator];
+        let kind = match (lows, price_change, oscillator_change) {
+            (true, p, o) if p < 0.0 && o > 0.0 => DivergenceKind::RegularBullish,
+            (true, p, o) if p > 0.0 && o < 0.0 => DivergenceKind::HiddenBullish,
+            (false, p, o) if p > 0.0 && o < 0.0 => DivergenceKind::RegularBearish,
+            (false, p, o) if p < 0.0 && o > 0.0 => DivergenceKind::HiddenBearish,
+            _ => continue,
+        };
+        if !options.hidden && matches!(kind, DivergenceKind::HiddenBullish | DivergenceKind::HiddenBearish) {
+            continue;
+        }
+
+        let relative = |change: f64, range: f64| if range > 0.0 { change.abs() / range } else { 0.0 };
+        let oscillator_range = range(oscillator, first_oscillator.min(first), second_oscillator.max(second));
+        divergences.push(Divergence {
+            kind,
+            price: [
+                DivergencePivot { index: first, value: prices[first] },
+                DivergencePivot { index: second, value: prices[second] },
+            ],
+            oscillator: [
+                DivergencePivot { index: first_oscillator, value: oscillator[first_oscillator] },
+                DivergencePivot { index: second_oscillator, value: oscillator[second_oscillator] },
+            ],
+            span,
+            strength: relative(price_change, range(prices, first, second))
+                + relative(oscillator_change, oscillator_range),
+        });
+    }
+
+    divergences
+}
+
+/// Right-aligns an oscillator shorter than the `len` prices (RSI and the like drop their warm-up)
+/// by NaN-padding its front, so that its last value stays on the last price.
+pub fn align_oscillator(oscillator: &[f64], len: usize) -> Vec<f64> {
+    let mut aligned = vec![f64::NAN; len.saturating_sub(oscillator.len())];
+    aligned.extend_from_slice(oscillator);
+    aligned
+}
+
+/// Regular and hidden divergences between a price series and an oscillator of the same length,
+/// ordered by their second price pivot.
+///
+/// Swing points are detected on each series separately; NaN values (an oscillator's warm-up) are
+/// never pivots.
+pub fn divergences(prices: &[f64], oscillator: &[f64], options: &DivergenceOptions) -> Vec<Divergence> {
+    let (price_highs, price_lows) = swing_points(prices, prices, options.left, options.right, false);
+    let (oscillator_highs, oscillator_lows) =
+        swing_points(oscillator, oscillator, options.left, options.right, false);
+
+    let mut result = divergences_of_kind(prices, oscillator, &price_lows, &oscillator_lows, true, options);
+    result.extend(divergences_of_kind(prices, oscillator, &price_highs, &oscillator_highs, false, options));
+    result.sort_by_key(|divergence| divergence.price[1].index);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::relative_strength_index_helper::relative_strength_index;
+
+    fn options() -> DivergenceOptions {
+        DivergenceOptions { left: 1, right: 1, min_span: 2, ..DivergenceOptions::default() }
+    }
+
+    #[test]
+    fn test_regular_bullish_divergence() {
+        let prices = [10.0, 8.0, 9.0, 10.0, 7.0, 9.0];
+        let oscillator = [50.0, 30.0, 40.0, 45.0, 35.0, 50.0];
+        let result = divergences(&prices, &oscillator, &options());
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].kind, DivergenceKind::RegularBullish);
+        assert_eq!(result[0].price[0].index, 1);
+        assert_eq!(result[0].oscillator[1].value, 35.0);
+        assert_eq!(result[0].span, 3);
+        // Price falls 1 over a range of 3, the oscillator rises 5 over a range of 15.
+        assert!((result[0].strength - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hidden_bearish_divergence_and_offset_pivots() {
+        let prices = [1.0, 5.0, 4.0, 3.0, 4.5, 2.0, 1.5];
+        // The second oscillator top comes one bar after the price top.
+        let oscillator = [40.0, 70.0, 60.0, 50.0, 65.0, 80.0, 60.0];
+        let result = divergences(&prices, &oscillator, &options());
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].kind, DivergenceKind::HiddenBearish);
+        assert_eq!(result[0].oscillator[1].index, 5);
+
+        let regular_only = DivergenceOptions { hidden: false, ..options() };
+        assert!(divergences(&prices, &oscillator, &regular_only).is_empty());
+    }
+
+    #[test]
+    fn test_oscillator_warm_up_is_ignored() {
+        let prices = [10.0, 8.0, 9.0, 10.0, 7.0, 9.0];
+        let oscillator = [f64::NAN, f64::NAN, f64::NAN, 45.0, 35.0, 50.0];
+        assert!(divergences(&prices, &oscillator, &options()).is_empty());
+    }
+
+    #[test]
+    fn test_shorter_rsi_is_right_aligned() {
+        let prices = [20.0, 23.0, 26.0, 24.0, 21.0, 19.0, 20.0, 22.0, 23.0, 21.0, 18.0, 20.0, 18.0, 17.0];
+        let rsi = relative_strength_index(&prices, 4);
+        assert_eq!(rsi.len(), prices.len() - 4);
+
+        let oscillator = align_oscillator(&rsi, prices.len());
+        assert!(oscillator[3].is_nan());
+        assert_eq!(oscillator[prices.len() - 1], rsi[rsi.len() - 1]);
+
+        let result = divergences(&prices, &oscillator, &options());
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].kind, DivergenceKind::RegularBullish);
+        assert_eq!([result[0].price[0].index, result[0].price[1].index], [5, 10]);
+        assert_eq!([result[0].oscillator[0].index, result[0].oscillator[1].index], [6, 11]);
+    }
+}
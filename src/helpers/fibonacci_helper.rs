@@ -0,0 +1,182 @@
+use crate::helpers::swing_points_helper::swing_points;
+use crate::low_high_open_close_volume_date_to_array_helper::MarketDataResult;
+use crate::structs::fibonacci_struct::{
+    FibonacciLevel, FibonacciOptions, FibonacciResult, LevelReaction, LevelReactionKind, SwingAnchor,
+};
+
+/// Start, end and direction of the most recent swing: the last swing point and the last swing
+/// point of the opposite kind before it. The swing goes up (1) when it ends on a swing high and down
+/// (-1) when it ends on a swing low. `None` until both a swing high and a swing low are confirmed.
+pub fn latest_swing(highs: &[f64], lows: &[f64], window: usize) -> Option<(usize, usize, i8)> {
+    let (swing_highs, swing_lows) = swing_points(highs, lows, window, window, false);
+    let high = *swing_highs.last()?;
+    let low = *swing_lows.last()?;
+
+    if high > low {
+        return Some((low, high, 1));
+    }
+    if low > high {
+        return Some((high, low, -1));
+    }
+    // A flat bar is both: pair it with the closest swing point before it, whose kind gives the direction.
+    let previous_low = swing_lows.iter().rev().find(|&&i| i < high);
+    let previous_high = swing_highs.iter().rev().find(|&&i| i < high);
+    match (previous_low, previous_high) {
+        (Some(&start), Some(&previous)) if start >= previous => Some((start, high, 1)),
+        (_, Some(&start)) => Some((start, high, -1)),
+        (Some(&start), None) => Some((start, high, 1)),
+        (None, None) => None,
+    }
+}
+
+/// 1 when the swing from `start` to `end` goes up, -1 when it goes down.
+///
+/// Compares the whole bars (their midpoints) rather than the highs alone, so a wide bar on the swing
+/// low doesn't turn a swing up into a swing down.
+pub fn swing_direction(highs: &[f64], lows: &[f64], start: usize, end: usize) -> i8 {
+    if highs[end] - lows[start] >= highs[start] - lows[end] { 1 } else { -1 }
+}
+
+/// Offsets of the Fibonacci time zones (0, 1, 2, 3, 5, 8, ...) lower than `limit`.
+pub fn time_zone_offsets(limit: usize) -> Vec<usize> {
+    let mut offsets = vec![];
+    let (mut current, mut next) = (0, 1);
+    while current < limit {
+        if offsets.last() != Some(&current) {
+            offsets.push(current);
+        }
+        (current, next) = (next, current + next);
+    }
+    offsets
+}
+
+/// Bars after the swing that touched a level (within `tolerance` percent of its price) and closed
+/// back on the side they came from.
+pub fn level_reactions(
+    market_data: &MarketDataResult,
+    levels: &[FibonacciLevel],
+    from: usize,
+    tolerance: f64,
+) -> Vec<LevelReaction> {
+    let mut reactions = Vec::new();
+
+    for i in from.max(1)..market_data.closes.len() {
+        let (close, previous_close) = (market_data.closes[i], market_data.closes[i - 1]);
+        for level in levels {
+            let margin = level.price.abs() * tolerance / 100.0;
+            let above = close > level.price && previous_close > level.price;
+            let below = close < level.price && previous_close < level.price;
+            let kind = if above && market_data.lows[i] <= level.price + margin {
+                LevelReactionKind::Support
+            } else if below && market_data.highs[i] >= level.price - margin {
+                LevelReactionKind::Resistance
+            } else {
+                continue;
+            };
+            reactions.push(LevelReaction { index: i, ratio: level.ratio, price: level.price, kind });
+        }
+    }
+
+    reactions
+}
+
+/// Fibonacci levels of the swing from `start` to `end`, going up when `direction` is 1.
+///
+/// Retracements are measured back from the end of the swing and extensions forward from its start,
+/// so a 1.618 extension of a swing up sits above its high.
+pub fn fibonacci_levels(
+    market_data: &MarketDataResult,
+    start: usize,
+    end: usize,
+    direction: i8,
+    options: &FibonacciOptions,
+) -> FibonacciResult {
+    let anchor = |index: usize, high: bool| SwingAnchor {
+        index,
+        price: if high { market_data.highs[index] } else { market_data.lows[index] },
+        date: market_data.dates[index].clone(),
+    };
+    let start_anchor = anchor(start, direction < 0);
+    let end_anchor = anchor(end, direction > 0);
+    let range = end_anchor.price - start_anchor.price;
+
+    let retracements: Vec<FibonacciLevel> = options
+        .retracements
+        .iter()
+        .map(|&ratio| FibonacciLevel { ratio, price: end_anchor.price - ratio * range })
+        .collect();
+    let extensions: Vec<FibonacciLevel> = options
+        .extensions
+        .iter()
+        .map(|&ratio| FibonacciLevel { ratio, price: start_anchor.price + ratio * range })
+        .collect();
+
+    let levels: Vec<FibonacciLevel> = retracements.iter().chain(&extensions).cloned().collect();
+    let reactions = level_reactions(market_data, &levels, end + 1, options.tolerance);
+    let time_zones = time_zone_offsets(market_data.closes.len() - start)
+        .into_iter()
+        .map(|offset| start + offset)
+        .collect();
+
+    FibonacciResult {
+        start: start_anchor,
+        end: end_anchor,
+        direction,
+        retracements,
+        extensions,
+        time_zones,
+        reactions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::low_high_open_close_volume_date_to_array_helper::candles;
+
+    #[test]
+    fn test_latest_swing() {
+        let values = [5.0, 1.0, 4.0, 10.0, 8.0, 7.0];
+        assert_eq!(latest_swing(&values, &values, 1), Some((1, 3, 1)));
+        assert_eq!(latest_swing(&values[..3], &values[..3], 1), None);
+        let falling = [1.0, 10.0, 6.0, 2.0, 4.0];
+        assert_eq!(latest_swing(&falling, &falling, 1), Some((1, 3, -1)));
+    }
+
+    #[test]
+    fn test_swing_direction_with_a_wide_bar_on_the_swing_low() {
+        // The low bar's high is above the end's high, the swing still goes up from 90 to 105.
+        let highs = [110.0, 104.0, 105.0];
+        let lows = [90.0, 100.0, 103.0];
+        assert_eq!(swing_direction(&highs, &lows, 0, 2), 1);
+        assert_eq!(swing_direction(&[105.0, 110.0], &[103.0, 90.0], 0, 1), -1);
+    }
+
+    #[test]
+    fn test_time_zone_offsets() {
+        assert_eq!(time_zone_offsets(14), vec![0, 1, 2, 3, 5, 8, 13]);
+    }
+
+    #[test]
+    fn test_fibonacci_levels_of_a_swing_up() {
+        let data = candles(&[
+            (1.0, 2.0, 0.0, 1.0),
+            (9.0, 10.0, 8.0, 9.0),
+            (7.0, 9.0, 6.5, 7.0),
+            (7.0, 8.5, 6.0, 7.0),
+        ]);
+        let result = fibonacci_levels(&data, 0, 1, 1, &FibonacciOptions::default());
+
+        assert_eq!(result.direction, 1);
+        assert_eq!(result.start.price, 0.0);
+        assert_eq!(result.end.price, 10.0);
+        assert!((result.retracements[3].price - 3.82).abs() < 1e-9);
+        assert!((result.extensions[1].price - 16.18).abs() < 1e-9);
+        assert_eq!(result.time_zones, vec![0, 1, 2, 3]);
+        // Bar 3 holds the 38.2% level (6.18) and is rejected by the 23.6% one (7.64).
+        assert_eq!(
+            result.reactions.iter().map(|r| (r.index, r.ratio, r.kind)).collect::<Vec<_>>(),
+            vec![(3, 0.236, LevelReactionKind::Resistance), (3, 0.382, LevelReactionKind::Support)]
+        );
+    }
+}
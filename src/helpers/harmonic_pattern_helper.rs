@@ -0,0 +1,210 @@
+use crate::structs::candlestick_pattern_struct::PatternDirection;
+use crate::structs::harmonic_pattern_struct::{HarmonicHit, HarmonicPattern, HarmonicRatio};
+use crate::structs::swing_points_struct::SwingKind;
+use crate::structs::zigzag_struct::ZigZagPivot;
+
+/// Ratio between two legs of an XABCD structure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Leg {
+    /// Retracement of XA by AB.
+    AbXa,
+    /// Retracement of AB by BC.
+    BcAb,
+    /// Extension of BC by CD.
+    CdBc,
+    /// Position of D on XA, measured from A.
+    AdXa,
+    /// Extension of XA to C (Cypher).
+    XcXa,
+    /// Retracement of XC by CD (Cypher).
+    CdXc,
+}
+
+impl Leg {
+    fn name(self) -> &'static str {
+        match self {
+            Leg::AbXa => "AB/XA",
+            Leg::BcAb => "BC/AB",
+            Leg::CdBc => "CD/BC",
+            Leg::AdXa => "AD/XA",
+            Leg::XcXa => "XC/XA",
+            Leg::CdXc => "CD/XC",
+        }
+    }
+
+    fn ratio(self, [x, a, b, c, d]: [f64; 5]) -> f64 {
+        let (numerator, denominator) = match self {
+            Leg::AbXa => (b - a, a - x),
+            Leg::BcAb => (c - b, b - a),
+            Leg::CdBc => (d - c, c - b),
+            Leg::AdXa => (d - a, a - x),
+            Leg::XcXa => (c - x, a - x),
+            Leg::CdXc => (d - c, c - x),
+        };
+        if denominator == 0.0 { f64::NAN } else { (numerator / denominator).abs() }
+    }
+
+    /// Price of D for the ratio `ratio`, for the legs that end on D.
+    fn project(self, [x, a, b, c, _]: [f64; 5], ratio: f64) -> Option<f64> {
+        match self {
+            Leg::AdXa => Some(a - ratio * (a - x)),
+            Leg::CdBc => Some(c - ratio * (c - b)),
+            Leg::CdXc => Some(c - ratio * (c - x)),
+            _ => None,
+        }
+    }
+}
+
+/// A leg ratio and its `[min, max]` range.
+type RatioRule = (Leg, f64, f64);
+
+/// Standard ratio ranges of every pattern.
+const PATTERNS: [(HarmonicPattern, &[RatioRule]); 5] = [
+    (
+        HarmonicPattern::Gartley,
+        &[(Leg::AbXa, 0.618, 0.618), (Leg::BcAb, 0.382, 0.886), (Leg::CdBc, 1.272, 1.618), (Leg::AdXa, 0.786, 0.786)],
+    ),
+    (
+        HarmonicPattern::Bat,
+        &[(Leg::AbXa, 0.382, 0.5), (Leg::BcAb, 0.382, 0.886), (Leg::CdBc, 1.618, 2.618), (Leg::AdXa, 0.886, 0.886)],
+    ),
+    (
+        HarmonicPattern::Butterfly,
+        &[(Leg::AbXa, 0.786, 0.786), (Leg::BcAb, 0.382, 0.886), (Leg::CdBc, 1.618, 2.618), (Leg::AdXa, 1.272, 1.618)],
+    ),
+    (
+        HarmonicPattern::Crab,
+        &[(Leg::AbXa, 0.382, 0.618), (Leg::BcAb, 0.382, 0.886), (Leg::CdBc, 2.24, 3.618), (Leg::AdXa, 1.618, 1.618)],
+    ),
+    (
+        HarmonicPattern::Cypher,
+        &[(Leg::AbXa, 0.382, 0.618), (Leg::XcXa, 1.272, 1.414), (Leg::CdXc, 0.786, 0.786)],
+    ),
+];
+
+/// Relative distance of `value` outside `[min, max]`; NaN ratios never match.
+fn ratio_error(value: f64, min: f64, max: f64) -> f64 {
+    if value.is_nan() {
+        f64::INFINITY
+    } else if value < min {
+        (min - value) / min
+    } else if value > max {
+        (value - max) / max
+    } else {
+        0.0
+    }
+}
+
+/// Potential Reversal Zone: the D range of the leg anchored on X, narrowed to its overlap with the
+/// CD/BC projection when they overlap.
+fn reversal_zone(prices: [f64; 5], rules: &[RatioRule]) -> (f64, f64) {
+    let range = |leg: Leg| {
+        rules.iter().find(|rule| rule.0 == leg).map(|&(_, min, max)| {
+            let (from, to) = (leg.project(prices, min).unwrap(), leg.project(prices, max).unwrap());
+            (from.min(to), from.max(to))
+        })
+    };
+    let primary = range(Leg::AdXa).or_else(|| range(Leg::CdXc)).unwrap();
+    match range(Leg::CdBc) {
+        Some((low, high)) if low <= primary.1 && high >= primary.0 => (low.max(primary.0), high.min(primary.1)),
+        _ => primary,
+    }
+}
+
+/// Harmonic patterns on every five consecutive ZigZag pivots whose ratios are all within
+/// `tolerance` percent of their ranges.
+pub fn harmonic_patterns(pivots: &[ZigZagPivot], tolerance: f64) -> Vec<HarmonicHit> {
+    let mut hits = Vec::new();
+
+    for points in pivots.windows(5) {
+        let prices = [points[0].price, points[1].price, points[2].price, points[3].price, points[4].price];
+        let direction = match points[4].kind {
+            SwingKind::Low => PatternDirection::Bullish,
+            SwingKind::High => PatternDirection::Bearish,
+        };
+
+        for (pattern, rules) in PATTERNS.iter() {
+            let ratios: Vec<HarmonicRatio> = rules
+                .iter()
+                .map(|&(leg, min, max)| {
+                    let value = leg.ratio(prices);
+                    let error = ratio_error(value, min, max);
+                    HarmonicRatio { leg: leg.name().to_string(), value, min, max, error }
+                })
+                .collect();
+            if ratios.iter().any(|ratio| ratio.error > tolerance / 100.0) {
+                continue;
+            }
+
+            let (prz_low, prz_high) = reversal_zone(prices, rules);
+            hits.push(HarmonicHit {
+                pattern: *pattern,
+                direction,
+                points: points.to_vec(),
+                error: ratios.iter().map(|ratio| ratio.error).sum::<f64>() / ratios.len() as f64,
+                ratios,
+                prz_low,
+                prz_high,
+            });
+        }
+    }
+
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pivots(prices: &[f64]) -> Vec<ZigZagPivot> {
+        prices
+            .iter()
+            .enumerate()
+            .map(|(index, &price)| ZigZagPivot {
+                index,
+                date: index.to_string(),
+                price,
+                kind: if index % 2 == 0 { SwingKind::Low } else { SwingKind::High },
+                label: None,
+                confirmed: true,
+                confirmed_at: Some(index + 1),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bullish_gartley() {
+        // XA = 100, AB = 61.8, BC = 0.5 AB, D at 0.786 XA (CD/BC = 1.4), all within range.
+        let points = pivots(&[100.0, 200.0, 138.2, 169.1, 121.4]);
+        let hits = harmonic_patterns(&points, 1.0);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].pattern, HarmonicPattern::Gartley);
+        assert_eq!(hits[0].direction, PatternDirection::Bullish);
+        assert!(hits[0].error < 0.01);
+        // D range of XA is the single price 121.4; the CD/BC range covers it.
+        assert!((hits[0].prz_low - 121.4).abs() < 1e-9);
+        assert!((hits[0].prz_high - 121.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ratios_outside_the_tolerance_do_not_match() {
+        let points = pivots(&[100.0, 200.0, 150.0, 169.1, 121.4]);
+        assert!(harmonic_patterns(&points, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_bearish_cypher() {
+        let mut points = pivots(&[200.0, 100.0, 150.0, 70.0, 170.0]);
+        for point in points.iter_mut() {
+            point.kind = if point.index % 2 == 0 { SwingKind::High } else { SwingKind::Low };
+        }
+        // AB/XA = 0.5, XC/XA = 1.3, CD/XC = 100 / 130 = 0.769 (2.2% below 0.786).
+        let hits = harmonic_patterns(&points, 5.0);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].pattern, HarmonicPattern::Cypher);
+        assert_eq!(hits[0].direction, PatternDirection::Bearish);
+        assert!((hits[0].prz_low - (70.0 + 0.786 * 130.0)).abs() < 1e-9);
+    }
+}
@@ -1,3 +1,7 @@
+use crate::helpers::crossover_helper::line_crossings;
+use crate::structs::crossover_struct::{LineCrossing, LineCrossingKind};
+use crate::structs::ichimoku_struct::{CloudPosition, IchimokuSignals, SignalStrength, TkCross};
+
 pub fn calculate_midline(highs: &[f64], lows: &[f64], period: usize) -> Vec<f64> {
     if highs.is_empty() || lows.is_empty() || period == 0 {
         return Vec::new();
@@ -41,8 +45,160 @@ pub fn shift_forward(series: &[f64], shift: usize) -> Vec<f64> {
     result
 }
 
+/// Shifts `series` back by `shift` bars, keeping its length; all NaN when `shift` is longer than it.
 pub fn shift_backward(series: &[f64], shift: usize) -> Vec<f64> {
-    let mut result = series[shift..].to_vec();
-    result.extend(std::iter::repeat(f64::NAN).take(shift));
+    let mut result = series.get(shift..).unwrap_or(&[]).to_vec();
+    result.resize(series.len(), f64::NAN);
+    result
+}
+
+/// Shifts `series` forward by `shift` bars without dropping its tail: the result is `shift` bars longer.
+pub fn extend_forward(series: &[f64], shift: usize) -> Vec<f64> {
+    let mut result = vec![f64::NAN; shift];
+    result.extend_from_slice(series);
     result
-}
\ No newline at end of file
+}
+
+/// Pads `series` with NaN up to `len` values.
+pub fn pad_to(series: &[f64], len: usize) -> Vec<f64> {
+    let mut result = series.to_vec();
+    result.resize(len.max(series.len()), f64::NAN);
+    result
+}
+
+/// Position of `price` relative to the cloud made of `span_a` and `span_b`.
+pub fn cloud_position(price: f64, span_a: f64, span_b: f64) -> Option<CloudPosition> {
+    if price.is_nan() || span_a.is_nan() || span_b.is_nan() {
+        return None;
+    }
+    Some(if price > span_a.max(span_b) {
+        CloudPosition::Above
+    } else if price < span_a.min(span_b) {
+        CloudPosition::Below
+    } else {
+        CloudPosition::Inside
+    })
+}
+
+/// Derives the per-bar Ichimoku signals of the first `closes.len()` bars.
+///
+/// The spans are the displaced ones, so `senkou_span_a[i]` is the cloud drawn on bar `i`.
+pub fn ichimoku_signals(
+    closes: &[f64],
+    tenkan_sen: &[f64],
+    kijun_sen: &[f64],
+    senkou_span_a: &[f64],
+    senkou_span_b: &[f64],
+    displacement: usize,
+) -> IchimokuSignals {
+    let len = closes.len();
+
+    let positions: Vec<Option<CloudPosition>> = (0..len)
+        .map(|i| cloud_position(closes[i], senkou_span_a[i], senkou_span_b[i]))
+        .collect();
+
+    let chikou_confirmation = (0..len)
+        .map(|i| {
+            if i < displacement {
+                0
+            } else if closes[i] > closes[i - displacement] {
+                1
+            } else if closes[i] < closes[i - displacement] {
+                -1
+            } else {
+                0
+            }
+        })
+        .collect();
+
+    let tk_crosses = line_crossings(tenkan_sen, kijun_sen)
+        .into_iter()
+        .map(|crossing| {
+            let i = crossing.index;
+            let cross_price = (tenkan_sen[i] + kijun_sen[i]) / 2.0;
+            let strength = match (cloud_position(cross_price, senkou_span_a[i], senkou_span_b[i]), crossing.kind) {
+                (Some(CloudPosition::Above), LineCrossingKind::Bullish)
+                | (Some(CloudPosition::Below), LineCrossingKind::Bearish) => SignalStrength::Strong,
+                (Some(CloudPosition::Above), LineCrossingKind::Bearish)
+                | (Some(CloudPosition::Below), LineCrossingKind::Bullish) => SignalStrength::Weak,
+                _ => SignalStrength::Neutral,
+            };
+            TkCross { crossing, strength }
+        })
+        .collect();
+
+    let mut kumo_breakouts = Vec::new();
+    for i in 1..len {
+        let (Some(previous), Some(current)) = (positions[i - 1], positions[i]) else {
+            continue;
+        };
+        if previous != current {
+            match current {
+                CloudPosition::Above => kumo_breakouts.push(LineCrossing { index: i, kind: LineCrossingKind::Bullish }),
+                CloudPosition::Below => kumo_breakouts.push(LineCrossing { index: i, kind: LineCrossingKind::Bearish }),
+                CloudPosition::Inside => {}
+            }
+        }
+    }
+
+    IchimokuSignals {
+        cloud_position: positions,
+        chikou_confirmation,
+        tk_crosses,
+        kumo_breakouts,
+        kumo_twists: line_crossings(senkou_span_a, senkou_span_b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extend_forward_keeps_the_tail() {
+        let result = extend_forward(&[1.0, 2.0], 2);
+        assert_eq!(result.len(), 4);
+        assert!(result[1].is_nan());
+        assert_eq!(&result[2..], &[1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_shift_backward_saturates() {
+        assert_eq!(&shift_backward(&[1.0, 2.0, 3.0], 1)[..2], &[2.0, 3.0]);
+        let result = shift_backward(&[1.0, 2.0], 5);
+        assert_eq!(result.len(), 2);
+        assert!(result.iter().all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn test_cloud_position() {
+        assert_eq!(cloud_position(12.0, 10.0, 11.0), Some(CloudPosition::Above));
+        assert_eq!(cloud_position(10.5, 11.0, 10.0), Some(CloudPosition::Inside));
+        assert_eq!(cloud_position(9.0, 10.0, 11.0), Some(CloudPosition::Below));
+        assert_eq!(cloud_position(9.0, f64::NAN, 11.0), None);
+    }
+
+    #[test]
+    fn test_ichimoku_signals() {
+        let closes = [10.0, 10.5, 12.0, 9.0];
+        let tenkan = [1.0, 3.0, 3.0, 1.0];
+        let kijun = [2.0, 2.0, 2.0, 2.0];
+        let span_a = [10.0, 10.0, 10.0, 10.0, 12.0];
+        let span_b = [11.0, 11.0, 11.0, 11.0, 11.0];
+        let signals = ichimoku_signals(&closes, &tenkan, &kijun, &span_a, &span_b, 2);
+
+        assert_eq!(signals.chikou_confirmation, vec![0, 0, 1, -1]);
+        assert_eq!(signals.tk_crosses.len(), 2);
+        // The crosses happen below the cloud.
+        assert_eq!(signals.tk_crosses[0].strength, SignalStrength::Weak);
+        assert_eq!(signals.tk_crosses[1].strength, SignalStrength::Strong);
+        assert_eq!(
+            signals.kumo_breakouts,
+            vec![
+                LineCrossing { index: 2, kind: LineCrossingKind::Bullish },
+                LineCrossing { index: 3, kind: LineCrossingKind::Bearish },
+            ]
+        );
+        assert_eq!(signals.kumo_twists, vec![LineCrossing { index: 4, kind: LineCrossingKind::Bullish }]);
+    }
+}
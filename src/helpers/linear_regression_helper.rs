@@ -0,0 +1,199 @@
+use crate::helpers::rolling_statistics_helper::rolling_mean;
+use crate::low_high_open_close_volume_date_to_array_helper::MarketDataResult;
+use crate::structs::linear_regression_struct::{
+    LinearRegressionResult, RegressionChannel, RegressionChannelOptions, RegressionFit, StandardErrorBandsResult,
+};
+
+/// Least squares line through `values`, x = 0 on the first one.
+pub fn fit_regression<I>(values: I) -> RegressionFit
+where
+    I: IntoIterator<Item = f64>,
+    I::IntoIter: Clone,
+{
+    let values = values.into_iter();
+    let (count, sum) = values.clone().fold((0usize, 0.0), |(count, sum), y| (count + 1, sum + y));
+    let n = count as f64;
+    let mean_x = (n - 1.0) / 2.0;
+    let mean_y = sum / n;
+
+    let (mut sxy, mut sxx, mut syy) = (0.0, 0.0, 0.0);
+    for (i, y) in values.clone().enumerate() {
+        let dx = i as f64 - mean_x;
+        let dy = y - mean_y;
+        sxy += dx * dy;
+        sxx += dx * dx;
+        syy += dy * dy;
+    }
+    let slope = if sxx == 0.0 { 0.0 } else { sxy / sxx };
+    let intercept = mean_y - slope * mean_x;
+    let residuals: f64 = values.enumerate().map(|(i, y)| (y - intercept - slope * i as f64).powi(2)).sum();
+
+    RegressionFit {
+        count,
+        slope,
+        intercept,
+        r_squared: if syy == 0.0 { 0.0 } else { (1.0 - residuals / syy).clamp(0.0, 1.0) },
+        standard_error: if count > 2 { (residuals / (n - 2.0)).sqrt() } else { 0.0 },
+    }
+}
+
+/// Regression of every window of `period` values ending on each index; `None` during the warm-up
+/// and on windows holding a NaN.
+fn rolling_fits(source: &[f64], period: usize) -> Vec<Option<RegressionFit>> {
+    (0..source.len())
+        .map(|i| {
+            if i + 1 < period {
+                return None;
+            }
+            let window = &source[i + 1 - period..=i];
+            if window.iter().any(|v| v.is_nan()) {
+                return None;
+            }
+            Some(fit_regression(window.iter().copied()))
+        })
+        .collect()
+}
+
+pub fn linear_regression(source: &[f64], period: usize) -> LinearRegressionResult {
+    let fits = rolling_fits(source, period);
+    let series = |value: fn(&RegressionFit) -> f64| -> Vec<f64> {
+        fits.iter().map(|fit| fit.as_ref().map_or(f64::NAN, value)).collect()
+    };
+
+    LinearRegressionResult {
+        slope: series(|fit| fit.slope),
+        intercept: series(|fit| fit.intercept),
+        value: series(|fit| fit.end_value()),
+        forecast: series(|fit| fit.at(fit.count as f64)),
+        r_squared: series(|fit| fit.r_squared),
+        standard_error: series(|fit| fit.standard_error),
+    }
+}
+
+/// Linear regression channel and Raff channel fitted through the last `period` values of `source`.
+///
+/// The Raff channel lines are parallel to the regression line, at the distance of the candle high
+/// or low that lies furthest from it.
+pub fn regression_channel(
+    market_data: &MarketDataResult,
+    source: &[f64],
+    options: &RegressionChannelOptions,
+) -> RegressionChannel {
+    let len = source.len();
+    let start = len - options.period;
+    let fit = fit_regression(source[start..].iter().copied());
+    let slope = fit.slope;
+    let intercept = fit.intercept - slope * start as f64;
+    let line = |i: usize| intercept + slope * i as f64;
+
+    let raff = (start..len)
+        .map(|i| (market_data.highs[i] - line(i)).max(line(i) - market_data.lows[i]))
+        .fold(0.0, f64::max);
+    let offset = options.deviations * fit.standard_error;
+    let lines = |distance: f64| -> Vec<f64> {
+        (0..len).map(|i| if i < start { f64::NAN } else { line(i) + distance }).collect()
+    };
+
+    RegressionChannel {
+        start,
+        end: len - 1,
+        slope,
+        intercept,
+        r_squared: fit.r_squared,
+        standard_error: fit.standard_error,
+        middle: lines(0.0),
+        upper: lines(offset),
+        lower: lines(-offset),
+        raff_upper: lines(raff),
+        raff_lower: lines(-raff),
+    }
+}
+
+/// Standard error bands: the rolling regression value ± `deviations` standard errors, all three
+/// lines smoothed by an SMA of `smoothing`.
+pub fn standard_error_bands(
+    source: &[f64],
+    period: usize,
+    deviations: f64,
+    smoothing: usize,
+) -> StandardErrorBandsResult {
+    let regression = linear_regression(source, period);
+    let band = |sign: f64| -> Vec<f64> {
+        let raw: Vec<f64> = regression
+            .value
+            .iter()
+            .zip(&regression.standard_error)
+            .map(|(value, error)| value + sign * deviations * error)
+            .collect();
+        rolling_mean(&raw, smoothing)
+    };
+
+    StandardErrorBandsResult { middle: band(0.0), upper: band(1.0), lower: band(-1.0) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::low_high_open_close_volume_date_to_array_helper::candles;
+
+    #[test]
+    fn test_fit_on_a_straight_line() {
+        let fit = fit_regression([3.0, 5.0, 7.0, 9.0]);
+        assert_eq!(fit.slope, 2.0);
+        assert_eq!(fit.intercept, 3.0);
+        assert_eq!(fit.end_value(), 9.0);
+        assert_eq!(fit.r_squared, 1.0);
+        assert_eq!(fit.standard_error, 0.0);
+    }
+
+    #[test]
+    fn test_fit_with_residuals() {
+        let fit = fit_regression([1.0, 3.0, 2.0, 4.0]);
+        assert!((fit.slope - 0.8).abs() < 1e-12);
+        assert!((fit.intercept - 1.3).abs() < 1e-12);
+        // Residuals -0.3, 0.9, -0.9, 0.3: SSE 1.8, SST 5.
+        assert!((fit.r_squared - (1.0 - 1.8 / 5.0)).abs() < 1e-12);
+        assert!((fit.standard_error - (1.8f64 / 2.0).sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rolling_regression_and_forecast() {
+        let source = [1.0, 2.0, 3.0, 5.0, 7.0];
+        let result = linear_regression(&source, 3);
+        assert!(result.slope[1].is_nan());
+        assert_eq!(result.slope[2], 1.0);
+        assert_eq!(result.value[2], 3.0);
+        assert_eq!(result.forecast[2], 4.0);
+        assert_eq!(result.slope[4], 2.0);
+        assert_eq!(result.intercept[4], 3.0);
+        assert_eq!(result.forecast[4], 9.0);
+    }
+
+    #[test]
+    fn test_channel_lines_are_on_the_candle_index() {
+        let closes = vec![10.0, 1.0, 2.0, 3.0, 4.0];
+        let lows = [10.0, 1.0, 0.0, 3.0, 4.0];
+        let rows: Vec<_> = closes.iter().zip(lows).map(|(&c, l)| (c, c + 0.5, l, c)).collect();
+        let options = RegressionChannelOptions { period: 4, ..RegressionChannelOptions::default() };
+        let channel = regression_channel(&candles(&rows), &closes, &options);
+
+        assert_eq!((channel.start, channel.end), (1, 4));
+        assert_eq!((channel.slope, channel.intercept), (1.0, 0.0));
+        assert!(channel.middle[0].is_nan());
+        assert_eq!(channel.middle[4], 4.0);
+        assert_eq!(channel.upper[4], 4.0);
+        // The low of candle 2 is 2 below the line.
+        assert_eq!(channel.raff_upper[3], 5.0);
+        assert_eq!(channel.raff_lower[3], 1.0);
+    }
+
+    #[test]
+    fn test_standard_error_bands_without_smoothing() {
+        let source = [1.0, 3.0, 2.0, 4.0];
+        let bands = standard_error_bands(&source, 4, 2.0, 1);
+        let error = (1.8f64 / 2.0).sqrt();
+        assert!((bands.middle[3] - 3.7).abs() < 1e-12);
+        assert!((bands.upper[3] - (3.7 + 2.0 * error)).abs() < 1e-12);
+        assert!((bands.lower[3] - (3.7 - 2.0 * error)).abs() < 1e-12);
+    }
+}
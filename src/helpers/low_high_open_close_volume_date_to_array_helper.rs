@@ -62,7 +62,32 @@ pub fn low_high_open_close_volume_date_to_array(data: JsValue) -> Result<JsValue
         .map_err(|err| JsValue::from_str(&format!("Failed to serialize result: {}", err)))
 }
 
+/// Converts an array of candles coming from JS into columnar `MarketDataResult`.
+/// Fails with "Not enough data." when the array is empty.
+pub fn market_data_from_js(data: JsValue) -> Result<MarketDataResult, JsValue> {
+    let processed_data = low_high_open_close_volume_date_to_array(data)?;
+    let market_data: MarketDataResult = crate::deserialize_js_value(&processed_data)?;
+
+    if market_data.closes.is_empty() {
+        return Err(crate::create_error("Not enough data."));
+    }
+
+    Ok(market_data)
+}
+
 pub fn low_high_open_close_volume_date_deserialize(segment: JsValue) -> MarketDataResult {
     let market_data_result: MarketDataResult = serde_wasm_bindgen::from_value(segment).expect("Failed to deserialize market data result");
     market_data_result
-}
\ No newline at end of file
+}
+/// Test candles from `(open, high, low, close)` rows, without volume and dated by their index.
+#[cfg(test)]
+pub fn candles(rows: &[(f64, f64, f64, f64)]) -> MarketDataResult {
+    MarketDataResult {
+        opens: rows.iter().map(|r| r.0).collect(),
+        highs: rows.iter().map(|r| r.1).collect(),
+        lows: rows.iter().map(|r| r.2).collect(),
+        closes: rows.iter().map(|r| r.3).collect(),
+        volumes: vec![0.0; rows.len()],
+        dates: (0..rows.len()).map(|i| i.to_string()).collect(),
+    }
+}
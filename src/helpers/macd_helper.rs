@@ -0,0 +1,24 @@
+use crate::helpers::moving_average_helper::moving_average;
+use crate::structs::moving_average_struct::{MacdResult, MovingAverageType};
+
+/// MACD: fast MA minus slow MA of the prices, smoothed by a signal MA.
+///
+/// Both averages use `kind`, the signal line uses `signal_kind`. All outputs are aligned with
+/// `prices` and NaN until the slow average (and then the signal) have warmed up.
+pub fn calculate_macd(
+    prices: &[f64],
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+    kind: MovingAverageType,
+    signal_kind: MovingAverageType,
+) -> MacdResult {
+    let fast = moving_average(prices, fast_period, kind);
+    let slow = moving_average(prices, slow_period, kind);
+
+    let macd: Vec<f64> = fast.iter().zip(&slow).map(|(f, s)| f - s).collect();
+    let signal = moving_average(&macd, signal_period, signal_kind);
+    let histogram = macd.iter().zip(&signal).map(|(m, s)| m - s).collect();
+
+    MacdResult { macd, signal, histogram }
+}
@@ -0,0 +1,180 @@
+use crate::helpers::date_helper::{format_timestamp, MILLIS_PER_MINUTE};
+use crate::helpers::volume_profile_helper::{point_of_control, value_area, PriceBins};
+use crate::low_high_open_close_volume_date_to_array_helper::MarketDataResult;
+use crate::structs::market_profile_struct::{MarketProfileOptions, PriceRange, TpoLevel, TpoPeriod, TpoProfile};
+
+/// Groups the candles `start..=end` into letter periods of `period_minutes`, counted from the first candle.
+/// Returns `(period_number, high, low, first_candle)` for every period that has candles.
+pub fn letter_periods(
+    highs: &[f64],
+    lows: &[f64],
+    timestamps: &[i64],
+    start: usize,
+    end: usize,
+    period_minutes: i64,
+) -> Vec<(usize, f64, f64, usize)> {
+    let period_millis = period_minutes * MILLIS_PER_MINUTE;
+    let mut periods: Vec<(usize, f64, f64, usize)> = Vec::new();
+
+    for i in start..=end {
+        let number = ((timestamps[i] - timestamps[start]).max(0) / period_millis) as usize;
+        match periods.last_mut() {
+            Some(period) if period.0 == number => {
+                period.1 = period.1.max(highs[i]);
+                period.2 = period.2.min(lows[i]);
+            }
+            _ => periods.push((number, highs[i], lows[i], i)),
+        }
+    }
+
+    periods
+}
+
+/// Runs of consecutive single-TPO rows that don't touch the extremes of the profile.
+fn single_print_runs(counts: &[usize]) -> Vec<(usize, usize)> {
+    let mut runs = Vec::new();
+    let mut run_start = None;
+
+    for (index, &count) in counts.iter().enumerate() {
+        match (count == 1, run_start) {
+            (true, None) => run_start = Some(index),
+            (false, Some(first)) => {
+                if first > 0 {
+                    runs.push((first, index - 1));
+                }
+                run_start = None;
+            }
+            _ => {}
+        }
+    }
+
+    runs
+}
+
+/// Builds the TPO profile of the session made of the candles `start..=end`; fails when the price
+/// grid is too large for the tick size.
+pub fn build_tpo_profile(
+    market_data: &MarketDataResult,
+    timestamps: &[i64],
+    start: usize,
+    end: usize,
+    options: &MarketProfileOptions,
+) -> Result<TpoProfile, String> {
+    let highs = &market_data.highs;
+    let lows = &market_data.lows;
+    let letters: Vec<char> = options.letters.chars().collect();
+    let periods = letter_periods(highs, lows, timestamps, start, end, options.period_minutes);
+
+    let high = highs[start..=end].iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let low = lows[start..=end].iter().cloned().fold(f64::INFINITY, f64::min);
+    let bins = PriceBins::new(low, high, 1, Some(options.tick_size))?;
+
+    let mut level_letters = vec![String::new(); bins.bin_count];
+    let mut counts = vec![0usize; bins.bin_count];
+    let mut initial_balance = (f64::NEG_INFINITY, f64::INFINITY);
+
+    let tpo_periods: Vec<TpoPeriod> = periods
+        .iter()
+        .map(|&(number, period_high, period_low, first_candle)| {
+            let letter = letters[number % letters.len()];
+            for index in bins.index_of(period_low)..=bins.index_of(period_high) {
+                level_letters[index].push(letter);
+                counts[index] += 1;
+            }
+            if number < options.initial_balance_periods {
+                initial_balance = (initial_balance.0.max(period_high), initial_balance.1.min(period_low));
+            }
+            TpoPeriod {
+                letter,
+                start_date: format_timestamp(timestamps[first_candle]),
+                high: period_high,
+                low: period_low,
+            }
+        })
+        .collect();
+
+    let weights: Vec<f64> = counts.iter().map(|&count| count as f64).collect();
+    let poc = point_of_control(&weights);
+    let (value_area_low, value_area_high) = value_area(&weights, poc, options.value_area);
+    let (initial_balance_high, initial_balance_low) = initial_balance;
+    let (ib_first, ib_last) = (bins.index_of(initial_balance_low), bins.index_of(initial_balance_high));
+    let last = bins.bin_count - 1;
+
+    let levels = level_letters
+        .into_iter()
+        .enumerate()
+        .map(|(index, letters)| TpoLevel {
+            price: bins.lower_edge(index),
+            tpo_count: counts[index],
+            single_print: counts[index] == 1,
+            in_value_area: (value_area_low..=value_area_high).contains(&index),
+            in_initial_balance: (ib_first..=ib_last).contains(&index),
+            letters,
+        })
+        .collect();
+
+    Ok(TpoProfile {
+        start_index: start,
+        end_index: end,
+        start_date: market_data.dates[start].clone(),
+        open: market_data.opens[start],
+        close: market_data.closes[end],
+        high,
+        low,
+        point_of_control: bins.lower_edge(poc),
+        value_area_high: bins.lower_edge(value_area_high),
+        value_area_low: bins.lower_edge(value_area_low),
+        initial_balance_high,
+        initial_balance_low,
+        poor_high: counts[last] > 1,
+        poor_low: counts[0] > 1,
+        single_prints: single_print_runs(&counts)
+            .into_iter()
+            .map(|(first, last)| PriceRange { low: bins.lower_edge(first), high: bins.lower_edge(last) })
+            .collect(),
+        periods: tpo_periods,
+        levels,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::low_high_open_close_volume_date_to_array_helper::candles;
+
+    #[test]
+    fn test_letter_periods_group_candles_by_time() {
+        let highs = [11.0, 12.0, 13.0, 12.5];
+        let lows = [10.0, 11.0, 12.0, 11.5];
+        // 15-minute candles, 30-minute periods, with a missing candle before the last one.
+        let timestamps = [0, 15 * MILLIS_PER_MINUTE, 30 * MILLIS_PER_MINUTE, 75 * MILLIS_PER_MINUTE];
+        let periods = letter_periods(&highs, &lows, &timestamps, 0, 3, 30);
+
+        assert_eq!(periods, vec![(0, 12.0, 10.0, 0), (1, 13.0, 12.0, 2), (2, 12.5, 11.5, 3)]);
+    }
+
+    #[test]
+    fn test_build_tpo_profile() {
+        let market_data = candles(&[
+            (100.5, 102.0, 100.0, 101.5),
+            (102.0, 103.0, 101.0, 102.5),
+            (100.5, 101.0, 100.0, 100.5),
+        ]);
+        let timestamps = [0, 30 * MILLIS_PER_MINUTE, 60 * MILLIS_PER_MINUTE];
+        let options = MarketProfileOptions { tick_size: 1.0, ..MarketProfileOptions::default() };
+        let profile = build_tpo_profile(&market_data, &timestamps, 0, 2, &options).unwrap();
+
+        let letters: Vec<&str> = profile.levels.iter().map(|level| level.letters.as_str()).collect();
+        assert_eq!(letters, vec!["AC", "ABC", "AB", "B"]);
+        assert_eq!(profile.point_of_control, 101.0);
+        assert_eq!((profile.initial_balance_low, profile.initial_balance_high), (100.0, 103.0));
+        assert!(profile.poor_low);
+        assert!(!profile.poor_high);
+    }
+
+    #[test]
+    fn test_single_print_runs_exclude_tails() {
+        assert_eq!(single_print_runs(&[1, 2, 1, 1, 3, 1]), vec![(2, 3)]);
+        assert!(single_print_runs(&[1, 1, 2]).is_empty());
+    }
+}
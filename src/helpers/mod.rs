@@ -1,33 +1,70 @@
 use serde::de::DeserializeOwned;
-use serde_wasm_bindgen::{from_value, to_value};
+use serde_wasm_bindgen::{from_value, Serializer};
 use wasm_bindgen::JsValue;
 use wasm_bindgen::prelude::wasm_bindgen;
 
+pub mod adaptive_moving_average_helper;
 pub mod bollinger_bands_helper;
 pub mod calculate_atr_helper;
 pub mod calculate_ema_helper;
 pub mod calculate_sma_helper;
+pub mod candlestick_pattern_helper;
+pub mod chart_pattern_helper;
+pub mod crossover_helper;
+pub mod date_helper;
 pub mod directional_movement_index_helper;
+pub mod divergence_helper;
 pub mod entry_exit_signals_helper;
+pub mod fibonacci_helper;
+pub mod harmonic_pattern_helper;
 pub mod highest_lowest_helper;
 pub mod ichimoku_helper;
+pub mod linear_regression_helper;
 pub mod low_high_open_close_volume_date_to_array_helper;
+pub mod macd_helper;
+pub mod market_profile_helper;
+pub mod momentum_oscillators_helper;
+pub mod moving_average_helper;
 pub mod pivot_points_helper;
+pub mod price_source_helper;
 pub mod relative_strength_index_helper;
+pub mod rolling_statistics_helper;
 pub mod smooth_helper;
+pub mod stochastic_oscillator_helper;
 pub mod stochastic_momentum_index_helper;
+pub mod supertrend_helper;
+pub mod support_resistance_helper;
+pub mod swing_points_helper;
+pub mod trend_oscillators_helper;
+pub mod trendline_helper;
 pub mod vec_to_js_array_helper;
+pub mod volatility_helper;
+pub mod volume_flow_helper;
+pub mod volume_oscillators_helper;
+pub mod volume_profile_helper;
+pub mod zigzag_helper;
 
 pub fn deserialize_js_value<T: DeserializeOwned>(data: &JsValue) -> Result<T, JsValue> {
     from_value(data.clone())
         .map_err(|e| JsValue::from_str(&format!("Error deserializing input: {:?}", e)))
 }
 
+/// Serializes a result for JS. `Option::None` becomes `null` (not `undefined`), as the d.ts declares it.
 pub fn serialize_to_js_value<T>(value: &T) -> Result<wasm_bindgen::JsValue, JsValue>
 where
     T: ?Sized + serde::ser::Serialize,
 {
-    to_value(value).map_err(|e| JsValue::from_str(&format!("Error serializing result: {:?}", e)))
+    value
+        .serialize(&Serializer::new().serialize_missing_as_null(true))
+        .map_err(|e| JsValue::from_str(&format!("Error serializing result: {:?}", e)))
+}
+
+/// Deserializes an optional options object, falling back to the defaults when it is `undefined` or `null`.
+pub fn deserialize_options<T: DeserializeOwned + Default>(options: &JsValue) -> Result<T, JsValue> {
+    if options.is_undefined() || options.is_null() {
+        return Ok(T::default());
+    }
+    deserialize_js_value(options)
 }
 
 pub fn jsvalue_to_f64(js_value: JsValue) -> Vec<f64> {
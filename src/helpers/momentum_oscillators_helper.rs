@@ -0,0 +1,68 @@
+use crate::helpers::highest_lowest_helper::calculate_high_low;
+
+/// Williams %R: where `source` sits inside the high–low range of the last `period` bars, from 0
+/// (at the highest high) to -100 (at the lowest low).
+///
+/// The first `period - 1` values are NaN, and a flat window yields -50.
+pub fn williams_r(highs: &[f64], lows: &[f64], source: &[f64], period: usize) -> Vec<f64> {
+    let mut result = vec![f64::NAN; source.len()];
+
+    for i in period.saturating_sub(1)..source.len() {
+        let (highest_high, lowest_low) = calculate_high_low(highs, lows, i + 1 - period, i);
+        let range = highest_high - lowest_low;
+        result[i] = if range == 0.0 {
+            -50.0
+        } else {
+            -100.0 * (highest_high - source[i]) / range
+        };
+    }
+
+    result
+}
+
+/// Commodity Channel Index: `(source - SMA) / (constant * mean absolute deviation)` over `period` bars.
+///
+/// The first `period - 1` values are NaN, and a window without deviation yields 0.
+pub fn commodity_channel_index(source: &[f64], period: usize, constant: f64) -> Vec<f64> {
+    let mut result = vec![f64::NAN; source.len()];
+
+    for i in period.saturating_sub(1)..source.len() {
+        let window = &source[i + 1 - period..=i];
+        let mean = window.iter().sum::<f64>() / period as f64;
+        let mean_deviation = window.iter().map(|value| (value - mean).abs()).sum::<f64>() / period as f64;
+        result[i] = if mean_deviation == 0.0 {
+            0.0
+        } else {
+            (source[i] - mean) / (constant * mean_deviation)
+        };
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_williams_r() {
+        let highs = [10.0, 12.0, 11.0];
+        let lows = [8.0, 9.0, 9.0];
+        let closes = [9.0, 12.0, 9.5];
+        let result = williams_r(&highs, &lows, &closes, 2);
+
+        assert!(result[0].is_nan());
+        assert_eq!(result[1], 0.0);
+        assert_eq!(result[2], -100.0 * 2.5 / 3.0);
+        assert_eq!(williams_r(&[5.0], &[5.0], &[5.0], 1)[0], -50.0);
+    }
+
+    #[test]
+    fn test_commodity_channel_index_uses_mean_absolute_deviation() {
+        let result = commodity_channel_index(&[1.0, 2.0, 6.0], 3, 0.015);
+        // Mean 3, mean absolute deviation (2 + 1 + 3) / 3 = 2.
+        assert!(result[1].is_nan());
+        assert!((result[2] - 3.0 / (0.015 * 2.0)).abs() < 1e-9);
+        assert_eq!(commodity_channel_index(&[4.0, 4.0], 2, 0.015)[1], 0.0);
+    }
+}
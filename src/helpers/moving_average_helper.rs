@@ -0,0 +1,376 @@
+use std::collections::VecDeque;
+
+use crate::helpers::linear_regression_helper::fit_regression;
+use crate::structs::moving_average_struct::{MovingAverageOptions, MovingAverageType};
+
+/// Fixed-size window of the latest values (and their volumes).
+#[derive(Clone, Debug)]
+struct Window {
+    period: usize,
+    values: VecDeque<f64>,
+    volumes: VecDeque<f64>,
+}
+
+impl Window {
+    fn new(period: usize) -> Self {
+        Window {
+            period,
+            values: VecDeque::with_capacity(period + 1),
+            volumes: VecDeque::with_capacity(period + 1),
+        }
+    }
+
+    /// Adds a value and returns `true` once the window is full.
+    fn push(&mut self, value: f64, volume: f64) -> bool {
+        self.values.push_back(value);
+        self.volumes.push_back(volume);
+        if self.values.len() > self.period {
+            self.values.pop_front();
+            self.volumes.pop_front();
+        }
+        self.values.len() == self.period
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Sma {
+    window: Window,
+    sum: f64,
+}
+
+impl Sma {
+    fn new(period: usize) -> Self {
+        Sma { window: Window::new(period), sum: 0.0 }
+    }
+
+    fn next(&mut self, value: f64) -> f64 {
+        if value.is_nan() {
+            return f64::NAN;
+        }
+        self.sum += value;
+        if self.window.values.len() == self.window.period {
+            self.sum -= self.window.values[0];
+        }
+        if self.window.push(value, 1.0) {
+            self.sum / self.window.period as f64
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Wma {
+    window: Window,
+}
+
+impl Wma {
+    fn new(period: usize) -> Self {
+        Wma { window: Window::new(period) }
+    }
+
+    fn next(&mut self, value: f64) -> f64 {
+        if value.is_nan() || !self.window.push(value, 1.0) {
+            return f64::NAN;
+        }
+        let weighted: f64 = self.window.values.iter().enumerate().map(|(i, v)| (i + 1) as f64 * v).sum();
+        let period = self.window.period as f64;
+        weighted / (period * (period + 1.0) / 2.0)
+    }
+}
+
+/// Exponential smoothing with factor `alpha`, seeded with the SMA of the first `period` values.
+#[derive(Clone, Debug)]
+struct SeededEma {
+    alpha: f64,
+    period: usize,
+    count: usize,
+    seed_sum: f64,
+    value: f64,
+}
+
+impl SeededEma {
+    fn new(period: usize, alpha: f64) -> Self {
+        SeededEma { alpha, period, count: 0, seed_sum: 0.0, value: f64::NAN }
+    }
+
+    fn exponential(period: usize) -> Self {
+        Self::new(period, 2.0 / (period as f64 + 1.0))
+    }
+
+    fn wilder(period: usize) -> Self {
+        Self::new(period, 1.0 / period as f64)
+    }
+
+    fn next(&mut self, value: f64) -> f64 {
+        if value.is_nan() {
+            return f64::NAN;
+        }
+        if self.count < self.period {
+            self.count += 1;
+            self.seed_sum += value;
+            if self.count == self.period {
+                self.value = self.seed_sum / self.period as f64;
+            }
+            return self.value;
+        }
+        self.value += self.alpha * (value - self.value);
+        self.value
+    }
+}
+
+/// End point of the least squares line fitted through the window (x = 0 for the oldest value).
+fn linear_regression_end_point(values: &VecDeque<f64>) -> f64 {
+    fit_regression(values.iter().copied()).end_value()
+}
+
+fn alma_weights(period: usize, offset: f64, sigma: f64) -> Vec<f64> {
+    let m = offset * (period as f64 - 1.0);
+    let s = period as f64 / sigma;
+    let weights: Vec<f64> = (0..period)
+        .map(|i| (-((i as f64 - m).powi(2)) / (2.0 * s * s)).exp())
+        .collect();
+    let total: f64 = weights.iter().sum();
+    weights.into_iter().map(|w| w / total).collect()
+}
+
+#[derive(Clone, Debug)]
+enum Inner {
+    Sma(Sma),
+    Ema(SeededEma),
+    Wma(Wma),
+    Smma(SeededEma),
+    Dema(SeededEma, SeededEma),
+    Tema(SeededEma, SeededEma, SeededEma),
+    Trima(Sma, Sma),
+    Hma(Wma, Wma, Wma),
+    Zlema { lag: Window, ema: SeededEma },
+    Alma { window: Window, weights: Vec<f64> },
+    Lsma(Window),
+    T3 { emas: Vec<SeededEma>, coefficients: [f64; 4] },
+    Vwma(Window),
+}
+
+/// Streaming moving average of any `MovingAverageType`.
+///
+/// Every kind returns NaN until it has received enough values; NaN inputs return NaN
+/// and leave the state untouched, so series with a warm-up period can be smoothed directly.
+#[derive(Clone, Debug)]
+pub struct MovingAverageState {
+    inner: Inner,
+}
+
+impl MovingAverageState {
+    pub fn new(kind: MovingAverageType, period: usize) -> Self {
+        Self::with_options(kind, period, &MovingAverageOptions::default())
+    }
+
+    pub fn with_options(kind: MovingAverageType, period: usize, options: &MovingAverageOptions) -> Self {
+        let period = period.max(1);
+        let inner = match kind {
+            MovingAverageType::Sma => Inner::Sma(Sma::new(period)),
+            MovingAverageType::Ema => Inner::Ema(SeededEma::exponential(period)),
+            MovingAverageType::Wma => Inner::Wma(Wma::new(period)),
+            MovingAverageType::Smma => Inner::Smma(SeededEma::wilder(period)),
+            MovingAverageType::Dema => Inner::Dema(SeededEma::exponential(period), SeededEma::exponential(period)),
+            MovingAverageType::Tema => Inner::Tema(
+                SeededEma::exponential(period),
+                SeededEma::exponential(period),
+                SeededEma::exponential(period),
+            ),
+            MovingAverageType::Trima => Inner::Trima(Sma::new(period / 2 + 1), Sma::new(period.div_ceil(2))),
+            MovingAverageType::Hma => Inner::Hma(
+                Wma::new((period / 2).max(1)),
+                Wma::new(period),
+                Wma::new(((period as f64).sqrt().floor() as usize).max(1)),
+            ),
+            MovingAverageType::Zlema => Inner::Zlema {
+                lag: Window::new((period - 1) / 2 + 1),
+                ema: SeededEma::exponential(period),
+            },
+            MovingAverageType::Alma => Inner::Alma {
+                window: Window::new(period),
+                weights: alma_weights(period, options.alma_offset, options.alma_sigma),
+            },
+            MovingAverageType::Lsma => Inner::Lsma(Window::new(period)),
+            MovingAverageType::T3 => {
+                let a = options.t3_volume_factor;
+                Inner::T3 {
+                    emas: (0..6).map(|_| SeededEma::exponential(period)).collect(),
+                    coefficients: [
+                        -a * a * a,
+                        3.0 * a * a + 3.0 * a * a * a,
+                        -6.0 * a * a - 3.0 * a - 3.0 * a * a * a,
+                        1.0 + 3.0 * a + a * a * a + 3.0 * a * a,
+                    ],
+                }
+            }
+            MovingAverageType::Vwma => Inner::Vwma(Window::new(period)),
+        };
+        MovingAverageState { inner }
+    }
+
+    pub fn next(&mut self, value: f64) -> f64 {
+        self.next_with_volume(value, 1.0)
+    }
+
+    /// Feeds a value with its volume. The volume is only used by `Vwma`.
+    pub fn next_with_volume(&mut self, value: f64, volume: f64) -> f64 {
+        if value.is_nan() {
+            return f64::NAN;
+        }
+
+        match &mut self.inner {
+            Inner::Sma(sma) => sma.next(value),
+            Inner::Ema(ema) | Inner::Smma(ema) => ema.next(value),
+            Inner::Wma(wma) => wma.next(value),
+            Inner::Dema(first, second) => {
+                let e1 = first.next(value);
+                let e2 = second.next(e1);
+                2.0 * e1 - e2
+            }
+            Inner::Tema(first, second, third) => {
+                let e1 = first.next(value);
+                let e2 = second.next(e1);
+                let e3 = third.next(e2);
+                3.0 * e1 - 3.0 * e2 + e3
+            }
+            Inner::Trima(first, second) => second.next(first.next(value)),
+            Inner::Hma(half, full, final_wma) => {
+                let raw = 2.0 * half.next(value) - full.next(value);
+                final_wma.next(raw)
+            }
+            Inner::Zlema { lag, ema } => {
+                if !lag.push(value, 1.0) {
+                    return f64::NAN;
+                }
+                ema.next(2.0 * value - lag.values[0])
+            }
+            Inner::Alma { window, weights } => {
+                if !window.push(value, 1.0) {
+                    return f64::NAN;
+                }
+                window.values.iter().zip(weights.iter()).map(|(v, w)| v * w).sum()
+            }
+            Inner::Lsma(window) => {
+                if !window.push(value, 1.0) {
+                    return f64::NAN;
+                }
+                linear_regression_end_point(&window.values)
+            }
+            Inner::T3 { emas, coefficients } => {
+                let mut current = value;
+                let mut outputs = [0.0; 6];
+                for (ema, output) in emas.iter_mut().zip(outputs.iter_mut()) {
+                    current = ema.next(current);
+                    *output = current;
+                }
+                coefficients[0] * outputs[5]
+                    + coefficients[1] * outputs[4]
+                    + coefficients[2] * outputs[3]
+                    + coefficients[3] * outputs[2]
+            }
+            Inner::Vwma(window) => {
+                if !window.push(value, volume) {
+                    return f64::NAN;
+                }
+                let total_volume: f64 = window.volumes.iter().sum();
+                if total_volume == 0.0 {
+                    return window.values.iter().sum::<f64>() / window.period as f64;
+                }
+                window.values.iter().zip(window.volumes.iter()).map(|(v, w)| v * w).sum::<f64>() / total_volume
+            }
+        }
+    }
+}
+
+/// Moving average of `source`, aligned with it (NaN during the warm-up).
+///
+/// `Vwma` uses unit volumes here, which makes it an SMA; use `moving_average_with_options` to pass volumes.
+pub fn moving_average(source: &[f64], period: usize, kind: MovingAverageType) -> Vec<f64> {
+    moving_average_with_options(source, None, period, kind, &MovingAverageOptions::default())
+}
+
+pub fn moving_average_with_options(
+    source: &[f64],
+    volumes: Option<&[f64]>,
+    period: usize,
+    kind: MovingAverageType,
+    options: &MovingAverageOptions,
+) -> Vec<f64> {
+    let mut state = MovingAverageState::with_options(kind, period, options);
+    source
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| state.next_with_volume(value, volumes.map_or(1.0, |v| v[i])))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a.is_nan() && e.is_nan()) || (a - e).abs() < 1e-9, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn test_sma_wma_and_ema() {
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_close(&moving_average(&data, 3, MovingAverageType::Sma), &[NAN, NAN, 2.0, 3.0, 4.0]);
+        assert_close(
+            &moving_average(&data, 3, MovingAverageType::Wma),
+            &[NAN, NAN, 14.0 / 6.0, 20.0 / 6.0, 26.0 / 6.0],
+        );
+        assert_close(&moving_average(&data, 3, MovingAverageType::Ema), &[NAN, NAN, 2.0, 3.0, 4.0]);
+        assert_close(&moving_average(&data, 2, MovingAverageType::Smma), &[NAN, 1.5, 2.25, 3.125, 4.0625]);
+    }
+
+    #[test]
+    fn test_linear_kinds_follow_a_straight_line() {
+        // On a straight line, the lag-compensated averages sit exactly on the line.
+        let data: Vec<f64> = (0..40).map(|i| i as f64).collect();
+        for kind in [MovingAverageType::Dema, MovingAverageType::Tema, MovingAverageType::Lsma, MovingAverageType::Hma] {
+            let result = moving_average(&data, 4, kind);
+            assert!((result[39] - 39.0).abs() < 1e-9, "{:?}: {}", kind, result[39]);
+        }
+    }
+
+    #[test]
+    fn test_constant_series_is_unchanged() {
+        let data = [5.0; 60];
+        for kind in [
+            MovingAverageType::Trima,
+            MovingAverageType::Zlema,
+            MovingAverageType::Alma,
+            MovingAverageType::T3,
+            MovingAverageType::Vwma,
+        ] {
+            let result = moving_average(&data, 5, kind);
+            assert!((result[59] - 5.0).abs() < 1e-9, "{:?}: {}", kind, result[59]);
+        }
+    }
+
+    #[test]
+    fn test_vwma_weights_by_volume() {
+        let result = moving_average_with_options(
+            &[10.0, 20.0],
+            Some(&[1.0, 3.0]),
+            2,
+            MovingAverageType::Vwma,
+            &MovingAverageOptions::default(),
+        );
+        assert_close(&result, &[NAN, 17.5]);
+    }
+
+    #[test]
+    fn test_nan_inputs_are_skipped() {
+        let result = moving_average(&[NAN, NAN, 1.0, 3.0], 2, MovingAverageType::Sma);
+        assert_close(&result, &[NAN, NAN, NAN, 2.0]);
+    }
+}
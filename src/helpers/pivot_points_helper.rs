@@ -1,3 +1,193 @@
+use crate::helpers::date_helper::{month_key, session_key, week_key};
+use crate::low_high_open_close_volume_date_to_array_helper::MarketDataResult;
+use crate::structs::pivot_points_struct::{PivotLevels, PivotMethod, PivotPointsBar, PivotTimeframe};
+
 pub fn calculate_pivot_point(high: f64, low: f64, close: f64) -> f64 {
     (high + low + close) / 3.0
-}
\ No newline at end of file
+}
+
+/// Pivot levels of a period with the given open, high, low and close.
+pub fn pivot_levels(method: PivotMethod, open: f64, high: f64, low: f64, close: f64) -> PivotLevels {
+    let range = high - low;
+
+    match method {
+        PivotMethod::Classic => {
+            let pivot = calculate_pivot_point(high, low, close);
+            PivotLevels {
+                pivot,
+                r1: 2.0 * pivot - low,
+                s1: 2.0 * pivot - high,
+                r2: Some(pivot + range),
+                s2: Some(pivot - range),
+                r3: Some(pivot + 2.0 * range),
+                s3: Some(pivot - 2.0 * range),
+                r4: Some(pivot + 3.0 * range),
+                s4: Some(pivot - 3.0 * range),
+            }
+        }
+        PivotMethod::Fibonacci => {
+            let pivot = calculate_pivot_point(high, low, close);
+            PivotLevels {
+                pivot,
+                r1: pivot + 0.382 * range,
+                s1: pivot - 0.382 * range,
+                r2: Some(pivot + 0.618 * range),
+                s2: Some(pivot - 0.618 * range),
+                r3: Some(pivot + range),
+                s3: Some(pivot - range),
+                r4: Some(pivot + 1.618 * range),
+                s4: Some(pivot - 1.618 * range),
+            }
+        }
+        PivotMethod::Camarilla => {
+            let step = range * 1.1;
+            PivotLevels {
+                pivot: calculate_pivot_point(high, low, close),
+                r1: close + step / 12.0,
+                s1: close - step / 12.0,
+                r2: Some(close + step / 6.0),
+                s2: Some(close - step / 6.0),
+                r3: Some(close + step / 4.0),
+                s3: Some(close - step / 4.0),
+                r4: Some(close + step / 2.0),
+                s4: Some(close - step / 2.0),
+            }
+        }
+        PivotMethod::Woodie => {
+            let pivot = (high + low + 2.0 * close) / 4.0;
+            let r3 = high + 2.0 * (pivot - low);
+            let s3 = low - 2.0 * (high - pivot);
+            PivotLevels {
+                pivot,
+                r1: 2.0 * pivot - low,
+                s1: 2.0 * pivot - high,
+                r2: Some(pivot + range),
+                s2: Some(pivot - range),
+                r3: Some(r3),
+                s3: Some(s3),
+                r4: Some(r3 + range),
+                s4: Some(s3 - range),
+            }
+        }
+        PivotMethod::Demark => {
+            let x = if close < open {
+                high + 2.0 * low + close
+            } else if close > open {
+                2.0 * high + low + close
+            } else {
+                high + low + 2.0 * close
+            };
+            PivotLevels {
+                pivot: x / 4.0,
+                r1: x / 2.0 - low,
+                s1: x / 2.0 - high,
+                ..PivotLevels::default()
+            }
+        }
+    }
+}
+
+/// Period of every candle for `timeframe`: consecutive candles with the same key share a period.
+pub fn period_keys(timestamps: &[i64], timeframe: PivotTimeframe, session_offset_minutes: i64) -> Vec<i64> {
+    timestamps
+        .iter()
+        .enumerate()
+        .map(|(i, &millis)| {
+            let day = session_key(millis, session_offset_minutes);
+            match timeframe {
+                PivotTimeframe::Bar => i as i64,
+                PivotTimeframe::Day => day,
+                PivotTimeframe::Week => week_key(day),
+                PivotTimeframe::Month => month_key(day),
+            }
+        })
+        .collect()
+}
+
+/// Open, high, low and close of a period, updated candle by candle.
+#[derive(Clone, Copy)]
+struct PeriodBar {
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+}
+
+/// Pivot levels of every candle, from the previous period and from the developing one.
+pub fn pivot_points_by_period(market_data: &MarketDataResult, keys: &[i64], method: PivotMethod) -> Vec<PivotPointsBar> {
+    let mut result = Vec::with_capacity(keys.len());
+    let mut previous: Option<PeriodBar> = None;
+    let mut current: Option<PeriodBar> = None;
+
+    for i in 0..keys.len() {
+        let (open, high, low, close) = (
+            market_data.opens[i],
+            market_data.highs[i],
+            market_data.lows[i],
+            market_data.closes[i],
+        );
+
+        current = match current {
+            Some(bar) if i > 0 && keys[i] == keys[i - 1] => Some(PeriodBar {
+                open: bar.open,
+                high: bar.high.max(high),
+                low: bar.low.min(low),
+                close,
+            }),
+            _ => {
+                previous = current;
+                Some(PeriodBar { open, high, low, close })
+            }
+        };
+
+        let developing = current.map(|bar| pivot_levels(method, bar.open, bar.high, bar.low, bar.close));
+        result.push(PivotPointsBar {
+            date: market_data.dates[i].clone(),
+            levels: previous.map(|bar| pivot_levels(method, bar.open, bar.high, bar.low, bar.close)),
+            developing: developing.unwrap_or_default(),
+        });
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::low_high_open_close_volume_date_to_array_helper::candles;
+
+    #[test]
+    fn test_classic_and_camarilla_levels() {
+        let classic = pivot_levels(PivotMethod::Classic, 10.0, 12.0, 9.0, 10.5);
+        assert_eq!(classic.pivot, 10.5);
+        assert_eq!(classic.r1, 12.0);
+        assert_eq!(classic.s1, 9.0);
+        assert_eq!(classic.r4, Some(19.5));
+
+        let camarilla = pivot_levels(PivotMethod::Camarilla, 10.0, 12.0, 9.0, 10.5);
+        assert!((camarilla.r4.unwrap() - (10.5 + 3.3 / 2.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_demark_only_defines_the_first_levels() {
+        // Bullish period: X = 2H + L + C.
+        let demark = pivot_levels(PivotMethod::Demark, 10.0, 12.0, 9.0, 11.0);
+        assert_eq!(demark.pivot, 44.0 / 4.0);
+        assert_eq!(demark.r1, 22.0 - 9.0);
+        assert_eq!(demark.r2, None);
+    }
+
+    #[test]
+    fn test_pivot_points_use_the_previous_period() {
+        let market_data = candles(&[(10.0, 12.0, 9.0, 11.0), (11.0, 13.0, 10.0, 10.5), (10.5, 11.0, 10.0, 10.8)]);
+        // Two candles on the first day, one on the second.
+        let result = pivot_points_by_period(&market_data, &[0, 0, 1], PivotMethod::Classic);
+
+        assert!(result[0].levels.is_none());
+        assert!(result[1].levels.is_none());
+        // First day: H 13, L 9, C 10.5.
+        assert_eq!(result[2].levels.as_ref().unwrap().pivot, 32.5 / 3.0);
+        assert_eq!(result[1].developing.pivot, 32.5 / 3.0);
+        assert_eq!(result[2].developing.pivot, (11.0 + 10.0 + 10.8) / 3.0);
+    }
+}
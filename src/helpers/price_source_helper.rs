@@ -0,0 +1,25 @@
+use crate::low_high_open_close_volume_date_to_array_helper::MarketDataResult;
+use crate::structs::price_source_struct::PriceSource;
+
+/// The `source` series of the candles.
+pub fn price_source(market_data: &MarketDataResult, source: PriceSource) -> Vec<f64> {
+    let (opens, highs, lows, closes) = (
+        &market_data.opens,
+        &market_data.highs,
+        &market_data.lows,
+        &market_data.closes,
+    );
+
+    (0..closes.len())
+        .map(|i| match source {
+            PriceSource::Open => opens[i],
+            PriceSource::High => highs[i],
+            PriceSource::Low => lows[i],
+            PriceSource::Close => closes[i],
+            PriceSource::Hl2 => (highs[i] + lows[i]) / 2.0,
+            PriceSource::Hlc3 => (highs[i] + lows[i] + closes[i]) / 3.0,
+            PriceSource::Ohlc4 => (opens[i] + highs[i] + lows[i] + closes[i]) / 4.0,
+            PriceSource::Hlcc4 => (highs[i] + lows[i] + 2.0 * closes[i]) / 4.0,
+        })
+        .collect()
+}
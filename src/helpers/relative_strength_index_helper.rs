@@ -11,4 +11,24 @@ pub fn calculate_gain_loss(values: &[f64], period: usize) -> (f64, f64) {
         }
     }
     (gain / period as f64, loss / period as f64)
-}
\ No newline at end of file
+}
+
+/// RSI of every window of `period` prices; the output is `period` values shorter than `prices`.
+pub fn relative_strength_index(prices: &[f64], period: usize) -> Vec<f64> {
+    let mut rsi_values = Vec::new();
+
+    for i in period..prices.len() {
+        let (avg_gain, avg_loss) = calculate_gain_loss(&prices[i - period..i], period);
+
+        // Avoid division by zero
+        if avg_loss == 0.0 {
+            rsi_values.push(100.0);
+        } else {
+            let rs = avg_gain / avg_loss;
+            let rsi = 100.0 - (100.0 / (1.0 + rs));
+            rsi_values.push(rsi);
+        }
+    }
+
+    rsi_values
+}
@@ -0,0 +1,346 @@
+use std::collections::VecDeque;
+
+use crate::structs::rolling_statistics_struct::{DeviationType, RollingStatistic, RollingStatisticOptions};
+
+// Every window below holds the last `period` non-NaN values: a NaN input yields NaN and leaves
+// the window untouched, so series with a warm-up period can be fed directly.
+
+/// Appends `value` and returns the value pushed out of a full window.
+fn slide(window: &mut VecDeque<f64>, value: f64, period: usize) -> Option<f64> {
+    window.push_back(value);
+    if window.len() > period {
+        window.pop_front()
+    } else {
+        None
+    }
+}
+
+/// Rolling mean and variance, updated with Welford's algorithm.
+///
+/// Unlike `sum_sq - sum² / n`, the running sum of squared deviations does not suffer from
+/// catastrophic cancellation on large, tightly packed values and never goes negative.
+#[derive(Clone, Debug)]
+pub struct RollingVariance {
+    period: usize,
+    window: VecDeque<f64>,
+    mean: f64,
+    m2: f64,
+}
+
+impl RollingVariance {
+    pub fn new(period: usize) -> Self {
+        RollingVariance { period, window: VecDeque::with_capacity(period + 1), mean: 0.0, m2: 0.0 }
+    }
+
+    /// Adds a value and returns `true` once the window is full.
+    pub fn push(&mut self, value: f64) -> bool {
+        if value.is_nan() {
+            return false;
+        }
+        match slide(&mut self.window, value, self.period) {
+            Some(old) => {
+                // Same window size: replace the oldest value.
+                let previous_mean = self.mean;
+                self.mean += (value - old) / self.period as f64;
+                self.m2 += (value - old) * (value - self.mean + old - previous_mean);
+            }
+            None => {
+                let delta = value - self.mean;
+                self.mean += delta / self.window.len() as f64;
+                self.m2 += delta * (value - self.mean);
+            }
+        }
+        self.m2 = self.m2.max(0.0);
+        self.window.len() == self.period
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    pub fn variance(&self, deviation: DeviationType) -> f64 {
+        let n = self.window.len();
+        match deviation {
+            DeviationType::Population if n > 0 => self.m2 / n as f64,
+            DeviationType::Sample if n > 1 => self.m2 / (n - 1) as f64,
+            _ => f64::NAN,
+        }
+    }
+
+    /// Standardised central moment of order `order` (3 for the skewness, 4 for the kurtosis).
+    /// A flat window yields 0.
+    fn standardized_moment(&self, order: i32) -> f64 {
+        let variance = self.variance(DeviationType::Population);
+        if variance == 0.0 {
+            return 0.0;
+        }
+        let moment = self.window.iter().map(|v| (v - self.mean).powi(order)).sum::<f64>() / self.window.len() as f64;
+        moment / variance.powf(order as f64 / 2.0)
+    }
+}
+
+/// Window kept sorted alongside its insertion order, for the median, quantiles and ranks.
+///
+/// Each new value is inserted at its rank and the expired one removed by binary search, so the
+/// window is never re-sorted.
+#[derive(Clone, Debug)]
+pub struct OrderStatisticWindow {
+    period: usize,
+    window: VecDeque<f64>,
+    sorted: Vec<f64>,
+}
+
+impl OrderStatisticWindow {
+    pub fn new(period: usize) -> Self {
+        OrderStatisticWindow {
+            period,
+            window: VecDeque::with_capacity(period + 1),
+            sorted: Vec::with_capacity(period + 1),
+        }
+    }
+
+    /// Adds a value and returns `true` once the window is full.
+    pub fn push(&mut self, value: f64) -> bool {
+        if value.is_nan() {
+            return false;
+        }
+        if let Some(old) = slide(&mut self.window, value, self.period) {
+            if let Ok(position) = self.sorted.binary_search_by(|v| v.total_cmp(&old)) {
+                self.sorted.remove(position);
+            }
+        }
+        let position = self.sorted.partition_point(|v| v.total_cmp(&value).is_lt());
+        self.sorted.insert(position, value);
+        self.window.len() == self.period
+    }
+
+    /// Quantile `q` (0 to 1), linearly interpolated between the closest ranks.
+    pub fn quantile(&self, q: f64) -> f64 {
+        if self.sorted.is_empty() {
+            return f64::NAN;
+        }
+        let rank = (self.sorted.len() - 1) as f64 * q.clamp(0.0, 1.0);
+        let below = rank.floor() as usize;
+        let above = rank.ceil() as usize;
+        self.sorted[below] + (rank - below as f64) * (self.sorted[above] - self.sorted[below])
+    }
+
+    /// Share, in percent, of the other values of the window lower than or equal to `value`
+    /// (which must be in the window).
+    pub fn percent_rank(&self, value: f64) -> f64 {
+        let others = self.sorted.len().saturating_sub(1);
+        if others == 0 {
+            return f64::NAN;
+        }
+        let lower_or_equal = self.sorted.partition_point(|v| *v <= value) - 1;
+        lower_or_equal as f64 / others as f64 * 100.0
+    }
+}
+
+/// Rolling extremum with a monotonic deque: amortised O(1) per value.
+#[derive(Clone, Debug)]
+pub struct RollingExtremum {
+    period: usize,
+    count: usize,
+    /// `(position, value)` candidates, the extremum in front.
+    candidates: VecDeque<(usize, f64)>,
+    /// `true` when `a` makes `b` useless as a future extremum.
+    dominates: fn(f64, f64) -> bool,
+}
+
+impl RollingExtremum {
+    pub fn max(period: usize) -> Self {
+        RollingExtremum { period, count: 0, candidates: VecDeque::new(), dominates: |a, b| a >= b }
+    }
+
+    pub fn min(period: usize) -> Self {
+        RollingExtremum { period, count: 0, candidates: VecDeque::new(), dominates: |a, b| a <= b }
+    }
+
+    pub fn next(&mut self, value: f64) -> f64 {
+        if value.is_nan() {
+            return f64::NAN;
+        }
+        while self.candidates.back().is_some_and(|&(_, last)| (self.dominates)(value, last)) {
+            self.candidates.pop_back();
+        }
+        self.candidates.push_back((self.count, value));
+        self.count += 1;
+        while self.candidates.front().is_some_and(|&(position, _)| position + self.period < self.count) {
+            self.candidates.pop_front();
+        }
+        if self.count < self.period {
+            return f64::NAN;
+        }
+        self.candidates.front().map_or(f64::NAN, |&(_, extremum)| extremum)
+    }
+}
+
+/// Feeds `values` through a `RollingVariance` and maps every full window with `statistic`.
+fn with_variance(values: &[f64], period: usize, statistic: impl Fn(&RollingVariance, f64) -> f64) -> Vec<f64> {
+    let mut window = RollingVariance::new(period);
+    values
+        .iter()
+        .map(|&value| if window.push(value) { statistic(&window, value) } else { f64::NAN })
+        .collect()
+}
+
+/// Same as `with_variance` with an `OrderStatisticWindow`.
+fn with_order_statistics(
+    values: &[f64],
+    period: usize,
+    statistic: impl Fn(&OrderStatisticWindow, f64) -> f64,
+) -> Vec<f64> {
+    let mut window = OrderStatisticWindow::new(period);
+    values
+        .iter()
+        .map(|&value| if window.push(value) { statistic(&window, value) } else { f64::NAN })
+        .collect()
+}
+
+pub fn rolling_sum(values: &[f64], period: usize) -> Vec<f64> {
+    with_variance(values, period, |window, _| window.mean() * period as f64)
+}
+
+pub fn rolling_mean(values: &[f64], period: usize) -> Vec<f64> {
+    with_variance(values, period, |window, _| window.mean())
+}
+
+pub fn rolling_variance(values: &[f64], period: usize, deviation: DeviationType) -> Vec<f64> {
+    with_variance(values, period, |window, _| window.variance(deviation))
+}
+
+pub fn rolling_std(values: &[f64], period: usize, deviation: DeviationType) -> Vec<f64> {
+    with_variance(values, period, |window, _| window.variance(deviation).sqrt())
+}
+
+/// `(value - mean) / std` of the window holding the value; 0 when the window is flat.
+pub fn rolling_z_score(values: &[f64], period: usize, deviation: DeviationType) -> Vec<f64> {
+    with_variance(values, period, |window, value| {
+        let std = window.variance(deviation).sqrt();
+        if std == 0.0 { 0.0 } else { (value - window.mean()) / std }
+    })
+}
+
+/// Population skewness of the window; 0 when the window is flat.
+pub fn rolling_skewness(values: &[f64], period: usize) -> Vec<f64> {
+    with_variance(values, period, |window, _| window.standardized_moment(3))
+}
+
+/// Population excess kurtosis of the window; 0 when the window is flat.
+pub fn rolling_kurtosis(values: &[f64], period: usize) -> Vec<f64> {
+    with_variance(values, period, |window, _| {
+        if window.variance(DeviationType::Population) == 0.0 { 0.0 } else { window.standardized_moment(4) - 3.0 }
+    })
+}
+
+pub fn rolling_min(values: &[f64], period: usize) -> Vec<f64> {
+    let mut window = RollingExtremum::min(period);
+    values.iter().map(|&value| window.next(value)).collect()
+}
+
+pub fn rolling_max(values: &[f64], period: usize) -> Vec<f64> {
+    let mut window = RollingExtremum::max(period);
+    values.iter().map(|&value| window.next(value)).collect()
+}
+
+pub fn rolling_quantile(values: &[f64], period: usize, q: f64) -> Vec<f64> {
+    with_order_statistics(values, period, |window, _| window.quantile(q))
+}
+
+pub fn rolling_median(values: &[f64], period: usize) -> Vec<f64> {
+    rolling_quantile(values, period, 0.5)
+}
+
+pub fn rolling_percent_rank(values: &[f64], period: usize) -> Vec<f64> {
+    with_order_statistics(values, period, |window, value| window.percent_rank(value))
+}
+
+/// Rolling `options.statistic` of `values`, aligned with them (NaN until `period` values are in).
+pub fn rolling_statistic(values: &[f64], options: &RollingStatisticOptions) -> Vec<f64> {
+    let period = options.period;
+    match options.statistic {
+        RollingStatistic::Sum => rolling_sum(values, period),
+        RollingStatistic::Mean => rolling_mean(values, period),
+        RollingStatistic::Variance => rolling_variance(values, period, options.deviation),
+        RollingStatistic::Std => rolling_std(values, period, options.deviation),
+        RollingStatistic::ZScore => rolling_z_score(values, period, options.deviation),
+        RollingStatistic::Skewness => rolling_skewness(values, period),
+        RollingStatistic::Kurtosis => rolling_kurtosis(values, period),
+        RollingStatistic::Min => rolling_min(values, period),
+        RollingStatistic::Max => rolling_max(values, period),
+        RollingStatistic::Median => rolling_median(values, period),
+        RollingStatistic::Quantile => rolling_quantile(values, period, options.quantile),
+        RollingStatistic::PercentRank => rolling_percent_rank(values, period),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAN: f64 = f64::NAN;
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a.is_nan() && e.is_nan()) || (a - e).abs() < 1e-9, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_sum_mean_and_variance() {
+        let values = [1.0, 2.0, 3.0, 4.0, 6.0];
+        assert_close(&rolling_sum(&values, 3), &[NAN, NAN, 6.0, 9.0, 13.0]);
+        assert_close(&rolling_mean(&values, 3), &[NAN, NAN, 2.0, 3.0, 13.0 / 3.0]);
+        assert_close(&rolling_variance(&values, 3, DeviationType::Sample), &[NAN, NAN, 1.0, 1.0, 7.0 / 3.0]);
+        assert_close(&rolling_std(&values, 2, DeviationType::Population), &[NAN, 0.5, 0.5, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_nan_values_are_skipped() {
+        let values = [1.0, NAN, 2.0, 3.0, NAN, 4.0];
+        assert_close(&rolling_mean(&values, 2), &[NAN, NAN, 1.5, 2.5, NAN, 3.5]);
+        assert_close(&rolling_max(&values, 3), &[NAN, NAN, NAN, 3.0, NAN, 4.0]);
+    }
+
+    #[test]
+    fn test_variance_is_stable_on_large_values() {
+        // The naive sum of squares loses every digit here and can even go negative.
+        let values: Vec<f64> = (0..50).map(|i| 1e9 + (i % 2) as f64 * 0.01).collect();
+        let variance = rolling_variance(&values, 4, DeviationType::Population);
+        assert!((variance[49] - 0.000025).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_z_score_skewness_and_kurtosis() {
+        let values = [1.0, 2.0, 3.0, 2.0, 2.0, 2.0];
+        let z = rolling_z_score(&values, 3, DeviationType::Population);
+        assert!((z[2] - 1.0 / (2.0f64 / 3.0).sqrt()).abs() < 1e-9);
+        assert_eq!(z[5], 0.0);
+
+        let skew = rolling_skewness(&[1.0, 1.0, 4.0], 3);
+        // Deviations -1, -1, 2: m3 = 2, m2 = 2.
+        assert!((skew[2] - 2.0 / 2.0f64.powf(1.5)).abs() < 1e-9);
+        let kurtosis = rolling_kurtosis(&[1.0, 1.0, 4.0], 3);
+        // m4 = 6, m2 = 2.
+        assert!((kurtosis[2] - (6.0 / 4.0 - 3.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_min_and_max() {
+        let values = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0];
+        assert_close(&rolling_min(&values, 3), &[NAN, NAN, 1.0, 1.0, 1.0, 1.0, 2.0]);
+        assert_close(&rolling_max(&values, 3), &[NAN, NAN, 4.0, 4.0, 5.0, 9.0, 9.0]);
+    }
+
+    #[test]
+    fn test_median_quantile_and_percent_rank() {
+        let values = [5.0, 1.0, 4.0, 2.0, 3.0, 3.0];
+        assert_close(&rolling_median(&values, 3), &[NAN, NAN, 4.0, 2.0, 3.0, 3.0]);
+        assert_close(&rolling_median(&values, 4), &[NAN, NAN, NAN, 3.0, 2.5, 3.0]);
+        assert_close(&rolling_quantile(&values, 4, 0.25), &[NAN, NAN, NAN, 1.75, 1.75, 2.75]);
+        // 4 is above 1, below 5; the duplicated 3 counts the other 3 as equal.
+        assert_close(&rolling_percent_rank(&values, 3), &[NAN, NAN, 50.0, 50.0, 50.0, 100.0]);
+    }
+}
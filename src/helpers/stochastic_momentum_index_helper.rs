@@ -1,3 +1,5 @@
+use crate::helpers::rolling_statistics_helper::{rolling_max, rolling_min};
+
 pub fn abs_diff(a: &[f64], b: &[f64]) -> Vec<f64> {
     a.iter()
         .zip(b.iter())
@@ -5,14 +7,12 @@ pub fn abs_diff(a: &[f64], b: &[f64]) -> Vec<f64> {
         .collect()
 }
 
+/// Highest value of every full window of `period` values (one value per window, no warm-up).
 pub fn max_in_window(data: &[f64], period: usize) -> Vec<f64> {
-    data.windows(period)
-        .map(|w| w.iter().fold(f64::MIN, |a, &b| a.max(b)))
-        .collect()
+    rolling_max(data, period).into_iter().skip(period.saturating_sub(1)).collect()
 }
 
+/// Lowest value of every full window of `period` values (one value per window, no warm-up).
 pub fn min_in_window(data: &[f64], period: usize) -> Vec<f64> {
-    data.windows(period)
-        .map(|w| w.iter().fold(f64::MAX, |a, &b| a.min(b)))
-        .collect()
+    rolling_min(data, period).into_iter().skip(period.saturating_sub(1)).collect()
 }
\ No newline at end of file
@@ -0,0 +1,78 @@
+use crate::helpers::crossover_helper::level_crossings;
+use crate::helpers::highest_lowest_helper::calculate_high_low;
+use crate::helpers::moving_average_helper::moving_average;
+use crate::structs::stochastic_oscillator_struct::{StochasticOptions, StochasticResult};
+
+/// Raw %K: position of the close inside the high–low range of the last `period` bars, current bar included.
+///
+/// The first `period - 1` values are NaN. A flat window (highest high == lowest low) has no range
+/// to measure against and yields 50, the middle of the scale.
+pub fn raw_stochastic(highs: &[f64], lows: &[f64], closes: &[f64], period: usize) -> Vec<f64> {
+    let mut result = vec![f64::NAN; closes.len()];
+
+    for i in period.saturating_sub(1)..closes.len() {
+        let (highest_high, lowest_low) = calculate_high_low(highs, lows, i + 1 - period, i);
+        let range = highest_high - lowest_low;
+        result[i] = if range == 0.0 {
+            50.0
+        } else {
+            100.0 * (closes[i] - lowest_low) / range
+        };
+    }
+
+    result
+}
+
+/// Fast, Slow or Full stochastic depending on `options.k_smoothing` and `options.d_period`.
+pub fn stochastic_oscillator(
+    highs: &[f64],
+    lows: &[f64],
+    closes: &[f64],
+    options: &StochasticOptions,
+) -> StochasticResult {
+    let raw = raw_stochastic(highs, lows, closes, options.k_period);
+    let k = moving_average(&raw, options.k_smoothing, options.ma_type);
+    let d = moving_average(&k, options.d_period, options.ma_type);
+    let events = level_crossings(&k, options.overbought, options.oversold);
+
+    StochasticResult { k, d, events }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_stochastic_includes_the_current_bar() {
+        let highs = [10.0, 11.0, 12.0];
+        let lows = [9.0, 10.0, 11.0];
+        let closes = [9.5, 10.5, 12.0];
+        let k = raw_stochastic(&highs, &lows, &closes, 2);
+
+        assert!(k[0].is_nan());
+        assert_eq!(k[1], 75.0);
+        // The new high belongs to the window, so a close at the high reads 100.
+        assert_eq!(k[2], 100.0);
+    }
+
+    #[test]
+    fn test_raw_stochastic_flat_window() {
+        let k = raw_stochastic(&[5.0, 5.0], &[5.0, 5.0], &[5.0, 5.0], 2);
+        assert_eq!(k[1], 50.0);
+    }
+
+    #[test]
+    fn test_slow_stochastic_smooths_k() {
+        let highs = [10.0, 10.0, 10.0, 10.0, 10.0];
+        let lows = [0.0; 5];
+        let closes = [2.0, 4.0, 6.0, 8.0, 10.0];
+        let options = StochasticOptions { k_period: 1, k_smoothing: 3, d_period: 2, ..StochasticOptions::default() };
+        let result = stochastic_oscillator(&highs, &lows, &closes, &options);
+
+        assert!(result.k[1].is_nan());
+        assert_eq!(result.k[2], 40.0);
+        assert_eq!(result.k[4], 80.0);
+        assert!(result.d[2].is_nan());
+        assert_eq!(result.d[3], 50.0);
+    }
+}
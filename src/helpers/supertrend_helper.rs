@@ -0,0 +1,99 @@
+use crate::helpers::calculate_atr_helper::average_true_range;
+use crate::structs::supertrend_struct::SuperTrendResult;
+
+/// SuperTrend over `source` with bands `multiplier` Wilder ATRs of `atr_period` away.
+///
+/// The bands only tighten while the close stays on their side, and the trend flips when the close
+/// crosses the active band. The first bar with an ATR starts in a downtrend.
+pub fn supertrend(
+    highs: &[f64],
+    lows: &[f64],
+    closes: &[f64],
+    source: &[f64],
+    atr_period: usize,
+    multiplier: f64,
+) -> SuperTrendResult {
+    let len = closes.len();
+    let atr = average_true_range(highs, lows, closes, atr_period);
+
+    let mut result = SuperTrendResult {
+        supertrend: vec![f64::NAN; len],
+        direction: vec![0; len],
+        flips: Vec::new(),
+        upper: vec![f64::NAN; len],
+        lower: vec![f64::NAN; len],
+    };
+
+    let mut final_upper = f64::NAN;
+    let mut final_lower = f64::NAN;
+    let mut direction: i8 = 0;
+
+    for i in 0..len {
+        if atr[i].is_nan() {
+            continue;
+        }
+        let basic_upper = source[i] + multiplier * atr[i];
+        let basic_lower = source[i] - multiplier * atr[i];
+
+        if direction == 0 {
+            final_upper = basic_upper;
+            final_lower = basic_lower;
+            direction = -1;
+        } else {
+            let previous_close = closes[i - 1];
+            if basic_upper < final_upper || previous_close > final_upper {
+                final_upper = basic_upper;
+            }
+            if basic_lower > final_lower || previous_close < final_lower {
+                final_lower = basic_lower;
+            }
+
+            let previous_direction = direction;
+            direction = if direction < 0 {
+                if closes[i] > final_upper { 1 } else { -1 }
+            } else if closes[i] < final_lower {
+                -1
+            } else {
+                1
+            };
+            if direction != previous_direction {
+                result.flips.push(i);
+            }
+        }
+
+        result.direction[i] = direction;
+        if direction > 0 {
+            result.supertrend[i] = final_lower;
+            result.lower[i] = final_lower;
+        } else {
+            result.supertrend[i] = final_upper;
+            result.upper[i] = final_upper;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supertrend_flips_on_a_breakout() {
+        let highs = [11.0, 11.0, 11.0, 16.0, 17.0, 18.0];
+        let lows = [9.0, 9.0, 9.0, 14.0, 15.0, 16.0];
+        let closes = [10.0, 10.0, 10.0, 15.5, 16.5, 17.5];
+        let source: Vec<f64> = highs.iter().zip(&lows).map(|(h, l)| (h + l) / 2.0).collect();
+        let result = supertrend(&highs, &lows, &closes, &source, 2, 1.0);
+
+        assert_eq!(result.direction[0], 0);
+        assert_eq!(result.direction[1], -1);
+        // ATR 2, upper band 10 + 2 = 12.
+        assert_eq!(result.supertrend[1], 12.0);
+        assert_eq!(result.upper[2], 12.0);
+        assert_eq!(result.direction[3], 1);
+        assert_eq!(result.flips, vec![3]);
+        assert!(result.upper[3].is_nan());
+        assert_eq!(result.lower[3], result.supertrend[3]);
+    }
+}
@@ -0,0 +1,143 @@
+use std::cmp::Ordering;
+
+use crate::low_high_open_close_volume_date_to_array_helper::MarketDataResult;
+use crate::structs::support_resistance_struct::{SupportResistanceZone, ZoneRole};
+use crate::structs::swing_points_struct::SwingPoint;
+
+/// Groups swing points by price: a point joins the current group while it is within
+/// `tolerance(price)` of the lowest price of the group, measured at that lowest price.
+pub fn cluster_levels<'a>(points: &[&'a SwingPoint], tolerance: impl Fn(f64) -> f64) -> Vec<Vec<&'a SwingPoint>> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.price.partial_cmp(&b.price).unwrap_or(Ordering::Equal));
+
+    let mut clusters: Vec<Vec<&SwingPoint>> = Vec::new();
+    for point in sorted {
+        match clusters.last_mut() {
+            Some(cluster) if point.price - cluster[0].price <= tolerance(cluster[0].price) => cluster.push(point),
+            _ => clusters.push(vec![point]),
+        }
+    }
+    clusters
+}
+
+/// Volume traded between `low` and `high`, each candle contributing the share of its range that
+/// overlaps the zone (all of it when the candle has no range and lies inside the zone).
+pub fn zone_volume(market_data: &MarketDataResult, low: f64, high: f64) -> f64 {
+    (0..market_data.closes.len())
+        .map(|i| {
+            let (bar_low, bar_high) = (market_data.lows[i], market_data.highs[i]);
+            let overlap = bar_high.min(high) - bar_low.max(low);
+            if bar_high == bar_low {
+                if bar_low >= low && bar_low <= high { market_data.volumes[i] } else { 0.0 }
+            } else if overlap > 0.0 {
+                market_data.volumes[i] * overlap / (bar_high - bar_low)
+            } else {
+                0.0
+            }
+        })
+        .sum()
+}
+
+/// Support/resistance zones built from clusters of swing points.
+///
+/// The strength of a zone is its number of touches, plus twice its share of the total volume and
+/// the recency of its last touch (both between 0 and 1), so touches count most and volume and
+/// recency break ties. The `max_zones` zones nearest to the last close are returned, strongest
+/// first.
+pub fn support_resistance_zones(
+    market_data: &MarketDataResult,
+    clusters: &[Vec<&SwingPoint>],
+    max_zones: usize,
+) -> Vec<SupportResistanceZone> {
+    let len = market_data.closes.len();
+    let close = market_data.closes[len - 1];
+    let total_volume: f64 = market_data.volumes.iter().sum();
+
+    let mut zones: Vec<SupportResistanceZone> = clusters
+        .iter()
+        .map(|cluster| {
+            let low = cluster[0].price;
+            let high = cluster[cluster.len() - 1].price;
+            let first = cluster.iter().min_by_key(|point| point.index).unwrap();
+            let last = cluster.iter().max_by_key(|point| point.index).unwrap();
+            let volume = zone_volume(market_data, low, high);
+            let volume_share = if total_volume > 0.0 { volume / total_volume } else { 0.0 };
+            let recency = (last.index + 1) as f64 / len as f64;
+
+            SupportResistanceZone {
+                low,
+                high,
+                touches: cluster.len(),
+                first_touch: first.date.clone(),
+                last_touch: last.date.clone(),
+                volume,
+                role: if close >= (low + high) / 2.0 { ZoneRole::Support } else { ZoneRole::Resistance },
+                flipped: first.kind != last.kind,
+                strength: cluster.len() as f64 + 2.0 * volume_share + recency,
+                distance: (low - close).max(close - high).max(0.0),
+            }
+        })
+        .collect();
+
+    zones.sort_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap_or(Ordering::Equal));
+    zones.truncate(max_zones);
+    zones.sort_by(|a, b| b.strength.partial_cmp(&a.strength).unwrap_or(Ordering::Equal));
+    zones
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::low_high_open_close_volume_date_to_array_helper::candles;
+    use crate::structs::swing_points_struct::SwingKind;
+
+    fn point(index: usize, price: f64, kind: SwingKind) -> SwingPoint {
+        SwingPoint { index, date: index.to_string(), price, kind }
+    }
+
+    #[test]
+    fn test_cluster_levels() {
+        let points = [
+            point(0, 10.0, SwingKind::Low),
+            point(1, 12.0, SwingKind::High),
+            point(2, 10.4, SwingKind::Low),
+            point(3, 10.6, SwingKind::High),
+        ];
+        let refs: Vec<&SwingPoint> = points.iter().collect();
+        let clusters = cluster_levels(&refs, |_| 0.5);
+
+        let prices: Vec<Vec<f64>> = clusters.iter().map(|c| c.iter().map(|p| p.price).collect()).collect();
+        assert_eq!(prices, vec![vec![10.0, 10.4], vec![10.6], vec![12.0]]);
+    }
+
+    #[test]
+    fn test_zone_volume_counts_the_overlap() {
+        let mut market_data = candles(&[(11.0, 12.0, 10.0, 11.0), (11.0, 11.0, 11.0, 11.0)]);
+        market_data.volumes = vec![100.0, 50.0];
+        // Half of the first candle and all of the flat one.
+        assert_eq!(zone_volume(&market_data, 10.5, 11.5), 100.0);
+    }
+
+    #[test]
+    fn test_zones_are_ranked_and_flagged() {
+        let mut market_data = candles(&[(10.0, 11.0, 9.0, 10.0); 4]);
+        market_data.closes[3] = 11.0;
+        market_data.volumes = vec![100.0; 4];
+        let support = [point(0, 10.0, SwingKind::Low), point(2, 10.2, SwingKind::High)];
+        let resistance = [point(1, 14.0, SwingKind::High)];
+        let clusters = vec![support.iter().collect(), resistance.iter().collect()];
+        let zones = support_resistance_zones(&market_data, &clusters, 5);
+
+        assert_eq!(zones[0].touches, 2);
+        assert!(zones[0].flipped);
+        assert_eq!(zones[0].role, ZoneRole::Support);
+        assert!((zones[0].distance - 0.8).abs() < 1e-9);
+        assert_eq!(zones[1].role, ZoneRole::Resistance);
+        assert!(!zones[1].flipped);
+
+        // Only the nearest zone is kept.
+        let nearest = support_resistance_zones(&market_data, &clusters, 1);
+        assert_eq!(nearest.len(), 1);
+        assert_eq!(nearest[0].low, 10.0);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::low_high_open_close_volume_date_to_array_helper::MarketDataResult;
+use crate::structs::swing_points_struct::{ImportantLevels, SwingKind, SwingPoint};
+
+/// Indices of the swing highs and swing lows.
+///
+/// A bar is a swing high when its high is at least as high as the highs of the `left` bars before
+/// it and the `right` bars after it (strictly higher with `strict`), and a swing low likewise on
+/// the lows. Bars without enough neighbours on either side are never reported, so inputs shorter
+/// than `left + right + 1` bars have no swing.
+pub fn swing_points(highs: &[f64], lows: &[f64], left: usize, right: usize, strict: bool) -> (Vec<usize>, Vec<usize>) {
+    let mut swing_highs = Vec::new();
+    let mut swing_lows = Vec::new();
+    let beats = |value: f64, other: f64| if strict { value > other } else { value >= other };
+
+    for i in left..highs.len().saturating_sub(right) {
+        let neighbours = (i - left..i).chain(i + 1..=i + right);
+        if neighbours.clone().all(|j| beats(highs[i], highs[j])) {
+            swing_highs.push(i);
+        }
+        if neighbours.clone().all(|j| beats(-lows[i], -lows[j])) {
+            swing_lows.push(i);
+        }
+    }
+
+    (swing_highs, swing_lows)
+}
+
+/// Swing points alternating between highs and lows, in bar order.
+///
+/// Of consecutive swing points of the same kind only the most extreme is kept (the first one on a
+/// tie), and bars that are both a swing high and a swing low are skipped.
+pub fn alternating_swing_points(
+    market_data: &MarketDataResult,
+    left: usize,
+    right: usize,
+    strict: bool,
+) -> Vec<SwingPoint> {
+    let (highs, lows) = (&market_data.highs, &market_data.lows);
+    let (swing_highs, swing_lows) = swing_points(highs, lows, left, right, strict);
+    let mut points: Vec<SwingPoint> = swing_highs
+        .iter()
+        .map(|&i| (i, highs[i], SwingKind::High))
+        .chain(swing_lows.iter().map(|&i| (i, lows[i], SwingKind::Low)))
+        .filter(|&(i, _, _)| !(swing_highs.contains(&i) && swing_lows.contains(&i)))
+        .map(|(index, price, kind)| SwingPoint { index, date: market_data.dates[index].clone(), price, kind })
+        .collect();
+    points.sort_by_key(|point| point.index);
+
+    let mut alternating: Vec<SwingPoint> = Vec::with_capacity(points.len());
+    for point in points {
+        match alternating.last_mut() {
+            Some(last) if last.kind == point.kind => {
+                let more_extreme = match point.kind {
+                    SwingKind::High => point.price > last.price,
+                    SwingKind::Low => point.price < last.price,
+                };
+                if more_extreme {
+                    *last = point;
+                }
+            }
+            _ => alternating.push(point),
+        }
+    }
+    alternating
+}
+
+/// Supports and resistances at the swing points of `highs` and `lows`, dated from `market_data`.
+pub fn important_levels(
+    market_data: &MarketDataResult,
+    highs: &[f64],
+    lows: &[f64],
+    left: usize,
+    right: usize,
+    strict: bool,
+) -> ImportantLevels {
+    let (swing_highs, swing_lows) = swing_points(highs, lows, left, right, strict);
+    let point = |index: usize, price: f64, kind: SwingKind| SwingPoint {
+        index,
+        date: market_data.dates[index].clone(),
+        price,
+        kind,
+    };
+    let resistances: Vec<SwingPoint> = swing_highs.iter().map(|&i| point(i, highs[i], SwingKind::High)).collect();
+    let supports: Vec<SwingPoint> = swing_lows.iter().map(|&i| point(i, lows[i], SwingKind::Low)).collect();
+
+    let highest_resistance = if !resistances.is_empty() {
+        resistances.iter().map(|p| p.price).fold(f64::MIN, f64::max)
+    } else {
+        highs.iter().cloned().fold(f64::MIN, f64::max)
+    };
+
+    let lowest_support = if !supports.is_empty() {
+        supports.iter().map(|p| p.price).fold(f64::MAX, f64::min)
+    } else {
+        lows.iter().cloned().fold(f64::MAX, f64::min)
+    };
+
+    // A flat bar is both a swing high and a swing low of a single series, but only one pivot.
+    let mut pivots: Vec<(usize, f64)> = resistances.iter().chain(&supports).map(|p| (p.index, p.price)).collect();
+    pivots.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    pivots.dedup();
+    let average_pivot = if !pivots.is_empty() {
+        pivots.iter().map(|(_, price)| price).sum::<f64>() / pivots.len() as f64
+    } else {
+        highs.iter().chain(lows).sum::<f64>() / (highs.len() + lows.len()) as f64
+    };
+
+    ImportantLevels {
+        highest_resistance,
+        lowest_support,
+        average_pivot,
+        supports,
+        resistances,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::low_high_open_close_volume_date_to_array_helper::candles;
+
+    #[test]
+    fn test_swing_points() {
+        let values = [1.0, 3.0, 2.0, 0.0, 2.0, 1.0];
+        let (highs, lows) = swing_points(&values, &values, 1, 1, false);
+        assert_eq!(highs, vec![1, 4]);
+        assert_eq!(lows, vec![3]);
+    }
+
+    #[test]
+    fn test_swing_points_with_asymmetric_window() {
+        let values = [1.0, 3.0, 2.0, 0.0, 2.0, 1.0];
+        // Two bars on the left: the top at index 1 lacks history.
+        let (highs, _) = swing_points(&values, &values, 2, 1, false);
+        assert_eq!(highs, vec![4]);
+    }
+
+    #[test]
+    fn test_strict_swing_points_skip_equal_extremes() {
+        let values = [1.0, 3.0, 3.0, 1.0];
+        assert_eq!(swing_points(&values, &values, 1, 1, false).0, vec![1, 2]);
+        assert!(swing_points(&values, &values, 1, 1, true).0.is_empty());
+    }
+
+    #[test]
+    fn test_swing_points_on_short_input() {
+        let (highs, lows) = swing_points(&[1.0, 2.0], &[1.0, 2.0], 5, 5, false);
+        assert!(highs.is_empty());
+        assert!(lows.is_empty());
+    }
+
+    #[test]
+    fn test_alternating_swing_points_keep_the_most_extreme() {
+        let values = [0.0, 1.0, 5.0, 2.0, 3.0, 2.5, 6.0, 1.0, 0.0];
+        let market_data = candles(&values.map(|v| (v, v, v, v)));
+        // Two swing highs (5 and 6) without a swing low in between.
+        let points = alternating_swing_points(&market_data, 2, 2, false);
+        let summary: Vec<_> = points.iter().map(|p| (p.index, p.kind)).collect();
+        assert_eq!(summary, vec![(6, SwingKind::High)]);
+    }
+
+    #[test]
+    fn test_important_levels_fall_back_on_the_data() {
+        let market_data = candles(&[(1.5, 2.0, 1.0, 1.5), (3.5, 4.0, 3.0, 3.5)]);
+        let levels = important_levels(&market_data, &market_data.highs, &market_data.lows, 5, 5, false);
+        assert!(levels.supports.is_empty());
+        assert_eq!(levels.highest_resistance, 4.0);
+        assert_eq!(levels.lowest_support, 1.0);
+        assert_eq!(levels.average_pivot, 2.5);
+    }
+}
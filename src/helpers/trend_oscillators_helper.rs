@@ -0,0 +1,95 @@
+use crate::helpers::directional_movement_index_helper::true_range;
+
+/// Number of bars since the extreme of `values[start..=end]`, the most recent one on ties.
+fn bars_since_extreme(values: &[f64], start: usize, end: usize, is_more_extreme: fn(f64, f64) -> bool) -> usize {
+    let mut extreme_index = start;
+    for i in start..=end {
+        if !is_more_extreme(values[extreme_index], values[i]) {
+            extreme_index = i;
+        }
+    }
+    end - extreme_index
+}
+
+/// Aroon Up and Down: `100 * (period - bars since the highest high / lowest low) / period`,
+/// looking at the last `period + 1` bars. The first `period` values are NaN.
+pub fn aroon(highs: &[f64], lows: &[f64], period: usize) -> (Vec<f64>, Vec<f64>) {
+    let len = highs.len();
+    let mut up = vec![f64::NAN; len];
+    let mut down = vec![f64::NAN; len];
+    let scale = 100.0 / period as f64;
+
+    for i in period..len {
+        let since_high = bars_since_extreme(highs, i - period, i, |extreme, value| extreme > value);
+        let since_low = bars_since_extreme(lows, i - period, i, |extreme, value| extreme < value);
+        up[i] = (period - since_high) as f64 * scale;
+        down[i] = (period - since_low) as f64 * scale;
+    }
+
+    (up, down)
+}
+
+/// Vortex Indicator: sums of the positive and negative vortex movements over `period` bars,
+/// each divided by the sum of the true range. The first `period` values are NaN, and a window
+/// without any range yields 0 for both lines.
+pub fn vortex(highs: &[f64], lows: &[f64], closes: &[f64], period: usize) -> (Vec<f64>, Vec<f64>) {
+    let len = closes.len();
+    let mut plus = vec![f64::NAN; len];
+    let mut minus = vec![f64::NAN; len];
+
+    let mut plus_movement = vec![0.0; len];
+    let mut minus_movement = vec![0.0; len];
+    let mut ranges = vec![0.0; len];
+    for i in 1..len {
+        plus_movement[i] = (highs[i] - lows[i - 1]).abs();
+        minus_movement[i] = (lows[i] - highs[i - 1]).abs();
+        ranges[i] = true_range(highs, lows, closes, i);
+    }
+
+    for i in period..len {
+        let window = i + 1 - period..=i;
+        let range: f64 = ranges[window.clone()].iter().sum();
+        if range == 0.0 {
+            plus[i] = 0.0;
+            minus[i] = 0.0;
+        } else {
+            plus[i] = plus_movement[window.clone()].iter().sum::<f64>() / range;
+            minus[i] = minus_movement[window].iter().sum::<f64>() / range;
+        }
+    }
+
+    (plus, minus)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aroon() {
+        let highs = [1.0, 3.0, 2.0, 2.0];
+        let lows = [0.5, 2.5, 1.0, 1.5];
+        let (up, down) = aroon(&highs, &lows, 2);
+
+        assert!(up[1].is_nan());
+        // Highest high one bar ago, lowest low (0.5) two bars ago.
+        assert_eq!(up[2], 50.0);
+        assert_eq!(down[2], 0.0);
+        // Highest high two bars ago, lowest low one bar ago.
+        assert_eq!(up[3], 0.0);
+        assert_eq!(down[3], 50.0);
+    }
+
+    #[test]
+    fn test_vortex() {
+        let highs = [10.0, 12.0, 13.0];
+        let lows = [9.0, 10.0, 11.0];
+        let closes = [9.5, 11.5, 12.5];
+        let (plus, minus) = vortex(&highs, &lows, &closes, 2);
+
+        assert!(plus[1].is_nan());
+        // VM+ = 3 + 3, VM- = 0 + 1, TR = 2.5 + 2.
+        assert!((plus[2] - 6.0 / 4.5).abs() < 1e-12);
+        assert!((minus[2] - 1.0 / 4.5).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::helpers::swing_points_helper::swing_points;
+use crate::low_high_open_close_volume_date_to_array_helper::MarketDataResult;
+use crate::structs::crossover_struct::{LineCrossing, LineCrossingKind};
+use crate::structs::support_resistance_struct::ZoneRole;
+use crate::structs::trendline_struct::{TrendChannel, Trendline, TrendlineOptions, TrendlineResult};
+
+fn line_at(slope: f64, intercept: f64, index: usize) -> f64 {
+    intercept + slope * index as f64
+}
+
+/// Signed distance of `price` beyond the line, positive on the side that breaks it
+/// (above a resistance, below a support), in percent of the line's price.
+fn excess(kind: ZoneRole, price: f64, line: f64) -> f64 {
+    let distance = match kind {
+        ZoneRole::Resistance => price - line,
+        ZoneRole::Support => line - price,
+    };
+    distance / line.abs() * 100.0
+}
+
+/// Candidate line through the pivots `a` and `b` of `pivots`, or `None` when a close between
+/// them violates it or it has fewer than `min_touches` touches.
+fn fit_line(
+    market_data: &MarketDataResult,
+    kind: ZoneRole,
+    prices: &[f64],
+    pivots: &[usize],
+    (a, b): (usize, usize),
+    options: &TrendlineOptions,
+) -> Option<Trendline> {
+    let (start, end) = (pivots[a], pivots[b]);
+    let slope = (prices[end] - prices[start]) / (end - start) as f64;
+    let intercept = prices[start] - slope * start as f64;
+    let closes = &market_data.closes;
+
+    if (start..=end).any(|i| excess(kind, closes[i], line_at(slope, intercept, i)) > options.tolerance) {
+        return None;
+    }
+    let broken = (end + 1..closes.len())
+        .find(|&i| excess(kind, closes[i], line_at(slope, intercept, i)) > options.tolerance)
+        .map(|index| LineCrossing {
+            index,
+            kind: match kind {
+                ZoneRole::Resistance => LineCrossingKind::Bullish,
+                ZoneRole::Support => LineCrossingKind::Bearish,
+            },
+        });
+    let last = broken.as_ref().map_or(closes.len(), |crossing| crossing.index);
+
+    let touch_indices: Vec<usize> = pivots[a..]
+        .iter()
+        .cloned()
+        .take_while(|&i| i < last)
+        .filter(|&i| excess(kind, prices[i], line_at(slope, intercept, i)).abs() <= options.tolerance)
+        .collect();
+    if touch_indices.len() < options.min_touches {
+        return None;
+    }
+
+    Some(Trendline {
+        kind,
+        slope,
+        intercept,
+        start,
+        end,
+        touches: touch_indices.len(),
+        touch_indices,
+        broken,
+    })
+}
+
+/// Trendlines of one kind, dropping the lines whose touches are all on a stronger line.
+///
+/// Anchors are at most `max_anchor_gap` pivots apart, so `P` pivots give `O(P * max_anchor_gap)`
+/// candidates, each checked against the closes in `O(N)`.
+fn trendlines_of_kind(
+    market_data: &MarketDataResult,
+    kind: ZoneRole,
+    prices: &[f64],
+    pivots: &[usize],
+    options: &TrendlineOptions,
+) -> Vec<Trendline> {
+    let mut candidates: Vec<Trendline> = (0..pivots.len())
+        .flat_map(|a| (a + 1..pivots.len().min(a + 1 + options.max_anchor_gap)).map(move |b| (a, b)))
+        .filter_map(|anchors| fit_line(market_data, kind, prices, pivots, anchors, options))
+        .collect();
+    candidates.sort_by(|x, y| y.touches.cmp(&x.touches).then(x.start.cmp(&y.start)));
+
+    let mut lines: Vec<Trendline> = Vec::new();
+    for candidate in candidates {
+        let covered = lines
+            .iter()
+            .any(|line| candidate.touch_indices.iter().all(|i| line.touch_indices.contains(i)));
+        if !covered {
+            lines.push(candidate);
+        }
+    }
+    lines
+}
+
+/// Line parallel to `line` through the farthest opposite pivot within its span, kept when at
+/// least two opposite pivots touch it.
+fn fit_channel(
+    line_index: usize,
+    line: &Trendline,
+    prices: &[f64],
+    pivots: &[usize],
+    tolerance: f64,
+) -> Option<TrendChannel> {
+    let last = line.broken.as_ref().map_or(prices.len(), |crossing| crossing.index);
+    let inside: Vec<usize> = pivots.iter().cloned().filter(|&i| i >= line.start && i < last).collect();
+    let offset = |i: usize| prices[i] - line_at(line.slope, line.intercept, i);
+    let farthest = match line.kind {
+        ZoneRole::Support => inside.iter().cloned().max_by(|&x, &y| offset(x).total_cmp(&offset(y))),
+        ZoneRole::Resistance => inside.iter().cloned().min_by(|&x, &y| offset(x).total_cmp(&offset(y))),
+    }?;
+    let intercept = line.intercept + offset(farthest);
+
+    let touch_indices: Vec<usize> = inside
+        .into_iter()
+        .filter(|&i| {
+            let parallel = line_at(line.slope, intercept, i);
+            ((prices[i] - parallel) / parallel.abs() * 100.0).abs() <= tolerance
+        })
+        .collect();
+    if touch_indices.len() < 2 {
+        return None;
+    }
+
+    Some(TrendChannel {
+        line: line_index,
+        slope: line.slope,
+        intercept,
+        touches: touch_indices.len(),
+        touch_indices,
+    })
+}
+
+/// Support and resistance trendlines through the swing points, and the channels they form.
+pub fn trendlines(market_data: &MarketDataResult, options: &TrendlineOptions) -> TrendlineResult {
+    let (highs, lows) = (&market_data.highs, &market_data.lows);
+    let (swing_highs, swing_lows) = swing_points(highs, lows, options.left, options.right, options.strict);
+
+    let mut lines = trendlines_of_kind(market_data, ZoneRole::Support, lows, &swing_lows, options);
+    lines.extend(trendlines_of_kind(market_data, ZoneRole::Resistance, highs, &swing_highs, options));
+
+    let channels = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| match line.kind {
+            ZoneRole::Support => fit_channel(index, line, highs, &swing_highs, options.tolerance),
+            ZoneRole::Resistance => fit_channel(index, line, lows, &swing_lows, options.tolerance),
+        })
+        .collect();
+
+    TrendlineResult { lines, channels }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::low_high_open_close_volume_date_to_array_helper::candles;
+
+    /// Rising zigzag with lows on `i` (every 4 bars) and highs on `i + 4` (2 bars later).
+    fn rising_channel() -> MarketDataResult {
+        let rows: Vec<_> = (0..21)
+            .map(|i| match i % 4 {
+                0 => i as f64,
+                2 => i as f64 + 4.0,
+                _ => i as f64 + 2.0,
+            })
+            .map(|c| (c, c, c, c))
+            .collect();
+        candles(&rows)
+    }
+
+    #[test]
+    fn test_trendlines_and_channel() {
+        let market_data = rising_channel();
+        let options = TrendlineOptions { left: 1, right: 1, ..TrendlineOptions::default() };
+        let result = trendlines(&market_data, &options);
+
+        let support = result.lines.iter().find(|line| line.kind == ZoneRole::Support).unwrap();
+        assert!((support.slope - 1.0).abs() < 1e-9);
+        assert!(support.intercept.abs() < 1e-9);
+        assert_eq!(support.touch_indices, vec![4, 8, 12, 16]);
+        assert!(support.broken.is_none());
+
+        // The highs from the first support anchor on.
+        let channel = result
+            .channels
+            .iter()
+            .find(|channel| result.lines[channel.line].kind == ZoneRole::Support)
+            .unwrap();
+        assert!((channel.intercept - 4.0).abs() < 1e-9);
+        assert_eq!(channel.touch_indices, vec![6, 10, 14, 18]);
+    }
+
+    #[test]
+    fn test_trendline_break() {
+        let mut market_data = rising_channel();
+        market_data.closes[19] = 10.0;
+        market_data.lows[19] = 10.0;
+        market_data.highs[19] = 10.0;
+        let options = TrendlineOptions { left: 1, right: 1, ..TrendlineOptions::default() };
+        let result = trendlines(&market_data, &options);
+
+        let support = result.lines.iter().find(|line| line.kind == ZoneRole::Support).unwrap();
+        assert_eq!(support.broken, Some(LineCrossing { index: 19, kind: LineCrossingKind::Bearish }));
+    }
+
+    #[test]
+    fn test_anchors_are_limited_to_nearby_pivots() {
+        let prices = [1.0, 4.0, 5.0, 4.0, 3.0];
+        let market_data = candles(&prices.map(|p| (p, p, p, p)));
+        let pivots = [0, 2, 4];
+        let anchors = |max_anchor_gap: usize| -> Vec<(usize, usize)> {
+            let options = TrendlineOptions { min_touches: 2, max_anchor_gap, ..TrendlineOptions::default() };
+            let lines = trendlines_of_kind(&market_data, ZoneRole::Support, &prices, &pivots, &options);
+            let mut anchors: Vec<(usize, usize)> = lines.iter().map(|line| (line.start, line.end)).collect();
+            anchors.sort();
+            anchors
+        };
+
+        assert_eq!(anchors(5), vec![(0, 2), (0, 4), (2, 4)]);
+        // Pivots 0 and 4 are two pivots apart.
+        assert_eq!(anchors(1), vec![(0, 2), (2, 4)]);
+    }
+}
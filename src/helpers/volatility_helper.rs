@@ -0,0 +1,197 @@
+use std::f64::consts::LN_2;
+
+use crate::helpers::date_helper::{bar_interval, session_ranges, MILLIS_PER_DAY};
+use crate::helpers::rolling_statistics_helper::{
+    rolling_max, rolling_mean, rolling_min, rolling_percent_rank, rolling_variance,
+};
+use crate::low_high_open_close_volume_date_to_array_helper::MarketDataResult;
+use crate::structs::rolling_statistics_struct::DeviationType;
+use crate::structs::volatility_struct::{HistoricalVolatilityOptions, HistoricalVolatilityResult, VolatilityEstimator};
+
+/// Annualisation factor of candles spaced like `timestamps`.
+///
+/// Monthly, weekly and daily candles use 12, 52 and `trading_days` periods a year. Intraday candles
+/// use `trading_days` times the median number of candles per session, so that a 6.5 hour equity
+/// session and a 24 hour crypto one both come out right. Falls back to `trading_days` with fewer
+/// than two candles.
+pub fn periods_per_year(timestamps: &[i64], trading_days: f64) -> f64 {
+    let Some(interval) = bar_interval(timestamps) else {
+        return trading_days;
+    };
+    if interval >= 28 * MILLIS_PER_DAY {
+        return 12.0;
+    }
+    if interval >= 7 * MILLIS_PER_DAY {
+        return 52.0;
+    }
+    if interval >= MILLIS_PER_DAY {
+        return trading_days;
+    }
+    let mut counts: Vec<usize> = session_ranges(timestamps, 0).iter().map(|(start, end)| end - start + 1).collect();
+    counts.sort_unstable();
+    trading_days * counts[counts.len() / 2] as f64
+}
+
+/// Log of `a / b`.
+fn ln_ratio(a: f64, b: f64) -> f64 {
+    (a / b).ln()
+}
+
+/// Rogers-Satchell term of a candle.
+fn rogers_satchell(high: f64, low: f64, open: f64, close: f64) -> f64 {
+    ln_ratio(high, close) * ln_ratio(high, open) + ln_ratio(low, close) * ln_ratio(low, open)
+}
+
+/// Per-candle variance of the `estimator` over the last `period` candles (not annualised).
+///
+/// The estimators using the previous close (close-to-close, Yang-Zhang) start one candle later.
+pub fn volatility_variance(
+    market_data: &MarketDataResult,
+    estimator: VolatilityEstimator,
+    period: usize,
+) -> Vec<f64> {
+    let (opens, highs, lows, closes) =
+        (&market_data.opens, &market_data.highs, &market_data.lows, &market_data.closes);
+    let len = closes.len();
+    let terms = |term: &dyn Fn(usize) -> f64, from: usize| -> Vec<f64> {
+        (0..len).map(|i| if i < from { f64::NAN } else { term(i) }).collect()
+    };
+
+    match estimator {
+        VolatilityEstimator::CloseToClose => {
+            let returns = terms(&|i| ln_ratio(closes[i], closes[i - 1]), 1);
+            rolling_variance(&returns, period, DeviationType::Sample)
+        }
+        VolatilityEstimator::Parkinson => {
+            let ranges = terms(&|i| ln_ratio(highs[i], lows[i]).powi(2) / (4.0 * LN_2), 0);
+            rolling_mean(&ranges, period)
+        }
+        VolatilityEstimator::GarmanKlass => {
+            let ranges = terms(
+                &|i| {
+                    0.5 * ln_ratio(highs[i], lows[i]).powi(2)
+                        - (2.0 * LN_2 - 1.0) * ln_ratio(closes[i], opens[i]).powi(2)
+                },
+                0,
+            );
+            rolling_mean(&ranges, period)
+        }
+        VolatilityEstimator::RogersSatchell => {
+            let ranges = terms(&|i| rogers_satchell(highs[i], lows[i], opens[i], closes[i]), 0);
+            rolling_mean(&ranges, period)
+        }
+        VolatilityEstimator::YangZhang => {
+            let overnight = terms(&|i| ln_ratio(opens[i], closes[i - 1]), 1);
+            let open_to_close = terms(&|i| ln_ratio(closes[i], opens[i]), 1);
+            let ranges = terms(&|i| rogers_satchell(highs[i], lows[i], opens[i], closes[i]), 1);
+
+            let n = period as f64;
+            let k = 0.34 / (1.34 + (n + 1.0) / (n - 1.0));
+            let overnight = rolling_variance(&overnight, period, DeviationType::Sample);
+            let open_to_close = rolling_variance(&open_to_close, period, DeviationType::Sample);
+            let ranges = rolling_mean(&ranges, period);
+            (0..len).map(|i| overnight[i] + k * open_to_close[i] + (1.0 - k) * ranges[i]).collect()
+        }
+    }
+}
+
+/// Annualised historical volatility, with its percentile and rank over `rank_period` values.
+///
+/// A flat rank window yields 50.
+pub fn historical_volatility(
+    market_data: &MarketDataResult,
+    options: &HistoricalVolatilityOptions,
+    periods_per_year: f64,
+) -> HistoricalVolatilityResult {
+    let values: Vec<f64> = volatility_variance(market_data, options.estimator, options.period)
+        .into_iter()
+        // Rounding can make a range estimator slightly negative; NaN stays NaN through the warm-up.
+        .map(|variance| if variance < 0.0 { 0.0 } else { (variance * periods_per_year).sqrt() })
+        .collect();
+
+    let percentile = rolling_percent_rank(&values, options.rank_period);
+    let lowest = rolling_min(&values, options.rank_period);
+    let highest = rolling_max(&values, options.rank_period);
+    let rank = (0..values.len())
+        .map(|i| {
+            let range = highest[i] - lowest[i];
+            if range == 0.0 { 50.0 } else { (values[i] - lowest[i]) / range * 100.0 }
+        })
+        .collect();
+
+    HistoricalVolatilityResult { values, percentile, rank, periods_per_year }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::low_high_open_close_volume_date_to_array_helper::candles;
+
+    #[test]
+    fn test_periods_per_year() {
+        let day = MILLIS_PER_DAY;
+        let daily: Vec<i64> = (0..10).map(|i| i * day).collect();
+        assert_eq!(periods_per_year(&daily, 252.0), 252.0);
+        let weekly: Vec<i64> = (0..10).map(|i| i * 7 * day).collect();
+        assert_eq!(periods_per_year(&weekly, 252.0), 52.0);
+        // Two sessions of 13 half-hour candles.
+        let hour = day / 24;
+        let intraday: Vec<i64> = (0..2)
+            .flat_map(|session| (0..13).map(move |i| session * day + 13 * hour + i * hour / 2))
+            .collect();
+        assert_eq!(periods_per_year(&intraday, 252.0), 252.0 * 13.0);
+        assert_eq!(periods_per_year(&[0], 365.0), 365.0);
+    }
+
+    #[test]
+    fn test_close_to_close() {
+        let closes = [100.0, 110.0, 99.0, 108.9];
+        let data = candles(&closes.map(|c| (c, c, c, c)));
+        let variance = volatility_variance(&data, VolatilityEstimator::CloseToClose, 2);
+        assert!(variance[1].is_nan());
+        let (up, down) = (1.1f64.ln(), 0.9f64.ln());
+        let mean = (up + down) / 2.0;
+        assert!((variance[2] - ((up - mean).powi(2) + (down - mean).powi(2))).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_range_estimators() {
+        let e = std::f64::consts::E;
+        // Range of one log unit, opening on the low and closing on the high.
+        let data = candles(&[(1.0, e, 1.0, e), (1.0, e, 1.0, e)]);
+        let parkinson = volatility_variance(&data, VolatilityEstimator::Parkinson, 2);
+        assert!((parkinson[1] - 1.0 / (4.0 * LN_2)).abs() < 1e-12);
+        let garman_klass = volatility_variance(&data, VolatilityEstimator::GarmanKlass, 2);
+        assert!((garman_klass[1] - (0.5 - (2.0 * LN_2 - 1.0))).abs() < 1e-12);
+        // ln(H/C) = 0 and ln(L/O) = 0: no Rogers-Satchell variance.
+        let rogers_satchell = volatility_variance(&data, VolatilityEstimator::RogersSatchell, 2);
+        assert!(rogers_satchell[1].abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_yang_zhang_without_jumps_or_drift_variance() {
+        // Same candle every bar: no overnight or open-to-close variance, only Rogers-Satchell.
+        let data = candles(&[(10.0, 12.0, 9.0, 10.0); 4]);
+        let variance = volatility_variance(&data, VolatilityEstimator::YangZhang, 3);
+        assert!(variance[2].is_nan());
+        let k = 0.34 / (1.34 + 4.0 / 2.0);
+        let expected = (1.0 - k) * rogers_satchell(12.0, 9.0, 10.0, 10.0);
+        assert!((variance[3] - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_annualisation_percentile_and_rank() {
+        let closes = [100.0, 101.0, 100.0, 104.0, 100.0, 100.5];
+        let data = candles(&closes.map(|c| (c, c, c, c)));
+        let options =
+            HistoricalVolatilityOptions { period: 2, rank_period: 3, ..HistoricalVolatilityOptions::default() };
+        let result = historical_volatility(&data, &options, 252.0);
+        let variance = volatility_variance(&data, VolatilityEstimator::CloseToClose, 2);
+        assert!((result.values[3] - (variance[3] * 252.0).sqrt()).abs() < 1e-12);
+        assert!(result.percentile[3].is_nan());
+        // Window of volatilities 2 to 4: the widest move is the last one.
+        assert_eq!(result.percentile[4], 100.0);
+        assert_eq!(result.rank[4], 100.0);
+        assert!(result.rank[5] < 100.0);
+    }
+}
@@ -0,0 +1,251 @@
+use std::collections::VecDeque;
+
+use crate::helpers::moving_average_helper::{moving_average, MovingAverageState};
+use crate::structs::moving_average_struct::MovingAverageType;
+
+/// Money Flow Multiplier: where the close sits inside the bar's range, from -1 (low) to +1 (high).
+/// Flat bars (high == low) carry no information and return 0.
+#[inline(always)]
+pub fn money_flow_multiplier(high: f64, low: f64, close: f64) -> f64 {
+    let range = high - low;
+    if range == 0.0 {
+        0.0
+    } else {
+        ((close - low) - (high - close)) / range
+    }
+}
+
+/// Running On-Balance Volume: volume is added on up closes and subtracted on down closes.
+#[derive(Clone, Debug, Default)]
+pub struct ObvState {
+    previous_close: Option<f64>,
+    obv: f64,
+}
+
+impl ObvState {
+    pub fn next(&mut self, close: f64, volume: f64) -> f64 {
+        if let Some(previous_close) = self.previous_close {
+            if close > previous_close {
+                self.obv += volume;
+            } else if close < previous_close {
+                self.obv -= volume;
+            }
+        }
+        self.previous_close = Some(close);
+        self.obv
+    }
+}
+
+/// Running Accumulation/Distribution line: cumulative sum of the money flow volume.
+#[derive(Clone, Debug, Default)]
+pub struct AccumulationDistributionState {
+    ad: f64,
+}
+
+impl AccumulationDistributionState {
+    pub fn next(&mut self, high: f64, low: f64, close: f64, volume: f64) -> f64 {
+        self.ad += money_flow_multiplier(high, low, close) * volume;
+        self.ad
+    }
+}
+
+/// Running Chaikin Money Flow: sum of money flow volume over sum of volume for the last `period` bars.
+/// Returns NaN until `period` bars have been received.
+#[derive(Clone, Debug)]
+pub struct ChaikinMoneyFlowState {
+    period: usize,
+    window: VecDeque<(f64, f64)>,
+    money_flow_sum: f64,
+    volume_sum: f64,
+}
+
+impl ChaikinMoneyFlowState {
+    pub fn new(period: usize) -> Self {
+        ChaikinMoneyFlowState {
+            period,
+            window: VecDeque::with_capacity(period + 1),
+            money_flow_sum: 0.0,
+            volume_sum: 0.0,
+        }
+    }
+
+    pub fn next(&mut self, high: f64, low: f64, close: f64, volume: f64) -> f64 {
+        let money_flow = money_flow_multiplier(high, low, close) * volume;
+        self.window.push_back((money_flow, volume));
+        self.money_flow_sum += money_flow;
+        self.volume_sum += volume;
+
+        if self.window.len() > self.period {
+            if let Some((old_money_flow, old_volume)) = self.window.pop_front() {
+                self.money_flow_sum -= old_money_flow;
+                self.volume_sum -= old_volume;
+            }
+        }
+
+        if self.window.len() < self.period {
+            f64::NAN
+        } else if self.volume_sum == 0.0 {
+            0.0
+        } else {
+            self.money_flow_sum / self.volume_sum
+        }
+    }
+}
+
+/// Running Chaikin oscillator: fast EMA minus slow EMA of the Accumulation/Distribution line.
+///
+/// Both EMAs are SMA-seeded, so the oscillator is NaN until the slow one has `slow_period` values.
+#[derive(Clone, Debug)]
+pub struct ChaikinOscillatorState {
+    ad: AccumulationDistributionState,
+    fast: MovingAverageState,
+    slow: MovingAverageState,
+}
+
+impl ChaikinOscillatorState {
+    pub fn new(fast_period: usize, slow_period: usize) -> Self {
+        ChaikinOscillatorState {
+            ad: AccumulationDistributionState::default(),
+            fast: MovingAverageState::new(MovingAverageType::Ema, fast_period),
+            slow: MovingAverageState::new(MovingAverageType::Ema, slow_period),
+        }
+    }
+
+    pub fn next(&mut self, high: f64, low: f64, close: f64, volume: f64) -> f64 {
+        let ad = self.ad.next(high, low, close, volume);
+        self.fast.next(ad) - self.slow.next(ad)
+    }
+}
+
+/// Running Price-Volume Trend: volume weighted by the percentage change of the close.
+#[derive(Clone, Debug, Default)]
+pub struct PriceVolumeTrendState {
+    previous_close: Option<f64>,
+    pvt: f64,
+}
+
+impl PriceVolumeTrendState {
+    pub fn next(&mut self, close: f64, volume: f64) -> f64 {
+        if let Some(previous_close) = self.previous_close {
+            if previous_close != 0.0 {
+                self.pvt += volume * (close - previous_close) / previous_close;
+            }
+        }
+        self.previous_close = Some(close);
+        self.pvt
+    }
+}
+
+pub fn on_balance_volume(closes: &[f64], volumes: &[f64]) -> Vec<f64> {
+    let mut state = ObvState::default();
+    closes
+        .iter()
+        .zip(volumes)
+        .map(|(&close, &volume)| state.next(close, volume))
+        .collect()
+}
+
+pub fn accumulation_distribution(highs: &[f64], lows: &[f64], closes: &[f64], volumes: &[f64]) -> Vec<f64> {
+    let mut state = AccumulationDistributionState::default();
+    (0..closes.len())
+        .map(|i| state.next(highs[i], lows[i], closes[i], volumes[i]))
+        .collect()
+}
+
+pub fn chaikin_money_flow(highs: &[f64], lows: &[f64], closes: &[f64], volumes: &[f64], period: usize) -> Vec<f64> {
+    let mut state = ChaikinMoneyFlowState::new(period);
+    (0..closes.len())
+        .map(|i| state.next(highs[i], lows[i], closes[i], volumes[i]))
+        .collect()
+}
+
+pub fn chaikin_oscillator(
+    highs: &[f64],
+    lows: &[f64],
+    closes: &[f64],
+    volumes: &[f64],
+    fast_period: usize,
+    slow_period: usize,
+) -> Vec<f64> {
+    let mut state = ChaikinOscillatorState::new(fast_period, slow_period);
+    (0..closes.len())
+        .map(|i| state.next(highs[i], lows[i], closes[i], volumes[i]))
+        .collect()
+}
+
+pub fn price_volume_trend(closes: &[f64], volumes: &[f64]) -> Vec<f64> {
+    let mut state = PriceVolumeTrendState::default();
+    closes
+        .iter()
+        .zip(volumes)
+        .map(|(&close, &volume)| state.next(close, volume))
+        .collect()
+}
+
+/// Signal line of a volume indicator: moving average of the values, NaN while the input is still warming up.
+pub fn signal_line(values: &[f64], period: usize, kind: MovingAverageType) -> Vec<f64> {
+    moving_average(values, period, kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_on_balance_volume() {
+        let closes = [10.0, 11.0, 10.5, 10.5, 12.0];
+        let volumes = [100.0, 200.0, 150.0, 300.0, 50.0];
+        assert_eq!(on_balance_volume(&closes, &volumes), vec![0.0, 200.0, 50.0, 50.0, 100.0]);
+    }
+
+    #[test]
+    fn test_money_flow_multiplier_flat_bar() {
+        assert_eq!(money_flow_multiplier(10.0, 10.0, 10.0), 0.0);
+        assert_eq!(money_flow_multiplier(12.0, 10.0, 12.0), 1.0);
+        assert_eq!(money_flow_multiplier(12.0, 10.0, 10.0), -1.0);
+    }
+
+    #[test]
+    fn test_chaikin_money_flow_warm_up() {
+        let highs = [12.0, 12.0, 12.0];
+        let lows = [10.0, 10.0, 10.0];
+        let closes = [12.0, 10.0, 11.5];
+        let volumes = [100.0, 100.0, 200.0];
+        let cmf = chaikin_money_flow(&highs, &lows, &closes, &volumes, 2);
+        assert!(cmf[0].is_nan());
+        assert_eq!(cmf[1], 0.0);
+        assert!((cmf[2] - (-100.0 + 0.5 * 200.0) / 300.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_chaikin_oscillator_waits_for_the_slow_ema() {
+        // Every close on the high: the A/D line is the running volume, 100, 300, 600, 1000.
+        let (highs, lows, closes) = ([12.0; 4], [10.0; 4], [12.0; 4]);
+        let volumes = [100.0, 200.0, 300.0, 400.0];
+        let oscillator = chaikin_oscillator(&highs, &lows, &closes, &volumes, 2, 3);
+        assert!(oscillator[1].is_nan());
+        // Fast EMA 200 + (600 - 200) * 2/3, slow SMA seed 1000 / 3.
+        assert!((oscillator[2] - (200.0 + 800.0 / 3.0 - 1000.0 / 3.0)).abs() < 1e-9);
+        // Fast EMA 1400/3 + (1000 - 1400/3) * 2/3, slow EMA 1000/3 + (1000 - 1000/3) / 2.
+        assert!((oscillator[3] - (7400.0 / 9.0 - 2000.0 / 3.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_price_volume_trend() {
+        let closes = [10.0, 11.0, 9.9];
+        let volumes = [1000.0, 1000.0, 1000.0];
+        let pvt = price_volume_trend(&closes, &volumes);
+        assert_eq!(pvt[0], 0.0);
+        assert!((pvt[1] - 100.0).abs() < 1e-9);
+        assert!((pvt[2] - 0.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_signal_line_skips_warm_up() {
+        let signal = signal_line(&[f64::NAN, 2.0, 4.0, 6.0], 2, MovingAverageType::Sma);
+        assert!(signal[0].is_nan());
+        assert!(signal[1].is_nan());
+        assert_eq!(signal[2], 3.0);
+        assert_eq!(signal[3], 5.0);
+    }
+}
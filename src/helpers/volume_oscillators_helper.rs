@@ -0,0 +1,240 @@
+use crate::helpers::moving_average_helper::moving_average;
+use crate::helpers::smooth_helper::smooth;
+use crate::structs::moving_average_struct::MovingAverageType;
+
+#[inline(always)]
+pub fn typical_price(high: f64, low: f64, close: f64) -> f64 {
+    (high + low + close) / 3.0
+}
+
+/// Money Flow Index: a volume-weighted RSI computed on the typical price.
+///
+/// The first `period` values are NaN (each value needs `period` price changes).
+/// When there is no negative money flow in the window the MFI is 100, and 50 when there is no flow at all.
+pub fn money_flow_index(highs: &[f64], lows: &[f64], closes: &[f64], volumes: &[f64], period: usize) -> Vec<f64> {
+    let len = closes.len();
+    let mut result = vec![f64::NAN; len];
+
+    let typical_prices: Vec<f64> = (0..len)
+        .map(|i| typical_price(highs[i], lows[i], closes[i]))
+        .collect();
+
+    // Signed raw money flow for every bar (positive on a rising typical price, negative on a falling one).
+    let mut positive_flow = vec![0.0; len];
+    let mut negative_flow = vec![0.0; len];
+    for i in 1..len {
+        let raw_money_flow = typical_prices[i] * volumes[i];
+        if typical_prices[i] > typical_prices[i - 1] {
+            positive_flow[i] = raw_money_flow;
+        } else if typical_prices[i] < typical_prices[i - 1] {
+            negative_flow[i] = raw_money_flow;
+        }
+    }
+
+    for i in period..len {
+        let positive: f64 = positive_flow[i + 1 - period..=i].iter().sum();
+        let negative: f64 = negative_flow[i + 1 - period..=i].iter().sum();
+
+        result[i] = if negative == 0.0 {
+            if positive == 0.0 { 50.0 } else { 100.0 }
+        } else {
+            100.0 - 100.0 / (1.0 + positive / negative)
+        };
+    }
+
+    result
+}
+
+/// Ease of Movement: midpoint move divided by the "box ratio" (scaled volume over range).
+///
+/// Returns the raw one-bar EMV (NaN on the first bar) and its `period` SMA.
+pub fn ease_of_movement(
+    highs: &[f64],
+    lows: &[f64],
+    volumes: &[f64],
+    period: usize,
+    volume_divisor: f64,
+) -> (Vec<f64>, Vec<f64>) {
+    let len = highs.len();
+    let mut raw = vec![f64::NAN; len];
+
+    for i in 1..len {
+        let distance = (highs[i] + lows[i]) / 2.0 - (highs[i - 1] + lows[i - 1]) / 2.0;
+        let range = highs[i] - lows[i];
+        let scaled_volume = volumes[i] / volume_divisor;
+
+        raw[i] = if range == 0.0 || scaled_volume == 0.0 {
+            0.0
+        } else {
+            distance / (scaled_volume / range)
+        };
+    }
+
+    let smoothed = smooth(&raw, period);
+    (raw, smoothed)
+}
+
+/// Force Index: price change times volume, raw (NaN on the first bar) and EMA-smoothed.
+///
+/// Like every EMA of the volume indicators (Chaikin, KVO, PVO and the signal lines), it is the
+/// `moving_average` EMA, seeded with the SMA of its first `period` values; it differs from the
+/// first-value seeded `exponential_moving_average` during the first bars.
+pub fn force_index(closes: &[f64], volumes: &[f64], period: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut raw = vec![f64::NAN; closes.len()];
+    for i in 1..closes.len() {
+        raw[i] = (closes[i] - closes[i - 1]) * volumes[i];
+    }
+
+    let smoothed = moving_average(&raw, period, MovingAverageType::Ema);
+    (raw, smoothed)
+}
+
+/// Klinger Volume Oscillator: EMA(fast) - EMA(slow) of the volume force.
+///
+/// The first bar has no trend; the oscillator is NaN until the slow EMA is seeded.
+pub fn klinger_volume_oscillator(
+    highs: &[f64],
+    lows: &[f64],
+    closes: &[f64],
+    volumes: &[f64],
+    fast_period: usize,
+    slow_period: usize,
+) -> Vec<f64> {
+    let len = closes.len();
+    let mut volume_force = vec![f64::NAN; len];
+
+    let mut previous_trend = 0.0;
+    let mut cumulative_measurement = 0.0;
+
+    for i in 1..len {
+        let trend = if highs[i] + lows[i] + closes[i] > highs[i - 1] + lows[i - 1] + closes[i - 1] {
+            1.0
+        } else {
+            -1.0
+        };
+        let daily_measurement = highs[i] - lows[i];
+
+        cumulative_measurement = if trend == previous_trend {
+            cumulative_measurement + daily_measurement
+        } else {
+            (highs[i - 1] - lows[i - 1]) + daily_measurement
+        };
+        previous_trend = trend;
+
+        volume_force[i] = if cumulative_measurement == 0.0 {
+            0.0
+        } else {
+            volumes[i] * (2.0 * (daily_measurement / cumulative_measurement) - 1.0).abs() * trend * 100.0
+        };
+    }
+
+    let fast = moving_average(&volume_force, fast_period, MovingAverageType::Ema);
+    let slow = moving_average(&volume_force, slow_period, MovingAverageType::Ema);
+    fast.iter().zip(&slow).map(|(f, s)| f - s).collect()
+}
+
+/// Shared logic of the Negative and Positive Volume Indexes: the index only moves
+/// (by the close's percentage change) on bars where `moves(volume, previous_volume)` holds.
+fn volume_index(closes: &[f64], volumes: &[f64], start: f64, moves: fn(f64, f64) -> bool) -> Vec<f64> {
+    let mut result = Vec::with_capacity(closes.len());
+    let mut index = start;
+
+    for i in 0..closes.len() {
+        if i > 0 && moves(volumes[i], volumes[i - 1]) && closes[i - 1] != 0.0 {
+            index += index * (closes[i] - closes[i - 1]) / closes[i - 1];
+        }
+        result.push(index);
+    }
+
+    result
+}
+
+/// Negative Volume Index: follows the close only on bars where volume decreased.
+pub fn negative_volume_index(closes: &[f64], volumes: &[f64], start: f64) -> Vec<f64> {
+    volume_index(closes, volumes, start, |volume, previous| volume < previous)
+}
+
+/// Positive Volume Index: follows the close only on bars where volume increased.
+pub fn positive_volume_index(closes: &[f64], volumes: &[f64], start: f64) -> Vec<f64> {
+    volume_index(closes, volumes, start, |volume, previous| volume > previous)
+}
+
+/// Percentage Volume Oscillator: (EMA(fast) - EMA(slow)) / EMA(slow) * 100 of the volume.
+///
+/// NaN until the slow EMA is seeded; the signal line uses `signal_kind`. Returns `(pvo, signal, histogram)`.
+pub fn percentage_volume_oscillator(
+    volumes: &[f64],
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+    signal_kind: MovingAverageType,
+) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    let fast = moving_average(volumes, fast_period, MovingAverageType::Ema);
+    let slow = moving_average(volumes, slow_period, MovingAverageType::Ema);
+
+    let pvo: Vec<f64> = fast
+        .iter()
+        .zip(&slow)
+        .map(|(&f, &s)| if s == 0.0 { 0.0 } else { (f - s) / s * 100.0 })
+        .collect();
+    let signal = moving_average(&pvo, signal_period, signal_kind);
+    let histogram = pvo.iter().zip(&signal).map(|(p, s)| p - s).collect();
+
+    (pvo, signal, histogram)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_money_flow_index() {
+        let highs = [10.0, 11.0, 12.0, 11.0];
+        let lows = [10.0, 11.0, 12.0, 11.0];
+        let closes = [10.0, 11.0, 12.0, 11.0];
+        let volumes = [100.0, 100.0, 100.0, 100.0];
+        let mfi = money_flow_index(&highs, &lows, &closes, &volumes, 2);
+
+        assert!(mfi[0].is_nan());
+        assert!(mfi[1].is_nan());
+        assert_eq!(mfi[2], 100.0);
+        // Positive flow 1200, negative flow 1100.
+        assert!((mfi[3] - (100.0 - 100.0 / (1.0 + 1200.0 / 1100.0))).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_force_index() {
+        let (raw, smoothed) = force_index(&[10.0, 11.0, 10.5], &[100.0, 200.0, 300.0], 2);
+        assert!(raw[0].is_nan());
+        assert_eq!(raw[1], 200.0);
+        assert_eq!(raw[2], -150.0);
+        // The EMA is seeded with the SMA of the first two raw values.
+        assert!(smoothed[1].is_nan());
+        assert_eq!(smoothed[2], 25.0);
+    }
+
+    #[test]
+    fn test_negative_and_positive_volume_index() {
+        let closes = [10.0, 11.0, 12.1];
+        let volumes = [100.0, 50.0, 80.0];
+        let nvi = negative_volume_index(&closes, &volumes, 1000.0);
+        let pvi = positive_volume_index(&closes, &volumes, 1000.0);
+
+        assert!((nvi[2] - 1100.0).abs() < 1e-9);
+        assert!((pvi[1] - 1000.0).abs() < 1e-9);
+        assert!((pvi[2] - 1100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_percentage_volume_oscillator_uses_seeded_emas() {
+        let volumes = [100.0, 200.0, 300.0, 400.0];
+        let (pvo, signal, histogram) = percentage_volume_oscillator(&volumes, 2, 3, 2, MovingAverageType::Ema);
+        assert!(pvo[1].is_nan());
+        // Fast EMA 150 + (300 - 150) * 2/3 = 250 and slow seed 200 on the third bar.
+        assert_eq!(pvo[2], 25.0);
+        assert!(signal[2].is_nan());
+        // Fast 250 + (400 - 250) * 2/3 = 350, slow 200 + (400 - 200) / 2 = 300.
+        assert!((pvo[3] - 50.0 / 3.0).abs() < 1e-9);
+        assert!((histogram[3] - (pvo[3] - signal[3])).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,278 @@
+use crate::low_high_open_close_volume_date_to_array_helper::MarketDataResult;
+use crate::structs::volume_profile_struct::{
+    VolumeDistribution, VolumeProfile, VolumeProfileBin, VolumeProfileOptions,
+};
+
+/// Tolerance used when snapping prices to the grid, so that e.g. 0.3 / 0.1 lands in bin 3.
+const GRID_EPSILON: f64 = 1e-9;
+
+/// Largest grid a profile may allocate; a tiny bin size over a wide range is rejected beyond it.
+pub const MAX_BIN_COUNT: usize = 100_000;
+
+/// Price grid of a profile: `bin_count` bins of `bin_size` starting at `origin`.
+#[derive(Clone, Copy, Debug)]
+pub struct PriceBins {
+    pub origin: f64,
+    pub bin_size: f64,
+    pub bin_count: usize,
+}
+
+impl PriceBins {
+    /// Builds the grid covering `[low, high]`.
+    ///
+    /// With a `bin_size`, bins are aligned on multiples of it (so they line up with the tick grid);
+    /// otherwise the range is split into `bin_count` equal bins. Fails on a bin size that isn't
+    /// positive and on grids of more than `MAX_BIN_COUNT` bins.
+    pub fn new(low: f64, high: f64, bin_count: usize, bin_size: Option<f64>) -> Result<Self, String> {
+        match bin_size {
+            Some(size) => {
+                if size <= 0.0 || size.is_nan() {
+                    return Err("Bin size must be greater than 0.".to_string());
+                }
+                let origin = (low / size + GRID_EPSILON).floor() * size;
+                let count = ((high - origin) / size + GRID_EPSILON).floor() + 1.0;
+                if count > MAX_BIN_COUNT as f64 || count.is_nan() {
+                    return Err("Too many price bins.".to_string());
+                }
+                Ok(PriceBins { origin, bin_size: size, bin_count: (count as usize).max(1) })
+            }
+            None => {
+                if bin_count > MAX_BIN_COUNT {
+                    return Err("Too many price bins.".to_string());
+                }
+                let range = high - low;
+                let bin_size = if range > 0.0 { range / bin_count as f64 } else { 1.0 };
+                let bin_count = if range > 0.0 { bin_count } else { 1 };
+                Ok(PriceBins { origin: low, bin_size, bin_count })
+            }
+        }
+    }
+
+    #[inline(always)]
+    pub fn index_of(&self, price: f64) -> usize {
+        let index = ((price - self.origin) / self.bin_size + GRID_EPSILON).floor();
+        (index.max(0.0) as usize).min(self.bin_count - 1)
+    }
+
+    #[inline(always)]
+    pub fn lower_edge(&self, index: usize) -> f64 {
+        self.origin + index as f64 * self.bin_size
+    }
+}
+
+/// Spreads the volume of one bar over the bins its high–low range covers.
+pub fn distribute_bar_volume(
+    bins: &PriceBins,
+    volumes: &mut [f64],
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: f64,
+    distribution: VolumeDistribution,
+) {
+    if volume == 0.0 || volume.is_nan() {
+        return;
+    }
+
+    let range = high - low;
+    let first = bins.index_of(low);
+    let last = bins.index_of(high);
+    if range <= 0.0 || first == last {
+        volumes[bins.index_of(close.clamp(low, high))] += volume;
+        return;
+    }
+
+    let mut weights = Vec::with_capacity(last - first + 1);
+    for index in first..=last {
+        let bin_low = bins.lower_edge(index).max(low);
+        let bin_high = (bins.lower_edge(index) + bins.bin_size).min(high);
+        let overlap = (bin_high - bin_low).max(0.0);
+        let weight = match distribution {
+            VolumeDistribution::Uniform => overlap,
+            // Triangular weighting that peaks at the close and fades toward the far end of the bar.
+            VolumeDistribution::CloseWeighted => {
+                let middle = (bin_low + bin_high) / 2.0;
+                overlap * (1.0 - (middle - close).abs() / range)
+            }
+        };
+        weights.push(weight);
+    }
+
+    let total_weight: f64 = weights.iter().sum();
+    if total_weight <= 0.0 {
+        volumes[bins.index_of(close.clamp(low, high))] += volume;
+        return;
+    }
+
+    for (offset, weight) in weights.iter().enumerate() {
+        volumes[first + offset] += volume * weight / total_weight;
+    }
+}
+
+/// Index of the Point of Control: the bin with the most volume.
+/// Ties go to the bin closest to the middle of the profile.
+pub fn point_of_control(volumes: &[f64]) -> usize {
+    let center = (volumes.len() as f64 - 1.0) / 2.0;
+    let mut best = 0;
+    for index in 1..volumes.len() {
+        let closer = (index as f64 - center).abs() < (best as f64 - center).abs();
+        if volumes[index] > volumes[best] || (volumes[index] == volumes[best] && closer) {
+            best = index;
+        }
+    }
+    best
+}
+
+/// Value Area around the POC: adds the heavier neighbouring bin (above or below)
+/// until `value_area` (e.g. 0.7) of the total volume is covered.
+///
+/// Returns the inclusive `(low_index, high_index)` range of bins.
+pub fn value_area(volumes: &[f64], poc: usize, value_area: f64) -> (usize, usize) {
+    let total: f64 = volumes.iter().sum();
+    let target = total * value_area;
+    let (mut low, mut high) = (poc, poc);
+    let mut covered = volumes[poc];
+
+    while covered < target && (low > 0 || high + 1 < volumes.len()) {
+        let below = if low > 0 { volumes[low - 1] } else { f64::NEG_INFINITY };
+        let above = if high + 1 < volumes.len() { volumes[high + 1] } else { f64::NEG_INFINITY };
+
+        if above >= below {
+            high += 1;
+            covered += above;
+        } else {
+            low -= 1;
+            covered += below;
+        }
+    }
+
+    (low, high)
+}
+
+/// High and low volume nodes: local peaks above the average bin volume and local troughs below it.
+pub fn volume_nodes(volumes: &[f64]) -> (Vec<usize>, Vec<usize>) {
+    let mut high_nodes = Vec::new();
+    let mut low_nodes = Vec::new();
+    if volumes.len() < 3 {
+        return (high_nodes, low_nodes);
+    }
+
+    let average = volumes.iter().sum::<f64>() / volumes.len() as f64;
+    for index in 1..volumes.len() - 1 {
+        let (previous, current, next) = (volumes[index - 1], volumes[index], volumes[index + 1]);
+        if current > average && current >= previous && current >= next && (current > previous || current > next) {
+            high_nodes.push(index);
+        } else if current < average && current <= previous && current <= next && (current < previous || current < next) {
+            low_nodes.push(index);
+        }
+    }
+
+    (high_nodes, low_nodes)
+}
+
+/// Builds the volume profile of the bars `start..=end`; fails when the price grid is too large.
+pub fn build_volume_profile(
+    market_data: &MarketDataResult,
+    start: usize,
+    end: usize,
+    options: &VolumeProfileOptions,
+) -> Result<VolumeProfile, String> {
+    let (highs, lows, closes, volumes) = (
+        &market_data.highs,
+        &market_data.lows,
+        &market_data.closes,
+        &market_data.volumes,
+    );
+    let low = lows[start..=end].iter().cloned().fold(f64::INFINITY, f64::min);
+    let high = highs[start..=end].iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let bins = PriceBins::new(low, high, options.bin_count, options.bin_size)?;
+
+    let mut bin_volumes = vec![0.0; bins.bin_count];
+    for i in start..=end {
+        distribute_bar_volume(&bins, &mut bin_volumes, highs[i], lows[i], closes[i], volumes[i], options.distribution);
+    }
+
+    let poc = point_of_control(&bin_volumes);
+    let (value_area_low, value_area_high) = value_area(&bin_volumes, poc, options.value_area);
+    let (high_volume_nodes, low_volume_nodes) = volume_nodes(&bin_volumes);
+    let middle = |index: usize| bins.lower_edge(index) + bins.bin_size / 2.0;
+
+    Ok(VolumeProfile {
+        start_index: start,
+        end_index: end,
+        start_date: market_data.dates[start].clone(),
+        end_date: market_data.dates[end].clone(),
+        bin_size: bins.bin_size,
+        total_volume: bin_volumes.iter().sum(),
+        point_of_control: middle(poc),
+        value_area_high: bins.lower_edge(value_area_high) + bins.bin_size,
+        value_area_low: bins.lower_edge(value_area_low),
+        high_volume_nodes: high_volume_nodes.into_iter().map(middle).collect(),
+        low_volume_nodes: low_volume_nodes.into_iter().map(middle).collect(),
+        bins: bin_volumes
+            .iter()
+            .enumerate()
+            .map(|(index, &volume)| VolumeProfileBin {
+                price_low: bins.lower_edge(index),
+                price_high: bins.lower_edge(index) + bins.bin_size,
+                volume,
+            })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_bins_aligned_on_bin_size() {
+        let bins = PriceBins::new(10.3, 11.9, 24, Some(0.5)).unwrap();
+        assert_eq!(bins.origin, 10.0);
+        assert_eq!(bins.bin_count, 4);
+        assert_eq!(bins.index_of(11.9), 3);
+    }
+
+    #[test]
+    fn test_price_bins_snap_prices_on_a_bin_boundary() {
+        // 0.3 / 0.1 is 2.9999999999999996 in floating point: 0.3 must still open the fourth bin.
+        let bins = PriceBins::new(0.0, 0.3, 0, Some(0.1)).unwrap();
+        assert_eq!(bins.bin_count, 4);
+        assert_eq!(bins.index_of(0.3), 3);
+        assert_eq!(bins.index_of(0.2), 2);
+    }
+
+    #[test]
+    fn test_price_bins_reject_invalid_or_oversized_grids() {
+        assert!(PriceBins::new(10.0, 11.0, 0, Some(0.0)).is_err());
+        assert!(PriceBins::new(10.0, 11.0, 0, Some(-1.0)).is_err());
+        // 1e-8 over a range of 1: a hundred million bins.
+        assert_eq!(PriceBins::new(10.0, 11.0, 0, Some(1e-8)).unwrap_err(), "Too many price bins.");
+        assert!(PriceBins::new(10.0, 11.0, MAX_BIN_COUNT + 1, None).is_err());
+        assert_eq!(PriceBins::new(0.0, 999.99, 0, Some(0.01)).unwrap().bin_count, MAX_BIN_COUNT);
+    }
+
+    #[test]
+    fn test_uniform_distribution_splits_volume_by_overlap() {
+        let bins = PriceBins::new(10.0, 14.0, 4, None).unwrap();
+        let mut volumes = vec![0.0; 4];
+        distribute_bar_volume(&bins, &mut volumes, 12.0, 10.0, 11.0, 100.0, VolumeDistribution::Uniform);
+        assert_eq!(volumes, vec![50.0, 50.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_value_area_expands_toward_heavier_side() {
+        let volumes = [5.0, 10.0, 40.0, 30.0, 15.0];
+        let poc = point_of_control(&volumes);
+        assert_eq!(poc, 2);
+        assert_eq!(value_area(&volumes, poc, 0.7), (2, 3));
+        assert_eq!(value_area(&volumes, poc, 0.8), (2, 4));
+    }
+
+    #[test]
+    fn test_volume_nodes() {
+        let (high, low) = volume_nodes(&[1.0, 8.0, 2.0, 1.0, 3.0, 9.0, 4.0]);
+        assert_eq!(high, vec![1, 5]);
+        assert_eq!(low, vec![3]);
+    }
+}
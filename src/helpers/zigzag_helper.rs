@@ -0,0 +1,147 @@
+use crate::helpers::calculate_atr_helper::average_true_range;
+use crate::low_high_open_close_volume_date_to_array_helper::MarketDataResult;
+use crate::structs::swing_points_struct::{SwingKind, SwingLabel};
+use crate::structs::zigzag_struct::{ZigZagMode, ZigZagPivot};
+
+/// Labels a swing point against the previous one of the same kind: a high has to be strictly
+/// higher to be a higher high, a low strictly lower to be a lower low.
+pub fn swing_label(kind: SwingKind, price: f64, previous: Option<f64>) -> Option<SwingLabel> {
+    let previous = previous?;
+    Some(match kind {
+        SwingKind::High if price > previous => SwingLabel::HigherHigh,
+        SwingKind::High => SwingLabel::LowerHigh,
+        SwingKind::Low if price < previous => SwingLabel::LowerLow,
+        SwingKind::Low => SwingLabel::HigherLow,
+    })
+}
+
+/// Pivots of the ZigZag on the highs and lows.
+///
+/// `thresholds(i, extreme)` is the reversal needed on bar `i` to confirm `extreme`; a NaN threshold
+/// (e.g. during an ATR warm-up) never confirms. The first leg starts once the range of the bars
+/// seen so far exceeds the threshold. The last pivot is the running extreme of the current leg.
+pub fn zigzag(market_data: &MarketDataResult, thresholds: impl Fn(usize, f64) -> f64) -> Vec<ZigZagPivot> {
+    let (highs, lows) = (&market_data.highs, &market_data.lows);
+    // (index, kind, confirmed_at)
+    let mut pivots: Vec<(usize, SwingKind, Option<usize>)> = Vec::new();
+    let mut direction = 0i8;
+    let (mut high, mut low) = (0, 0);
+
+    for i in 0..highs.len() {
+        match direction {
+            0 => {
+                if highs[i] > highs[high] {
+                    high = i;
+                }
+                if lows[i] < lows[low] {
+                    low = i;
+                }
+                if highs[high] - lows[low] >= thresholds(i, if low < high { lows[low] } else { highs[high] }) {
+                    if low < high {
+                        pivots.push((low, SwingKind::Low, Some(i)));
+                        direction = 1;
+                    } else {
+                        pivots.push((high, SwingKind::High, Some(i)));
+                        direction = -1;
+                    }
+                }
+            }
+            1 => {
+                if highs[i] > highs[high] {
+                    high = i;
+                } else if highs[high] - lows[i] >= thresholds(i, highs[high]) {
+                    pivots.push((high, SwingKind::High, Some(i)));
+                    direction = -1;
+                    low = i;
+                }
+            }
+            _ => {
+                if lows[i] < lows[low] {
+                    low = i;
+                } else if highs[i] - lows[low] >= thresholds(i, lows[low]) {
+                    pivots.push((low, SwingKind::Low, Some(i)));
+                    direction = 1;
+                    high = i;
+                }
+            }
+        }
+    }
+    match direction {
+        1 => pivots.push((high, SwingKind::High, None)),
+        -1 => pivots.push((low, SwingKind::Low, None)),
+        _ => {}
+    }
+
+    let (mut previous_high, mut previous_low) = (None, None);
+    pivots
+        .into_iter()
+        .map(|(index, kind, confirmed_at)| {
+            let (price, previous) = match kind {
+                SwingKind::High => (highs[index], &mut previous_high),
+                SwingKind::Low => (lows[index], &mut previous_low),
+            };
+            let label = swing_label(kind, price, *previous);
+            *previous = Some(price);
+            ZigZagPivot {
+                index,
+                date: market_data.dates[index].clone(),
+                price,
+                kind,
+                label,
+                confirmed: confirmed_at.is_some(),
+                confirmed_at,
+            }
+        })
+        .collect()
+}
+
+/// ZigZag pivots with a threshold in the unit of `mode`.
+pub fn zigzag_pivots(
+    market_data: &MarketDataResult,
+    mode: ZigZagMode,
+    threshold: f64,
+    atr_period: usize,
+) -> Vec<ZigZagPivot> {
+    match mode {
+        ZigZagMode::Percent => zigzag(market_data, |_, extreme| extreme.abs() * threshold / 100.0),
+        ZigZagMode::Absolute => zigzag(market_data, |_, _| threshold),
+        ZigZagMode::Atr => {
+            let atr = average_true_range(&market_data.highs, &market_data.lows, &market_data.closes, atr_period);
+            zigzag(market_data, |i, _| atr[i] * threshold)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::low_high_open_close_volume_date_to_array_helper::candles;
+
+    fn market_data(closes: &[f64]) -> MarketDataResult {
+        candles(&closes.iter().map(|&c| (c, c, c, c)).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_zigzag_with_absolute_threshold() {
+        let data = market_data(&[10.0, 12.0, 11.5, 15.0, 11.0, 13.0, 9.0, 10.0, 14.0, 13.5]);
+        let pivots = zigzag(&data, |_, _| 3.0);
+
+        let summary: Vec<_> = pivots.iter().map(|p| (p.index, p.kind, p.label, p.confirmed_at)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (0, SwingKind::Low, None, Some(3)),
+                (3, SwingKind::High, None, Some(4)),
+                (6, SwingKind::Low, Some(SwingLabel::LowerLow), Some(8)),
+                (8, SwingKind::High, Some(SwingLabel::LowerHigh), None),
+            ]
+        );
+        assert!(!pivots[3].confirmed);
+    }
+
+    #[test]
+    fn test_zigzag_without_enough_movement() {
+        let data = market_data(&[10.0, 10.4, 10.2]);
+        assert!(zigzag(&data, |_, extreme| extreme * 0.05).is_empty());
+    }
+}
@@ -0,0 +1,137 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::adaptive_moving_average_helper;
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::{create_error, deserialize_js_value, serialize_to_js_value};
+
+fn parse_prices(data: &JsValue) -> Result<Vec<f64>, JsValue> {
+    let prices: Vec<f64> = deserialize_js_value(data)?;
+    if prices.is_empty() {
+        return Err(create_error("Prices vector must not be empty."));
+    }
+    Ok(prices)
+}
+
+fn validate_fast_slow(fast_period: usize, slow_period: usize) -> Result<(), JsValue> {
+    if fast_period == 0 || slow_period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    if fast_period >= slow_period {
+        return Err(create_error("Fast period must be lower than slow period."));
+    }
+    Ok(())
+}
+
+/// Kaufman Adaptive Moving Average (KAMA).
+///
+/// - `data`: JS array (`number[]`) of prices.
+/// - `period`: number of changes in the efficiency ratio (default 10).
+/// - `fast_period` / `slow_period`: EMA periods of the fastest and slowest smoothing (defaults 2 and 30).
+///
+/// Returns `{ values, efficiency_ratio, alpha }`, NaN for the first `period` prices.
+#[wasm_bindgen]
+pub fn kaufman_adaptive_moving_average(
+    data: &JsValue,
+    period: Option<usize>,
+    fast_period: Option<usize>,
+    slow_period: Option<usize>,
+) -> Result<JsValue, JsValue> {
+    let period = period.unwrap_or(10);
+    let fast_period = fast_period.unwrap_or(2);
+    let slow_period = slow_period.unwrap_or(30);
+    if period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    validate_fast_slow(fast_period, slow_period)?;
+    let prices = parse_prices(data)?;
+
+    serialize_to_js_value(&adaptive_moving_average_helper::kaufman_adaptive_moving_average(
+        &prices,
+        period,
+        fast_period,
+        slow_period,
+    ))
+}
+
+/// Chande Variable Index Dynamic Average (VIDYA).
+///
+/// - `data`: JS array (`number[]`) of prices.
+/// - `period`: period of the underlying EMA (default 14).
+/// - `cmo_period`: period of the Chande Momentum Oscillator driving the factor (default 9).
+///
+/// Returns `{ values, alpha }`, NaN for the first `cmo_period` prices.
+#[wasm_bindgen]
+pub fn variable_index_dynamic_average(
+    data: &JsValue,
+    period: Option<usize>,
+    cmo_period: Option<usize>,
+) -> Result<JsValue, JsValue> {
+    let period = period.unwrap_or(14);
+    let cmo_period = cmo_period.unwrap_or(9);
+    if period == 0 || cmo_period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    let prices = parse_prices(data)?;
+
+    serialize_to_js_value(&adaptive_moving_average_helper::variable_index_dynamic_average(
+        &prices, period, cmo_period,
+    ))
+}
+
+/// Ehlers Fractal Adaptive Moving Average (FRAMA).
+///
+/// - `data`: array of candles; the fractal dimension uses the highs and lows, the average the closes.
+/// - `period`: even look-back window (default 16).
+/// - `fast_period` / `slow_period`: bounds of the equivalent EMA period (defaults 1 and 198, Ehlers' original).
+///
+/// Returns `{ values, alpha }`, NaN for the first `period - 1` candles.
+#[wasm_bindgen]
+pub fn fractal_adaptive_moving_average(
+    data: JsValue,
+    period: Option<usize>,
+    fast_period: Option<usize>,
+    slow_period: Option<usize>,
+) -> Result<JsValue, JsValue> {
+    let period = period.unwrap_or(16);
+    let fast_period = fast_period.unwrap_or(1);
+    let slow_period = slow_period.unwrap_or(198);
+    if period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    if !period.is_multiple_of(2) {
+        return Err(create_error("Period must be an even number."));
+    }
+    validate_fast_slow(fast_period, slow_period)?;
+    let market_data = market_data_from_js(data)?;
+
+    serialize_to_js_value(&adaptive_moving_average_helper::fractal_adaptive_moving_average(
+        &market_data.highs,
+        &market_data.lows,
+        &market_data.closes,
+        period,
+        fast_period,
+        slow_period,
+    ))
+}
+
+/// McGinley Dynamic.
+///
+/// - `data`: JS array (`number[]`) of prices.
+/// - `period`: length of the line (default 14).
+/// - `constant`: McGinley's constant `k` (default 0.6).
+///
+/// Returns `{ values, alpha }`; the line starts at the SMA of the first `period` prices.
+#[wasm_bindgen]
+pub fn mcginley_dynamic(data: &JsValue, period: Option<usize>, constant: Option<f64>) -> Result<JsValue, JsValue> {
+    let period = period.unwrap_or(14);
+    let constant = constant.unwrap_or(0.6);
+    if period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    if constant <= 0.0 {
+        return Err(create_error("Constant must be greater than 0."));
+    }
+    let prices = parse_prices(data)?;
+
+    serialize_to_js_value(&adaptive_moving_average_helper::mcginley_dynamic(&prices, period, constant))
+}
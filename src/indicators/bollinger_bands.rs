@@ -1,32 +1,44 @@
-
 use wasm_bindgen::prelude::*;
-use crate::{create_error, deserialize_js_value, serialize_to_js_value};
+use crate::{create_error, deserialize_js_value, deserialize_options, serialize_to_js_value};
+use crate::bollinger_bands_struct::{BollingerBandLines, BollingerBandsOptions};
+use crate::structs::rolling_statistics_struct::DeviationType;
 use crate::helpers::bollinger_bands_helper::compute_bollinger_bands;
 
 /// WASM-exposed function that calculates Bollinger Bands.
 ///
 /// - `data`: JS array (`number[]`) containing price values.
-/// - `period`: number of periods for the moving average.
-/// - `multiplier`: coefficient (often 2) for calculating the upper and lower bands.
+/// - `options`: `{ period, multiplier, upper_multiplier, lower_multiplier, ma_type, deviation, squeeze_period }`,
+///   all optional (defaults 20, 2, `multiplier`, `multiplier`, `"sma"`, `"population"` and 125).
+///
+/// Returns `{ middle, upper, lower, percent_b, bandwidth, squeeze }`, aligned with the prices
+/// (NaN during the warm-up).
 ///
-/// Returns a serialized JS object containing three arrays of numbers.
+/// The former positional form `(data, period, multiplier)` is still accepted: a number as second
+/// argument is the period and `multiplier` the band width, and only `{ middle, upper, lower }` are
+/// returned, without the warm-up, as before.
 #[wasm_bindgen]
-pub fn bollinger_bands(
-    data: &JsValue,
-    period: Option<usize>,
-    multiplier: Option<f64>
-) -> Result<JsValue, JsValue> {
-
-    // Default values
-    let period = period.unwrap_or(20);
-    let multiplier = multiplier.unwrap_or(2f64);
+pub fn bollinger_bands(data: &JsValue, options: JsValue, multiplier: Option<f64>) -> Result<JsValue, JsValue> {
+    let legacy_period = options.as_f64();
+    let options: BollingerBandsOptions = match legacy_period {
+        Some(period) if period <= 0.0 => return Err(create_error("Period must be greater than 0.")),
+        Some(period) => BollingerBandsOptions {
+            period: period as usize,
+            multiplier: multiplier.unwrap_or(2.0),
+            ..BollingerBandsOptions::default()
+        },
+        None => deserialize_options(&options)?,
+    };
     let prices: Vec<f64> = deserialize_js_value(data)?;
 
-    if period <= 0 {
+    if options.period == 0 {
         return Err(create_error("Period must be greater than 0."));
     }
 
-    if multiplier <= 0.0 {
+    if options.deviation == DeviationType::Sample && options.period < 2 {
+        return Err(create_error("Sample deviation requires a period greater than 1."));
+    }
+
+    if options.upper_multiplier() <= 0.0 || options.lower_multiplier() <= 0.0 {
         return Err(create_error("Multiplier must be greater than 0."));
     }
 
@@ -36,8 +48,17 @@ pub fn bollinger_bands(
     }
 
     // Compute Bollinger Bands using the helper function
-    let result = compute_bollinger_bands(&prices, period, multiplier);
+    let result = compute_bollinger_bands(&prices, &options);
+
+    if legacy_period.is_some() {
+        let trim = |values: Vec<f64>| values.into_iter().skip(options.period - 1).collect();
+        return serialize_to_js_value(&BollingerBandLines {
+            middle: trim(result.middle),
+            upper: trim(result.upper),
+            lower: trim(result.lower),
+        });
+    }
 
-    // Serialize the result into JsValue (a JS object with 3 properties containing number[])
-    Ok(serialize_to_js_value(&result)?)
+    // Serialize the result into JsValue (a JS object whose properties are aligned with the prices)
+    serialize_to_js_value(&result)
 }
@@ -0,0 +1,37 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::candlestick_pattern_helper::{candlestick_patterns as find_patterns, trend_context};
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::candlestick_pattern_struct::CandlestickOptions;
+use crate::{create_error, deserialize_options, serialize_to_js_value};
+
+/// Candlestick pattern recognition.
+///
+/// - `data`: array of candles.
+/// - `options`: `{ doji_body_ratio, long_shadow_ratio, short_shadow_ratio, tweezer_tolerance,
+///   trend_period, trend_type }`, all optional. With `trend_period`, the close against its moving
+///   average gives the trend before each pattern: it tells a hammer from a hanging man and an
+///   inverted hammer from a shooting star, and weighs the confidence of reversals.
+///
+/// Returns the hits `{ index, pattern, direction, bars, confidence }`, `index` being the last
+/// candle of the pattern.
+#[wasm_bindgen]
+pub fn candlestick_patterns(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: CandlestickOptions = deserialize_options(&options)?;
+    if options.trend_period == Some(0) {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    let ratios = [
+        options.doji_body_ratio,
+        options.long_shadow_ratio,
+        options.short_shadow_ratio,
+        options.tweezer_tolerance,
+    ];
+    if ratios.iter().any(|&ratio| ratio < 0.0) {
+        return Err(create_error("Ratios must not be negative."));
+    }
+    let market_data = market_data_from_js(data)?;
+
+    let trends = trend_context(&market_data.closes, &options);
+    serialize_to_js_value(&find_patterns(&market_data, &trends, &options))
+}
@@ -0,0 +1,31 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::chart_pattern_helper::chart_patterns as find_patterns;
+use crate::helpers::swing_points_helper::alternating_swing_points;
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::chart_pattern_struct::ChartPatternOptions;
+use crate::{create_error, deserialize_options, serialize_to_js_value};
+
+/// Chart patterns on the swing points.
+///
+/// - `data`: array of candles.
+/// - `options`: `{ left, right, strict, tolerance, shoulder_tolerance, pole_ratio }`, all optional.
+///   Swing points are detected as in `extract_important_levels` (3 bars on each side by default)
+///   and reduced to alternating highs and lows.
+///
+/// Returns the double/triple tops and bottoms, (inverse) head and shoulders, triangles, wedges,
+/// flags and pennants as `{ pattern, direction, pivots, upper, lower, breakout, confirmed, target }`.
+#[wasm_bindgen]
+pub fn chart_patterns(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: ChartPatternOptions = deserialize_options(&options)?;
+    if options.tolerance < 0.0 || options.shoulder_tolerance < 0.0 {
+        return Err(create_error("Tolerance must not be negative."));
+    }
+    if options.pole_ratio <= 0.0 {
+        return Err(create_error("Pole ratio must be greater than 0."));
+    }
+    let market_data = market_data_from_js(data)?;
+
+    let pivots = alternating_swing_points(&market_data, options.left, options.right, options.strict);
+    serialize_to_js_value(&find_patterns(&pivots, &market_data.closes, &options))
+}
@@ -0,0 +1,33 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::divergence_helper;
+use crate::structs::divergence_struct::DivergenceOptions;
+use crate::{create_error, deserialize_js_value, deserialize_options, serialize_to_js_value};
+
+/// Price/oscillator divergences.
+///
+/// - `prices`: JS array (`number[]`) of prices, usually the closes.
+/// - `oscillator`: JS array (`number[]`) with any oscillator of the crate (RSI, stochastic %K, MACD
+///   line, ...); leading NaN values are fine and a shorter one is right-aligned on the prices.
+/// - `options`: `{ left, right, max_offset, min_span, max_span, hidden }`, all optional.
+///
+/// Returns `{ kind, price, oscillator, span, strength }` for every regular (and hidden) bullish or
+/// bearish divergence between consecutive price pivots.
+#[wasm_bindgen]
+pub fn divergence(prices: &JsValue, oscillator: &JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: DivergenceOptions = deserialize_options(&options)?;
+    if options.min_span > options.max_span {
+        return Err(create_error("Minimum span must not be greater than maximum span."));
+    }
+    let prices: Vec<f64> = deserialize_js_value(prices)?;
+    let oscillator: Vec<f64> = deserialize_js_value(oscillator)?;
+    if prices.is_empty() {
+        return Err(create_error("Prices vector must not be empty."));
+    }
+    if oscillator.len() > prices.len() {
+        return Err(create_error("Oscillator must not be longer than the prices."));
+    }
+
+    let oscillator = divergence_helper::align_oscillator(&oscillator, prices.len());
+    serialize_to_js_value(&divergence_helper::divergences(&prices, &oscillator, &options))
+}
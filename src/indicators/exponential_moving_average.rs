@@ -2,6 +2,9 @@ use crate::calculate_ema_helper::calculate_ema;
 use crate::{create_error, jsvalue_to_f64, serialize_to_js_value};
 use wasm_bindgen::prelude::*;
 
+/// Exponential moving average seeded with the first value: there is no warm-up and the first
+/// values lean on it. `moving_average(data, period, "ema")` seeds with the SMA of the first
+/// `period` values instead and is NaN until then, so the two differ over the first bars.
 #[wasm_bindgen]
 pub fn exponential_moving_average(data: JsValue, period: usize) -> Result<JsValue, JsValue> {
     let vec_data = jsvalue_to_f64(data);
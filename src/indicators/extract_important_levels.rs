@@ -1,77 +1,40 @@
-use crate::serialize_to_js_value;
-use crate::structs::extract_important_levels_struct::DataEntry;
 use wasm_bindgen::prelude::*;
 
+use crate::helpers::price_source_helper::price_source;
+use crate::helpers::swing_points_helper::important_levels;
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::swing_points_struct::SwingPointsOptions;
+use crate::{deserialize_options, serialize_to_js_value};
+
+/// Supports and resistances at the swing points of the candles.
+///
+/// - `data`: array of candles.
+/// - `options`: `{ left, right, strict, source }`, all optional. A swing high must not be exceeded
+///   by the `left` bars before it and the `right` bars after it (defaults 5 and 5); `strict`
+///   requires its neighbours to be strictly lower. Swing highs are found on the highs and swing lows
+///   on the lows, unless a `source` series is given for both.
+///
+/// Returns `{ highest_resistance, lowest_support, average_pivot, supports, resistances }`, each
+/// swing point with its `index`, `date`, `price` and `kind`.
 #[wasm_bindgen]
-impl DataEntry {
-    pub fn new(
-        pivot_point: f64,
-        resistance1: f64,
-        resistance2: f64,
-        support1: f64,
-        support2: f64,
-    ) -> DataEntry {
-        DataEntry {
-            pivot_point,
-            resistance1,
-            resistance2,
-            support1,
-            support2,
+pub fn extract_important_levels(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: SwingPointsOptions = deserialize_options(&options)?;
+    let market_data = market_data_from_js(data)?;
+
+    let levels = match options.source {
+        Some(source) => {
+            let values = price_source(&market_data, source);
+            important_levels(&market_data, &values, &values, options.left, options.right, options.strict)
         }
-    }
-}
-
-#[wasm_bindgen]
-pub fn extract_important_levels(data: &[f64]) -> Result<JsValue, JsValue> {
-    let mut supports = Vec::new();
-    let mut resistances = Vec::new();
-    let mut pivot_points = Vec::new();
-
-    const WINDOW: usize = 5;
-
-    for i in WINDOW..(data.len() - WINDOW) {
-        let current = data[i];
-        let is_highest = (i - WINDOW..i).all(|j| data[j] <= current)
-            && (i + 1..=i + WINDOW).all(|j| data[j] <= current);
-        let is_lowest = (i - WINDOW..i).all(|j| data[j] >= current)
-            && (i + 1..=i + WINDOW).all(|j| data[j] >= current);
-
-        if is_highest {
-            resistances.push(current);
-        }
-
-        if is_lowest {
-            supports.push(current);
-        }
-
-        if is_highest || is_lowest {
-            pivot_points.push(current);
-        }
-    }
-
-    let highest_resistance = if !resistances.is_empty() {
-        resistances.iter().cloned().fold(f64::MIN, f64::max)
-    } else {
-        data.iter().cloned().fold(f64::MIN, f64::max)
-    };
-
-    let lowest_support = if !supports.is_empty() {
-        supports.iter().cloned().fold(f64::MAX, f64::min)
-    } else {
-        data.iter().cloned().fold(f64::MAX, f64::min)
-    };
-
-    let average_pivot = if !pivot_points.is_empty() {
-        pivot_points.iter().sum::<f64>() / pivot_points.len() as f64
-    } else {
-        data.iter().sum::<f64>() / data.len() as f64
+        None => important_levels(
+            &market_data,
+            &market_data.highs,
+            &market_data.lows,
+            options.left,
+            options.right,
+            options.strict,
+        ),
     };
 
-    serialize_to_js_value(&(
-        highest_resistance,
-        lowest_support,
-        average_pivot,
-        supports,
-        resistances,
-    ))
+    serialize_to_js_value(&levels)
 }
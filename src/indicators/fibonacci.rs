@@ -0,0 +1,42 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::fibonacci_helper::{fibonacci_levels as compute_fibonacci_levels, latest_swing, swing_direction};
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::fibonacci_struct::FibonacciOptions;
+use crate::{create_error, deserialize_options, serialize_to_js_value};
+
+/// Fibonacci retracements, extensions and time zones of a swing.
+///
+/// - `data`: array of candles.
+/// - `options`: `{ start, end, window, retracements, extensions, tolerance }`, all optional. Without
+///   `start` and `end`, the levels are drawn on the most recent swing found with the same detection
+///   as `extract_important_levels` (`window` bars on each side, default 5), so they move on to the
+///   next swing as soon as a new pivot is confirmed.
+///
+/// Returns `{ start, end, direction, retracements, extensions, time_zones, reactions }`, where
+/// `reactions` lists the bars after the swing that were rejected by a level.
+#[wasm_bindgen]
+pub fn fibonacci_levels(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: FibonacciOptions = deserialize_options(&options)?;
+    if options.tolerance < 0.0 {
+        return Err(create_error("Tolerance must not be negative."));
+    }
+    let market_data = market_data_from_js(data)?;
+
+    let (start, end, direction) = match (options.start, options.end) {
+        (Some(start), Some(end)) => {
+            if start >= end {
+                return Err(create_error("Start index must be lower than end index."));
+            }
+            if end >= market_data.closes.len() {
+                return Err(create_error("End index is out of range."));
+            }
+            (start, end, swing_direction(&market_data.highs, &market_data.lows, start, end))
+        }
+        (None, None) => latest_swing(&market_data.highs, &market_data.lows, options.window)
+            .ok_or_else(|| create_error("Not enough swing points to anchor the levels."))?,
+        _ => return Err(create_error("Start and end must be given together.")),
+    };
+
+    serialize_to_js_value(&compute_fibonacci_levels(&market_data, start, end, direction, &options))
+}
@@ -0,0 +1,34 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::harmonic_pattern_helper::harmonic_patterns as find_patterns;
+use crate::helpers::zigzag_helper::zigzag_pivots;
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::harmonic_pattern_struct::HarmonicOptions;
+use crate::{create_error, deserialize_options, serialize_to_js_value};
+
+/// Harmonic patterns (Gartley, Bat, Butterfly, Crab and Cypher) on the ZigZag pivots.
+///
+/// - `data`: array of candles.
+/// - `options`: `{ mode, threshold, atr_period, tolerance }`, all optional. The first three set the
+///   ZigZag as in `zigzag` (3% reversals by default); `tolerance` is how far, in percent, a ratio
+///   may fall outside its standard range (default 5).
+///
+/// Returns `{ pattern, direction, points, ratios, error, prz_low, prz_high }` for every match. D is
+/// the last ZigZag pivot while the pattern is forming, with `confirmed: false`.
+#[wasm_bindgen]
+pub fn harmonic_patterns(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: HarmonicOptions = deserialize_options(&options)?;
+    if options.threshold <= 0.0 {
+        return Err(create_error("Threshold must be greater than 0."));
+    }
+    if options.atr_period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    if options.tolerance < 0.0 {
+        return Err(create_error("Tolerance must not be negative."));
+    }
+    let market_data = market_data_from_js(data)?;
+
+    let pivots = zigzag_pivots(&market_data, options.mode, options.threshold, options.atr_period);
+    serialize_to_js_value(&find_patterns(&pivots, options.tolerance))
+}
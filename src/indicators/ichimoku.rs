@@ -1,6 +1,7 @@
 use wasm_bindgen::prelude::*;
 use thiserror::Error;
-use crate::helpers::ichimoku_helper::{average_series, calculate_midline, shift_forward};
+use crate::helpers::date_helper::{bar_interval, format_timestamp, parse_timestamps};
+use crate::helpers::ichimoku_helper::{average_series, calculate_midline, extend_forward, ichimoku_signals, pad_to};
 use crate::structs::ichimoku_struct::IchimokuResult;
 use crate::low_high_open_close_volume_date_to_array_helper::{
     low_high_open_close_volume_date_to_array,
@@ -30,6 +31,7 @@ struct IchimokuParams {
     period_tenkan: usize,
     period_kijun: usize,
     period_senkou: usize,
+    displacement: usize,
 }
 
 impl Default for IchimokuParams {
@@ -38,6 +40,7 @@ impl Default for IchimokuParams {
             period_tenkan: 9,
             period_kijun: 26,
             period_senkou: 52,
+            displacement: 26,
         }
     }
 }
@@ -47,15 +50,23 @@ impl IchimokuParams {
         period_tenkan: Option<usize>,
         period_kijun: Option<usize>,
         period_senkou: Option<usize>,
+        displacement: Option<usize>,
     ) -> Result<Self, IchimokuError> {
+        let period_kijun = period_kijun.unwrap_or(Self::default().period_kijun);
         let params = Self {
             period_tenkan: period_tenkan.unwrap_or(Self::default().period_tenkan),
-            period_kijun: period_kijun.unwrap_or(Self::default().period_kijun),
+            period_kijun,
             period_senkou: period_senkou.unwrap_or(Self::default().period_senkou),
+            // Sans valeur explicite, le décalage suit la période Kijun.
+            displacement: displacement.unwrap_or(period_kijun),
         };
 
         // Validation des paramètres
-        if params.period_tenkan == 0 || params.period_kijun == 0 || params.period_senkou == 0 {
+        if params.period_tenkan == 0
+            || params.period_kijun == 0
+            || params.period_senkou == 0
+            || params.displacement == 0
+        {
             return Err(IchimokuError::InvalidInput(
                 "Periods must be greater than 0".to_string(),
             ));
@@ -91,6 +102,17 @@ fn validate_market_data(market_data: &MarketDataResult, params: &IchimokuParams)
     Ok(())
 }
 
+/// Dates of the `count` projected bars, spaced by the typical bar interval of the input.
+fn future_dates(dates: &[String], count: usize) -> Vec<String> {
+    let Ok(timestamps) = parse_timestamps(dates) else {
+        return Vec::new();
+    };
+    let (Some(interval), Some(&last)) = (bar_interval(&timestamps), timestamps.last()) else {
+        return Vec::new();
+    };
+    (1..=count as i64).map(|step| format_timestamp(last + step * interval)).collect()
+}
+
 fn calculate_ichimoku_values(
     market_data: &MarketDataResult,
     params: &IchimokuParams,
@@ -99,26 +121,39 @@ fn calculate_ichimoku_values(
     let tenkan_sen = calculate_midline(&market_data.highs, &market_data.lows, params.period_tenkan);
     let kijun_sen = calculate_midline(&market_data.highs, &market_data.lows, params.period_kijun);
 
-    // Calcul de Senkou Span A (moyennes de Tenkan et Kijun)
-    let senkou_span_a = shift_forward(
+    // Calcul de Senkou Span A (moyennes de Tenkan et Kijun), projetée dans le futur
+    let senkou_span_a = extend_forward(
         &average_series(&tenkan_sen, &kijun_sen),
-        params.period_kijun
+        params.displacement
     );
 
-    // Calcul de Senkou Span B
-    let senkou_span_b = shift_forward(
+    // Calcul de Senkou Span B, projetée dans le futur
+    let senkou_span_b = extend_forward(
         &calculate_midline(&market_data.highs, &market_data.lows, params.period_senkou),
-        params.period_kijun
+        params.displacement
     );
 
-    let chikou_span = shift_backward(&market_data.closes, params.period_kijun);
+    let signals = ichimoku_signals(
+        &market_data.closes,
+        &tenkan_sen,
+        &kijun_sen,
+        &senkou_span_a,
+        &senkou_span_b,
+        params.displacement,
+    );
+
+    // Toutes les séries couvrent les barres projetées
+    let len = senkou_span_a.len();
+    let chikou_span = pad_to(&shift_backward(&market_data.closes, params.displacement), len);
 
     Ok(IchimokuResult {
-        tenkan_sen,
-        kijun_sen,
+        tenkan_sen: pad_to(&tenkan_sen, len),
+        kijun_sen: pad_to(&kijun_sen, len),
         senkou_span_a,
         senkou_span_b,
-        chikou_span
+        chikou_span,
+        future_dates: future_dates(&market_data.dates, params.displacement),
+        signals,
     })
 }
 
@@ -128,9 +163,10 @@ pub fn ichimoku(
     period_tenkan: Option<usize>,
     period_kijun: Option<usize>,
     period_senkou: Option<usize>,
+    displacement: Option<usize>,
 ) -> Result<JsValue, JsValue> {
     // Initialisation et validation des paramètres
-    let params = IchimokuParams::new(period_tenkan, period_kijun, period_senkou)
+    let params = IchimokuParams::new(period_tenkan, period_kijun, period_senkou, displacement)
         .map_err(|e| e.to_string())?;
 
     // Traitement des données d'entrée
@@ -154,14 +190,33 @@ pub fn ichimoku(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::low_high_open_close_volume_date_to_array_helper::candles;
 
     #[test]
     fn test_ichimoku_params_validation() {
-        assert!(IchimokuParams::new(Some(0), Some(26), Some(52)).is_err());
-        assert!(IchimokuParams::new(Some(9), Some(0), Some(52)).is_err());
-        assert!(IchimokuParams::new(Some(9), Some(26), Some(0)).is_err());
+        assert!(IchimokuParams::new(Some(0), Some(26), Some(52), None).is_err());
+        assert!(IchimokuParams::new(Some(9), Some(0), Some(52), None).is_err());
+        assert!(IchimokuParams::new(Some(9), Some(26), Some(0), None).is_err());
+        assert!(IchimokuParams::new(Some(9), Some(26), Some(52), Some(0)).is_err());
 
-        let valid_params = IchimokuParams::new(Some(9), Some(26), Some(52));
+        let valid_params = IchimokuParams::new(Some(9), Some(26), Some(52), None);
         assert!(valid_params.is_ok());
+        assert_eq!(valid_params.unwrap().displacement, 26);
+
+        let crypto_params = IchimokuParams::new(Some(20), Some(60), Some(120), Some(30)).unwrap();
+        assert_eq!(crypto_params.displacement, 30);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_displacement_longer_than_the_data() {
+        let rows: Vec<_> = (0..60).map(|i| 100.0 + i as f64).map(|c| (c, c + 1.0, c - 1.0, c)).collect();
+        let mut market_data = candles(&rows);
+        market_data.dates = (0..60).map(|i| format!("2025-01-01T{:02}:{:02}:00Z", i / 60, i % 60)).collect();
+        let params = IchimokuParams::new(None, None, None, Some(100)).unwrap();
+        let result = calculate_ichimoku_values(&market_data, &params).unwrap();
+
+        assert_eq!(result.chikou_span.len(), 160);
+        assert!(result.chikou_span.iter().all(|v| v.is_nan()));
+        assert_eq!(result.future_dates.len(), 100);
+    }
+}
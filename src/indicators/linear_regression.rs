@@ -0,0 +1,88 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::linear_regression_helper;
+use crate::helpers::price_source_helper::price_source;
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::linear_regression_struct::{
+    LinearRegressionOptions, RegressionChannelOptions, StandardErrorBandsOptions,
+};
+use crate::{create_error, deserialize_options, serialize_to_js_value};
+
+fn validate_period(period: usize) -> Result<(), JsValue> {
+    if period < 2 {
+        return Err(create_error("Period must be greater than 1."));
+    }
+    Ok(())
+}
+
+fn validate_deviations(deviations: f64) -> Result<(), JsValue> {
+    if deviations <= 0.0 {
+        return Err(create_error("Deviations must be greater than 0."));
+    }
+    Ok(())
+}
+
+/// Rolling linear regression.
+///
+/// - `data`: array of candles.
+/// - `options`: `{ period, source }`, all optional (defaults 14 and `"close"`).
+///
+/// Returns `{ slope, intercept, value, forecast, r_squared, standard_error }`, aligned with the
+/// candles (NaN during the warm-up). `intercept` is the line on the first candle of each window,
+/// `value` on the current one and `forecast` one candle ahead.
+#[wasm_bindgen]
+pub fn linear_regression(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: LinearRegressionOptions = deserialize_options(&options)?;
+    validate_period(options.period)?;
+    let market_data = market_data_from_js(data)?;
+
+    let source = price_source(&market_data, options.source);
+    serialize_to_js_value(&linear_regression_helper::linear_regression(&source, options.period))
+}
+
+/// Linear regression channel and Raff channel over the last `period` candles.
+///
+/// - `data`: array of candles.
+/// - `options`: `{ period, source, deviations }`, all optional (defaults 100, `"close"` and 2).
+///
+/// Returns `{ start, end, slope, intercept, r_squared, standard_error, middle, upper, lower,
+/// raff_upper, raff_lower }`; the lines are aligned with the candles, NaN before `start`.
+#[wasm_bindgen]
+pub fn linear_regression_channel(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: RegressionChannelOptions = deserialize_options(&options)?;
+    validate_period(options.period)?;
+    validate_deviations(options.deviations)?;
+    let market_data = market_data_from_js(data)?;
+    if market_data.closes.len() < options.period {
+        return Err(create_error("Not enough data."));
+    }
+
+    let source = price_source(&market_data, options.source);
+    serialize_to_js_value(&linear_regression_helper::regression_channel(&market_data, &source, &options))
+}
+
+/// Standard error bands.
+///
+/// - `data`: array of candles.
+/// - `options`: `{ period, source, deviations, smoothing }`, all optional (defaults 21, `"close"`, 2
+///   and 3).
+///
+/// Returns `{ middle, upper, lower }`, aligned with the candles (NaN during the warm-up).
+#[wasm_bindgen]
+pub fn standard_error_bands(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: StandardErrorBandsOptions = deserialize_options(&options)?;
+    validate_period(options.period)?;
+    validate_deviations(options.deviations)?;
+    if options.smoothing == 0 {
+        return Err(create_error("Smoothing must be greater than 0."));
+    }
+    let market_data = market_data_from_js(data)?;
+
+    let source = price_source(&market_data, options.source);
+    serialize_to_js_value(&linear_regression_helper::standard_error_bands(
+        &source,
+        options.period,
+        options.deviations,
+        options.smoothing,
+    ))
+}
@@ -0,0 +1,43 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::date_helper::{parse_timestamps, session_ranges};
+use crate::helpers::market_profile_helper::build_tpo_profile;
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::market_profile_struct::MarketProfileOptions;
+use crate::{create_error, deserialize_options, serialize_to_js_value};
+
+/// Market Profile (TPO) construction from intraday candles.
+///
+/// - `data`: array of intraday candles with parseable dates.
+/// - `options`: `{ tick_size, period_minutes, initial_balance_periods, value_area,
+///   session_offset_minutes, letters }`; only `tick_size` is required.
+///
+/// Returns one TPO profile per session, with a row per price level ready to be drawn.
+#[wasm_bindgen]
+pub fn market_profile(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: MarketProfileOptions = deserialize_options(&options)?;
+
+    if options.tick_size <= 0.0 {
+        return Err(create_error("Tick size must be greater than 0."));
+    }
+    if options.period_minutes <= 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    if options.value_area <= 0.0 || options.value_area > 1.0 {
+        return Err(create_error("Value area must be between 0 and 1."));
+    }
+    if options.letters.is_empty() {
+        return Err(create_error("Letters must not be empty."));
+    }
+
+    let market_data = market_data_from_js(data)?;
+    let timestamps = parse_timestamps(&market_data.dates).map_err(|e| create_error(&e))?;
+
+    let profiles = session_ranges(&timestamps, options.session_offset_minutes)
+        .into_iter()
+        .map(|(start, end)| build_tpo_profile(&market_data, &timestamps, start, end, &options))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| create_error(&e))?;
+
+    serialize_to_js_value(&profiles)
+}
@@ -10,4 +10,24 @@ pub mod extract_important_levels;
 pub mod stochastic_momentum_index;
 pub mod stochastic_oscillator;
 pub mod trends_meter;
-pub mod parabolic_sar;
\ No newline at end of file
+pub mod parabolic_sar;
+pub mod volume_flow;
+pub mod volume_oscillators;
+pub mod volume_profile;
+pub mod market_profile;
+pub mod moving_average;
+pub mod adaptive_moving_average;
+pub mod momentum_oscillators;
+pub mod trend_oscillators;
+pub mod supertrend;
+pub mod fibonacci;
+pub mod zigzag;
+pub mod support_resistance;
+pub mod trendline;
+pub mod candlestick_pattern;
+pub mod chart_pattern;
+pub mod harmonic_pattern;
+pub mod divergence;
+pub mod rolling_statistics;
+pub mod linear_regression;
+pub mod volatility;
\ No newline at end of file
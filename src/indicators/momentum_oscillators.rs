@@ -0,0 +1,75 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::crossover_helper::level_crossings;
+use crate::helpers::momentum_oscillators_helper;
+use crate::helpers::moving_average_helper::moving_average;
+use crate::helpers::price_source_helper::price_source;
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::crossover_struct::LevelCrossing;
+use crate::structs::momentum_oscillators_struct::{CciOptions, OscillatorResult, WilliamsROptions};
+use crate::structs::moving_average_struct::MovingAverageType;
+use crate::{create_error, deserialize_options, serialize_to_js_value};
+
+fn validate(period: usize, signal_period: Option<usize>, overbought: f64, oversold: f64) -> Result<(), JsValue> {
+    if period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    if signal_period == Some(0) {
+        return Err(create_error("Signal period must be greater than 0."));
+    }
+    if oversold >= overbought {
+        return Err(create_error("Oversold level must be lower than overbought level."));
+    }
+    Ok(())
+}
+
+fn build_result(
+    values: Vec<f64>,
+    signal_period: Option<usize>,
+    signal_type: MovingAverageType,
+    events: Vec<LevelCrossing>,
+) -> Result<JsValue, JsValue> {
+    let signal = signal_period.map(|period| moving_average(&values, period, signal_type));
+    serialize_to_js_value(&OscillatorResult { values, signal, events })
+}
+
+/// Williams %R.
+///
+/// - `data`: array of candles.
+/// - `options`: `{ period, source, signal_period, signal_type, overbought, oversold }`, all optional
+///   (defaults 14, `"close"`, no signal, `"sma"`, -20 and -80).
+///
+/// Returns `{ values, signal, events }`; values go from 0 (highest high) to -100 (lowest low).
+#[wasm_bindgen]
+pub fn williams_r(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: WilliamsROptions = deserialize_options(&options)?;
+    validate(options.period, options.signal_period, options.overbought, options.oversold)?;
+    let market_data = market_data_from_js(data)?;
+
+    let source = price_source(&market_data, options.source);
+    let values = momentum_oscillators_helper::williams_r(&market_data.highs, &market_data.lows, &source, options.period);
+    let events = level_crossings(&values, options.overbought, options.oversold);
+    build_result(values, options.signal_period, options.signal_type, events)
+}
+
+/// Commodity Channel Index (CCI).
+///
+/// - `data`: array of candles.
+/// - `options`: `{ period, source, constant, signal_period, signal_type, overbought, oversold }`, all
+///   optional (defaults 20, `"hlc3"`, 0.015, no signal, `"sma"`, 100 and -100).
+///
+/// Returns `{ values, signal, events }`.
+#[wasm_bindgen]
+pub fn commodity_channel_index(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: CciOptions = deserialize_options(&options)?;
+    validate(options.period, options.signal_period, options.overbought, options.oversold)?;
+    if options.constant <= 0.0 {
+        return Err(create_error("Constant must be greater than 0."));
+    }
+    let market_data = market_data_from_js(data)?;
+
+    let source = price_source(&market_data, options.source);
+    let values = momentum_oscillators_helper::commodity_channel_index(&source, options.period, options.constant);
+    let events = level_crossings(&values, options.overbought, options.oversold);
+    build_result(values, options.signal_period, options.signal_type, events)
+}
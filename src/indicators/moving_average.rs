@@ -0,0 +1,127 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::macd_helper::calculate_macd;
+use crate::helpers::moving_average_helper::{moving_average_with_options, MovingAverageState};
+use crate::structs::moving_average_struct::{MovingAverageOptions, MovingAverageType};
+use crate::{create_error, deserialize_js_value, deserialize_options, serialize_to_js_value};
+
+/// Reads an optional moving average name coming from JS (`"sma"`, `"ema"`, `"hma"`, ...).
+pub(crate) fn parse_moving_average_type(
+    kind: Option<String>,
+    default: MovingAverageType,
+) -> Result<MovingAverageType, JsValue> {
+    match kind {
+        Some(name) => MovingAverageType::from_name(&name).map_err(|e| create_error(&e)),
+        None => Ok(default),
+    }
+}
+
+/// Moving average of any supported kind.
+///
+/// - `data`: JS array (`number[]`) of values.
+/// - `period`: number of values in the average.
+/// - `kind`: `"sma"` (default), `"ema"`, `"wma"`, `"smma"`/`"rma"`, `"dema"`, `"tema"`, `"trima"`,
+///   `"hma"`, `"zlema"`, `"alma"`, `"lsma"`, `"t3"` or `"vwma"`.
+/// - `volumes`: JS array of volumes, required by `"vwma"`.
+/// - `options`: `{ alma_offset, alma_sigma, t3_volume_factor }`.
+///
+/// Returns one value per input, NaN during the warm-up. `"ema"` is seeded with the SMA of the first
+/// `period` values, unlike `exponential_moving_average` which starts from the first value.
+#[wasm_bindgen]
+pub fn moving_average(
+    data: &JsValue,
+    period: usize,
+    kind: Option<String>,
+    volumes: &JsValue,
+    options: &JsValue,
+) -> Result<Vec<f64>, JsValue> {
+    let kind = parse_moving_average_type(kind, MovingAverageType::Sma)?;
+    let options: MovingAverageOptions = deserialize_options(options)?;
+    let values: Vec<f64> = deserialize_js_value(data)?;
+
+    if period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    if values.is_empty() {
+        return Err(create_error("Prices vector must not be empty."));
+    }
+
+    let volumes: Option<Vec<f64>> = if volumes.is_undefined() || volumes.is_null() {
+        None
+    } else {
+        Some(deserialize_js_value(volumes)?)
+    };
+    if volumes.as_ref().is_some_and(|v| v.len() != values.len()) {
+        return Err(create_error("Volumes must have the same length as the data."));
+    }
+    if kind == MovingAverageType::Vwma && volumes.is_none() {
+        return Err(create_error("VWMA requires volumes."));
+    }
+
+    Ok(moving_average_with_options(&values, volumes.as_deref(), period, kind, &options))
+}
+
+/// Streaming moving average, fed one value at a time.
+#[wasm_bindgen]
+pub struct MovingAverageStream {
+    state: MovingAverageState,
+}
+
+#[wasm_bindgen]
+impl MovingAverageStream {
+    #[wasm_bindgen(constructor)]
+    pub fn new(period: usize, kind: Option<String>, options: &JsValue) -> Result<MovingAverageStream, JsValue> {
+        if period == 0 {
+            return Err(create_error("Period must be greater than 0."));
+        }
+        let kind = parse_moving_average_type(kind, MovingAverageType::Sma)?;
+        let options: MovingAverageOptions = deserialize_options(options)?;
+
+        Ok(MovingAverageStream {
+            state: MovingAverageState::with_options(kind, period, &options),
+        })
+    }
+
+    /// Adds a value (and its volume, used by VWMA) and returns the updated average.
+    pub fn next(&mut self, value: f64, volume: Option<f64>) -> f64 {
+        self.state.next_with_volume(value, volume.unwrap_or(1.0))
+    }
+}
+
+/// Moving Average Convergence Divergence (MACD).
+///
+/// - `data`: JS array (`number[]`) of prices.
+/// - `fast_period` / `slow_period` / `signal_period`: defaults 12, 26 and 9.
+/// - `kind`: moving average used for the fast and slow lines (default `"ema"`).
+/// - `signal_kind`: moving average used for the signal line (default `"ema"`).
+///
+/// Returns `{ macd, signal, histogram }`.
+#[wasm_bindgen]
+pub fn macd(
+    data: &JsValue,
+    fast_period: Option<usize>,
+    slow_period: Option<usize>,
+    signal_period: Option<usize>,
+    kind: Option<String>,
+    signal_kind: Option<String>,
+) -> Result<JsValue, JsValue> {
+    let fast_period = fast_period.unwrap_or(12);
+    let slow_period = slow_period.unwrap_or(26);
+    let signal_period = signal_period.unwrap_or(9);
+    let kind = parse_moving_average_type(kind, MovingAverageType::Ema)?;
+    let signal_kind = parse_moving_average_type(signal_kind, MovingAverageType::Ema)?;
+    let prices: Vec<f64> = deserialize_js_value(data)?;
+
+    if fast_period == 0 || slow_period == 0 || signal_period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    if fast_period >= slow_period {
+        return Err(create_error("Fast period must be lower than slow period."));
+    }
+    if prices.is_empty() {
+        return Err(create_error("Prices vector must not be empty."));
+    }
+
+    let result = calculate_macd(&prices, fast_period, slow_period, signal_period, kind, signal_kind);
+    serialize_to_js_value(&result)
+}
@@ -1,35 +1,32 @@
-use wasm_bindgen::JsValue;
-use wasm_bindgen::prelude::wasm_bindgen;
-use crate::deserialize_js_value;
-use crate::low_high_open_close_volume_date_to_array_helper::{low_high_open_close_volume_date_to_array, MarketDataResult};
-use crate::structs::pivot_points_struct::PivotLevels;
+use wasm_bindgen::prelude::*;
 
-#[wasm_bindgen]
-pub fn pivot_points(data: JsValue) -> Result<Vec<f64>, JsValue> {
-    // Traitement des données
-    let processed_data = low_high_open_close_volume_date_to_array(data)
-        .map_err(|_| JsValue::from_str("Failed to process input data"))?;
-
-    let market_data: MarketDataResult = deserialize_js_value(&processed_data)
-        .map_err(|_| JsValue::from_str("Failed to deserialize market data"))?;
-
-    let highs = market_data.highs;
-    let lows = market_data.lows;
-    let closes = market_data.closes;
+use crate::helpers::date_helper::parse_timestamps;
+use crate::helpers::pivot_points_helper::{period_keys, pivot_points_by_period};
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::pivot_points_struct::{PivotPointsOptions, PivotTimeframe};
+use crate::{create_error, deserialize_options, serialize_to_js_value};
 
-    let mut result = Vec::new();
-
-    // Utilisation de l'itération combinée
-    for (high, low, close) in highs.iter().zip(lows.iter()).zip(closes.iter()).map(|((high, low), close)| (high, low, close)) {
-        let pivot_levels = PivotLevels::new(*high, *low, *close);
+/// Pivot points anchored on the previous period.
+///
+/// - `data`: array of candles; dates must be parseable unless `timeframe` is `"bar"`.
+/// - `options`: `{ method, timeframe, session_offset_minutes }`, all optional. `method` is one of
+///   `"classic"` (default), `"fibonacci"`, `"camarilla"`, `"woodie"` or `"demark"`, and `timeframe`
+///   one of `"bar"`, `"day"` (default), `"week"` or `"month"`.
+///
+/// Returns one `{ date, levels, developing }` record per candle: `levels` are computed from the
+/// previous completed period (`null` during the first one) and `developing` from the current
+/// period so far.
+#[wasm_bindgen]
+pub fn pivot_points(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: PivotPointsOptions = deserialize_options(&options)?;
+    let market_data = market_data_from_js(data)?;
 
-        // Ajout des résultats dans le vecteur
-        result.push(pivot_levels.pivot_point);
-        result.push(pivot_levels.resistance1);
-        result.push(pivot_levels.resistance2);
-        result.push(pivot_levels.support1);
-        result.push(pivot_levels.support2);
-    }
+    let timestamps = if options.timeframe == PivotTimeframe::Bar {
+        vec![0; market_data.dates.len()]
+    } else {
+        parse_timestamps(&market_data.dates).map_err(|e| create_error(&e))?
+    };
+    let keys = period_keys(&timestamps, options.timeframe, options.session_offset_minutes);
 
-    Ok(result)
-}
\ No newline at end of file
+    serialize_to_js_value(&pivot_points_by_period(&market_data, &keys, options.method))
+}
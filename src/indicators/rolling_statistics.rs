@@ -0,0 +1,41 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::rolling_statistics_helper;
+use crate::structs::rolling_statistics_struct::{DeviationType, RollingStatistic, RollingStatisticOptions};
+use crate::{create_error, deserialize_js_value, deserialize_options};
+
+/// Rolling statistic over a window of values.
+///
+/// - `data`: JS array (`number[]`) of values.
+/// - `options`: `{ statistic, period, quantile, deviation }`, all optional (defaults `"std"`, 20, 0.5
+///   and `"population"`). `statistic` is one of `"sum"`, `"mean"`, `"variance"`, `"std"`, `"z_score"`,
+///   `"skewness"`, `"kurtosis"`, `"min"`, `"max"`, `"median"`, `"quantile"` or `"percent_rank"`.
+///
+/// Returns one value per input, NaN until the window holds `period` values. NaN inputs yield NaN and
+/// are left out of the window.
+#[wasm_bindgen]
+pub fn rolling_statistic(data: &JsValue, options: JsValue) -> Result<Vec<f64>, JsValue> {
+    let options: RollingStatisticOptions = deserialize_options(&options)?;
+    if options.period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    if !(0.0..=1.0).contains(&options.quantile) {
+        return Err(create_error("Quantile must be between 0 and 1."));
+    }
+    let uses_deviation = matches!(
+        options.statistic,
+        RollingStatistic::Variance | RollingStatistic::Std | RollingStatistic::ZScore
+    );
+    let needs_two = options.statistic == RollingStatistic::PercentRank
+        || uses_deviation && options.deviation == DeviationType::Sample;
+    if needs_two && options.period < 2 {
+        return Err(create_error("Period must be greater than 1."));
+    }
+
+    let values: Vec<f64> = deserialize_js_value(data)?;
+    if values.is_empty() {
+        return Err(create_error("Prices vector must not be empty."));
+    }
+
+    Ok(rolling_statistics_helper::rolling_statistic(&values, &options))
+}
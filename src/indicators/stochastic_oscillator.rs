@@ -1,28 +1,35 @@
 use wasm_bindgen::prelude::*;
-use crate::deserialize_js_value;
-use crate::highest_lowest_helper::calculate_high_low;
-use crate::low_high_open_close_volume_date_to_array_helper::{low_high_open_close_volume_date_to_array, MarketDataResult};
 
-#[wasm_bindgen]
-pub fn stochastic_oscillator(
-    data: JsValue,
-    period: usize
-)  -> Result<Vec<f64>, JsValue> {
-
-    let processed_data = low_high_open_close_volume_date_to_array(data)?;
-    let market_data: MarketDataResult = deserialize_js_value(&processed_data)?;
-
-    let highs = market_data.highs;
-    let lows = market_data.lows;
-    let closes = market_data.closes;
-
-    let mut result = Vec::with_capacity(closes.len());
+use crate::helpers::stochastic_oscillator_helper;
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::stochastic_oscillator_struct::StochasticOptions;
+use crate::{create_error, deserialize_options, serialize_to_js_value};
 
-    for i in period..closes.len() {
-        let (highest_high, lowest_low) = calculate_high_low(&highs, &lows, i - period, i - 1);
-        let stoch = 100.0 * (closes[i] - lowest_low) / (highest_high - lowest_low);
-        result.push(stoch);
+/// Stochastic oscillator (Fast, Slow or Full).
+///
+/// - `data`: array of candles.
+/// - `options`: `{ k_period, k_smoothing, d_period, ma_type, overbought, oversold }`, all optional
+///   (defaults 14, 1, 3, `"sma"`, 80 and 20). `k_smoothing: 1` is the Fast stochastic, 3 the Slow one.
+///
+/// Returns `{ k, d, events }`, aligned with the candles (NaN during the warm-up). The window of
+/// every %K includes the current candle, and a flat window yields 50.
+#[wasm_bindgen]
+pub fn stochastic_oscillator(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: StochasticOptions = deserialize_options(&options)?;
+    if options.k_period == 0 || options.k_smoothing == 0 || options.d_period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    if options.oversold >= options.overbought {
+        return Err(create_error("Oversold level must be lower than overbought level."));
     }
 
-    Ok(result)
+    let market_data = market_data_from_js(data)?;
+
+    let result = stochastic_oscillator_helper::stochastic_oscillator(
+        &market_data.highs,
+        &market_data.lows,
+        &market_data.closes,
+        &options,
+    );
+    serialize_to_js_value(&result)
 }
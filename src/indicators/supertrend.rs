@@ -0,0 +1,37 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::price_source_helper::price_source;
+use crate::helpers::supertrend_helper;
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::supertrend_struct::SuperTrendOptions;
+use crate::{create_error, deserialize_options, serialize_to_js_value};
+
+/// SuperTrend.
+///
+/// - `data`: array of candles.
+/// - `options`: `{ atr_period, multiplier, source }`, all optional (defaults 10, 3 and `"hl2"`).
+///
+/// Returns `{ supertrend, direction, flips, upper, lower }`; `upper` and `lower` split the line by
+/// trend so each segment can be drawn in its own colour.
+#[wasm_bindgen]
+pub fn supertrend(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: SuperTrendOptions = deserialize_options(&options)?;
+    if options.atr_period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    if options.multiplier <= 0.0 {
+        return Err(create_error("Multiplier must be greater than 0."));
+    }
+    let market_data = market_data_from_js(data)?;
+
+    let source = price_source(&market_data, options.source);
+    let result = supertrend_helper::supertrend(
+        &market_data.highs,
+        &market_data.lows,
+        &market_data.closes,
+        &source,
+        options.atr_period,
+        options.multiplier,
+    );
+    serialize_to_js_value(&result)
+}
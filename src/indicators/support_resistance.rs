@@ -0,0 +1,54 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::calculate_atr_helper::average_true_range;
+use crate::helpers::support_resistance_helper::{cluster_levels, support_resistance_zones as build_zones};
+use crate::helpers::swing_points_helper::important_levels;
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::support_resistance_struct::{SupportResistanceOptions, ZoneToleranceMode};
+use crate::structs::swing_points_struct::SwingPoint;
+use crate::{create_error, deserialize_options, serialize_to_js_value};
+
+/// Support/resistance zones clustered from the swing points.
+///
+/// - `data`: array of candles.
+/// - `options`: `{ left, right, strict, tolerance_mode, tolerance, atr_period, max_zones }`, all
+///   optional. Swing levels within `tolerance` of each other (0.5% by default, or price units with
+///   `"absolute"`, or multiples of the last ATR with `"atr"`) are merged into one zone.
+///
+/// Returns the `max_zones` zones nearest to the last close (default 5), strongest first, each as
+/// `{ low, high, touches, first_touch, last_touch, volume, role, flipped, strength, distance }`.
+#[wasm_bindgen]
+pub fn support_resistance_zones(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: SupportResistanceOptions = deserialize_options(&options)?;
+    if options.tolerance < 0.0 {
+        return Err(create_error("Tolerance must not be negative."));
+    }
+    if options.atr_period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    let market_data = market_data_from_js(data)?;
+
+    let levels = important_levels(
+        &market_data,
+        &market_data.highs,
+        &market_data.lows,
+        options.left,
+        options.right,
+        options.strict,
+    );
+    let points: Vec<&SwingPoint> = levels.supports.iter().chain(&levels.resistances).collect();
+
+    let tolerance = options.tolerance;
+    let clusters = match options.tolerance_mode {
+        ZoneToleranceMode::Percent => cluster_levels(&points, |price| price.abs() * tolerance / 100.0),
+        ZoneToleranceMode::Absolute => cluster_levels(&points, |_| tolerance),
+        ZoneToleranceMode::Atr => {
+            let atr =
+                average_true_range(&market_data.highs, &market_data.lows, &market_data.closes, options.atr_period);
+            let last_atr = atr.last().cloned().filter(|value| !value.is_nan()).unwrap_or(0.0);
+            cluster_levels(&points, |_| last_atr * tolerance)
+        }
+    };
+
+    serialize_to_js_value(&build_zones(&market_data, &clusters, options.max_zones))
+}
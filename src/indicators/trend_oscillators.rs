@@ -0,0 +1,49 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::crossover_helper::line_crossings;
+use crate::helpers::trend_oscillators_helper;
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::trend_oscillators_struct::{AroonResult, VortexResult};
+use crate::{create_error, serialize_to_js_value};
+
+/// Aroon Up/Down and the Aroon Oscillator.
+///
+/// - `data`: array of candles.
+/// - `period`: look-back window (default 25).
+///
+/// Returns `{ up, down, oscillator, events }`, where `events` are the crossings of Up over Down
+/// (`"bullish"` when Up crosses above). Values are NaN for the first `period` candles.
+#[wasm_bindgen]
+pub fn aroon(data: JsValue, period: Option<usize>) -> Result<JsValue, JsValue> {
+    let period = period.unwrap_or(25);
+    if period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    let market_data = market_data_from_js(data)?;
+
+    let (up, down) = trend_oscillators_helper::aroon(&market_data.highs, &market_data.lows, period);
+    let oscillator = up.iter().zip(&down).map(|(u, d)| u - d).collect();
+    let events = line_crossings(&up, &down);
+    serialize_to_js_value(&AroonResult { up, down, oscillator, events })
+}
+
+/// Vortex Indicator (VI+ and VI-).
+///
+/// - `data`: array of candles.
+/// - `period`: look-back window (default 14).
+///
+/// Returns `{ plus, minus, events }`, where `events` are the crossings of VI+ over VI-.
+/// Values are NaN for the first `period` candles.
+#[wasm_bindgen]
+pub fn vortex_indicator(data: JsValue, period: Option<usize>) -> Result<JsValue, JsValue> {
+    let period = period.unwrap_or(14);
+    if period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    let market_data = market_data_from_js(data)?;
+
+    let (plus, minus) =
+        trend_oscillators_helper::vortex(&market_data.highs, &market_data.lows, &market_data.closes, period);
+    let events = line_crossings(&plus, &minus);
+    serialize_to_js_value(&VortexResult { plus, minus, events })
+}
@@ -0,0 +1,33 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::trendline_helper;
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::trendline_struct::TrendlineOptions;
+use crate::{create_error, deserialize_options, serialize_to_js_value};
+
+/// Trendlines and channels through the swing points.
+///
+/// - `data`: array of candles.
+/// - `options`: `{ left, right, strict, min_touches, tolerance, max_anchor_gap }`, all optional.
+///   Swing points are detected as in `extract_important_levels`; a line must touch at least
+///   `min_touches` of them (default 3) within `tolerance` percent (default 0.5) without any close
+///   crossing it between its anchors, which are at most `max_anchor_gap` pivots apart (default 5).
+///
+/// Returns `{ lines, channels }`: each line with its `slope`, `intercept`, anchors, touches and the
+/// close that `broken` it, and each channel as the parallel line through the opposite pivots.
+#[wasm_bindgen]
+pub fn trendlines(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: TrendlineOptions = deserialize_options(&options)?;
+    if options.min_touches < 2 {
+        return Err(create_error("Minimum touches must be at least 2."));
+    }
+    if options.tolerance < 0.0 {
+        return Err(create_error("Tolerance must not be negative."));
+    }
+    if options.max_anchor_gap == 0 {
+        return Err(create_error("Maximum anchor gap must be greater than 0."));
+    }
+    let market_data = market_data_from_js(data)?;
+
+    serialize_to_js_value(&trendline_helper::trendlines(&market_data, &options))
+}
@@ -0,0 +1,37 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::date_helper::parse_timestamps;
+use crate::helpers::volatility_helper;
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::volatility_struct::HistoricalVolatilityOptions;
+use crate::{create_error, deserialize_options, serialize_to_js_value};
+
+/// Historical (realised) volatility.
+///
+/// - `data`: array of candles.
+/// - `options`: `{ estimator, period, periods_per_year, trading_days, rank_period }`, all optional
+///   (defaults `"close_to_close"`, 20, derived from the dates, 252 and 252). `estimator` is one of
+///   `"close_to_close"`, `"parkinson"`, `"garman_klass"`, `"rogers_satchell"` or `"yang_zhang"`.
+///
+/// Returns `{ values, percentile, rank, periods_per_year }`; the volatility is annualised and expressed
+/// as a fraction, every series is aligned with the candles (NaN during the warm-up).
+#[wasm_bindgen]
+pub fn historical_volatility(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: HistoricalVolatilityOptions = deserialize_options(&options)?;
+    if options.period < 2 || options.rank_period < 2 {
+        return Err(create_error("Period must be greater than 1."));
+    }
+    if options.periods_per_year.is_some_and(|periods| periods <= 0.0) || options.trading_days <= 0.0 {
+        return Err(create_error("Periods per year must be greater than 0."));
+    }
+    let market_data = market_data_from_js(data)?;
+
+    let periods_per_year = match options.periods_per_year {
+        Some(periods) => periods,
+        None => {
+            let timestamps = parse_timestamps(&market_data.dates).map_err(|e| create_error(&e))?;
+            volatility_helper::periods_per_year(&timestamps, options.trading_days)
+        }
+    };
+    serialize_to_js_value(&volatility_helper::historical_volatility(&market_data, &options, periods_per_year))
+}
@@ -0,0 +1,336 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::moving_average_helper::MovingAverageState;
+use crate::helpers::volume_flow_helper::{
+    self, AccumulationDistributionState, ChaikinMoneyFlowState, ChaikinOscillatorState, ObvState,
+    PriceVolumeTrendState,
+};
+use crate::indicators::moving_average::parse_moving_average_type;
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::moving_average_struct::MovingAverageType;
+use crate::structs::volume_flow_struct::{VolumeFlowPoint, VolumeFlowResult};
+use crate::{create_error, serialize_to_js_value};
+
+pub(crate) fn validate_signal_period(signal_period: Option<usize>) -> Result<(), JsValue> {
+    if signal_period == Some(0) {
+        return Err(create_error("Signal period must be greater than 0."));
+    }
+    Ok(())
+}
+
+/// Reads the moving average of a signal line, EMA unless `signal_type` says otherwise.
+pub(crate) fn parse_signal_type(signal_type: Option<String>) -> Result<MovingAverageType, JsValue> {
+    parse_moving_average_type(signal_type, MovingAverageType::Ema)
+}
+
+fn build_result(
+    values: Vec<f64>,
+    signal_period: Option<usize>,
+    signal_type: MovingAverageType,
+) -> Result<JsValue, JsValue> {
+    let signal = signal_period.map(|period| volume_flow_helper::signal_line(&values, period, signal_type));
+    serialize_to_js_value(&VolumeFlowResult { values, signal })
+}
+
+fn signal_state(signal_period: Option<usize>, signal_type: MovingAverageType) -> Option<MovingAverageState> {
+    signal_period.map(|period| MovingAverageState::new(signal_type, period))
+}
+
+fn next_point(value: f64, signal: &mut Option<MovingAverageState>) -> VolumeFlowPoint {
+    VolumeFlowPoint {
+        value,
+        signal: signal.as_mut().map_or(f64::NAN, |state| state.next(value)),
+    }
+}
+
+/// On-Balance Volume (OBV).
+///
+/// - `data`: array of candles.
+/// - `signal_period`: optional period of the signal line.
+/// - `signal_type`: moving average of the signal line (default `"ema"`).
+///
+/// Returns `{ values, signal }`.
+#[wasm_bindgen]
+pub fn on_balance_volume(
+    data: JsValue,
+    signal_period: Option<usize>,
+    signal_type: Option<String>,
+) -> Result<JsValue, JsValue> {
+    validate_signal_period(signal_period)?;
+    let signal_type = parse_signal_type(signal_type)?;
+    let market_data = market_data_from_js(data)?;
+
+    let values = volume_flow_helper::on_balance_volume(&market_data.closes, &market_data.volumes);
+    build_result(values, signal_period, signal_type)
+}
+
+/// Accumulation/Distribution line.
+///
+/// - `data`: array of candles.
+/// - `signal_period`: optional period of the signal line.
+/// - `signal_type`: moving average of the signal line (default `"ema"`).
+///
+/// Returns `{ values, signal }`.
+#[wasm_bindgen]
+pub fn accumulation_distribution(
+    data: JsValue,
+    signal_period: Option<usize>,
+    signal_type: Option<String>,
+) -> Result<JsValue, JsValue> {
+    validate_signal_period(signal_period)?;
+    let signal_type = parse_signal_type(signal_type)?;
+    let market_data = market_data_from_js(data)?;
+
+    let values = volume_flow_helper::accumulation_distribution(
+        &market_data.highs,
+        &market_data.lows,
+        &market_data.closes,
+        &market_data.volumes,
+    );
+    build_result(values, signal_period, signal_type)
+}
+
+/// Chaikin Money Flow (CMF).
+///
+/// - `data`: array of candles.
+/// - `period`: look-back window (default 20).
+/// - `signal_period`: optional period of the signal line.
+/// - `signal_type`: moving average of the signal line (default `"ema"`).
+///
+/// Returns `{ values, signal }`; values are NaN for the first `period - 1` candles.
+#[wasm_bindgen]
+pub fn chaikin_money_flow(
+    data: JsValue,
+    period: Option<usize>,
+    signal_period: Option<usize>,
+    signal_type: Option<String>,
+) -> Result<JsValue, JsValue> {
+    let period = period.unwrap_or(20);
+    if period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    validate_signal_period(signal_period)?;
+    let signal_type = parse_signal_type(signal_type)?;
+    let market_data = market_data_from_js(data)?;
+
+    let values = volume_flow_helper::chaikin_money_flow(
+        &market_data.highs,
+        &market_data.lows,
+        &market_data.closes,
+        &market_data.volumes,
+        period,
+    );
+    build_result(values, signal_period, signal_type)
+}
+
+/// Chaikin oscillator: EMA(fast) - EMA(slow) of the Accumulation/Distribution line.
+///
+/// The EMAs are seeded with an SMA, so the first `slow_period - 1` values are NaN.
+///
+/// - `data`: array of candles.
+/// - `fast_period`: fast EMA period (default 3).
+/// - `slow_period`: slow EMA period (default 10).
+/// - `signal_period`: optional period of the signal line.
+/// - `signal_type`: moving average of the signal line (default `"ema"`).
+///
+/// Returns `{ values, signal }`.
+#[wasm_bindgen]
+pub fn chaikin_oscillator(
+    data: JsValue,
+    fast_period: Option<usize>,
+    slow_period: Option<usize>,
+    signal_period: Option<usize>,
+    signal_type: Option<String>,
+) -> Result<JsValue, JsValue> {
+    let fast_period = fast_period.unwrap_or(3);
+    let slow_period = slow_period.unwrap_or(10);
+    if fast_period == 0 || slow_period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    if fast_period >= slow_period {
+        return Err(create_error("Fast period must be lower than slow period."));
+    }
+    validate_signal_period(signal_period)?;
+    let signal_type = parse_signal_type(signal_type)?;
+    let market_data = market_data_from_js(data)?;
+
+    let values = volume_flow_helper::chaikin_oscillator(
+        &market_data.highs,
+        &market_data.lows,
+        &market_data.closes,
+        &market_data.volumes,
+        fast_period,
+        slow_period,
+    );
+    build_result(values, signal_period, signal_type)
+}
+
+/// Price-Volume Trend (PVT).
+///
+/// - `data`: array of candles.
+/// - `signal_period`: optional period of the signal line.
+/// - `signal_type`: moving average of the signal line (default `"ema"`).
+///
+/// Returns `{ values, signal }`.
+#[wasm_bindgen]
+pub fn price_volume_trend(
+    data: JsValue,
+    signal_period: Option<usize>,
+    signal_type: Option<String>,
+) -> Result<JsValue, JsValue> {
+    validate_signal_period(signal_period)?;
+    let signal_type = parse_signal_type(signal_type)?;
+    let market_data = market_data_from_js(data)?;
+
+    let values = volume_flow_helper::price_volume_trend(&market_data.closes, &market_data.volumes);
+    build_result(values, signal_period, signal_type)
+}
+
+/// Streaming On-Balance Volume, fed one candle at a time.
+#[wasm_bindgen]
+pub struct OnBalanceVolumeStream {
+    state: ObvState,
+    signal: Option<MovingAverageState>,
+}
+
+#[wasm_bindgen]
+impl OnBalanceVolumeStream {
+    #[wasm_bindgen(constructor)]
+    pub fn new(signal_period: Option<usize>, signal_type: Option<String>) -> Result<OnBalanceVolumeStream, JsValue> {
+        validate_signal_period(signal_period)?;
+        let signal_type = parse_signal_type(signal_type)?;
+        Ok(OnBalanceVolumeStream {
+            state: ObvState::default(),
+            signal: signal_state(signal_period, signal_type),
+        })
+    }
+
+    pub fn next(&mut self, close: f64, volume: f64) -> VolumeFlowPoint {
+        let value = self.state.next(close, volume);
+        next_point(value, &mut self.signal)
+    }
+}
+
+/// Streaming Accumulation/Distribution line, fed one candle at a time.
+#[wasm_bindgen]
+pub struct AccumulationDistributionStream {
+    state: AccumulationDistributionState,
+    signal: Option<MovingAverageState>,
+}
+
+#[wasm_bindgen]
+impl AccumulationDistributionStream {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        signal_period: Option<usize>,
+        signal_type: Option<String>,
+    ) -> Result<AccumulationDistributionStream, JsValue> {
+        validate_signal_period(signal_period)?;
+        let signal_type = parse_signal_type(signal_type)?;
+        Ok(AccumulationDistributionStream {
+            state: AccumulationDistributionState::default(),
+            signal: signal_state(signal_period, signal_type),
+        })
+    }
+
+    pub fn next(&mut self, high: f64, low: f64, close: f64, volume: f64) -> VolumeFlowPoint {
+        let value = self.state.next(high, low, close, volume);
+        next_point(value, &mut self.signal)
+    }
+}
+
+/// Streaming Chaikin Money Flow, fed one candle at a time.
+#[wasm_bindgen]
+pub struct ChaikinMoneyFlowStream {
+    state: ChaikinMoneyFlowState,
+    signal: Option<MovingAverageState>,
+}
+
+#[wasm_bindgen]
+impl ChaikinMoneyFlowStream {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        period: Option<usize>,
+        signal_period: Option<usize>,
+        signal_type: Option<String>,
+    ) -> Result<ChaikinMoneyFlowStream, JsValue> {
+        let period = period.unwrap_or(20);
+        if period == 0 {
+            return Err(create_error("Period must be greater than 0."));
+        }
+        validate_signal_period(signal_period)?;
+        let signal_type = parse_signal_type(signal_type)?;
+        Ok(ChaikinMoneyFlowStream {
+            state: ChaikinMoneyFlowState::new(period),
+            signal: signal_state(signal_period, signal_type),
+        })
+    }
+
+    pub fn next(&mut self, high: f64, low: f64, close: f64, volume: f64) -> VolumeFlowPoint {
+        let value = self.state.next(high, low, close, volume);
+        next_point(value, &mut self.signal)
+    }
+}
+
+/// Streaming Chaikin oscillator, fed one candle at a time.
+#[wasm_bindgen]
+pub struct ChaikinOscillatorStream {
+    state: ChaikinOscillatorState,
+    signal: Option<MovingAverageState>,
+}
+
+#[wasm_bindgen]
+impl ChaikinOscillatorStream {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        fast_period: Option<usize>,
+        slow_period: Option<usize>,
+        signal_period: Option<usize>,
+        signal_type: Option<String>,
+    ) -> Result<ChaikinOscillatorStream, JsValue> {
+        let fast_period = fast_period.unwrap_or(3);
+        let slow_period = slow_period.unwrap_or(10);
+        if fast_period == 0 || slow_period == 0 {
+            return Err(create_error("Period must be greater than 0."));
+        }
+        if fast_period >= slow_period {
+            return Err(create_error("Fast period must be lower than slow period."));
+        }
+        validate_signal_period(signal_period)?;
+        let signal_type = parse_signal_type(signal_type)?;
+        Ok(ChaikinOscillatorStream {
+            state: ChaikinOscillatorState::new(fast_period, slow_period),
+            signal: signal_state(signal_period, signal_type),
+        })
+    }
+
+    pub fn next(&mut self, high: f64, low: f64, close: f64, volume: f64) -> VolumeFlowPoint {
+        let value = self.state.next(high, low, close, volume);
+        next_point(value, &mut self.signal)
+    }
+}
+
+/// Streaming Price-Volume Trend, fed one candle at a time.
+#[wasm_bindgen]
+pub struct PriceVolumeTrendStream {
+    state: PriceVolumeTrendState,
+    signal: Option<MovingAverageState>,
+}
+
+#[wasm_bindgen]
+impl PriceVolumeTrendStream {
+    #[wasm_bindgen(constructor)]
+    pub fn new(signal_period: Option<usize>, signal_type: Option<String>) -> Result<PriceVolumeTrendStream, JsValue> {
+        validate_signal_period(signal_period)?;
+        let signal_type = parse_signal_type(signal_type)?;
+        Ok(PriceVolumeTrendStream {
+            state: PriceVolumeTrendState::default(),
+            signal: signal_state(signal_period, signal_type),
+        })
+    }
+
+    pub fn next(&mut self, close: f64, volume: f64) -> VolumeFlowPoint {
+        let value = self.state.next(close, volume);
+        next_point(value, &mut self.signal)
+    }
+}
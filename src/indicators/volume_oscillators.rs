@@ -0,0 +1,227 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::volume_flow_helper::signal_line;
+use crate::helpers::volume_oscillators_helper;
+use crate::indicators::volume_flow::{parse_signal_type, validate_signal_period};
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::volume_flow_struct::VolumeFlowResult;
+use crate::structs::volume_oscillators_struct::{
+    EaseOfMovementResult, ForceIndexResult, KlingerOscillatorResult, PercentageVolumeOscillatorResult,
+};
+use crate::{create_error, serialize_to_js_value};
+
+/// Money Flow Index (MFI), a volume-weighted RSI over the typical price.
+///
+/// - `data`: array of candles.
+/// - `period`: look-back window (default 14).
+///
+/// Returns one value per candle; the first `period` values are NaN.
+#[wasm_bindgen]
+pub fn money_flow_index(data: JsValue, period: Option<usize>) -> Result<Vec<f64>, JsValue> {
+    let period = period.unwrap_or(14);
+    if period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+
+    let market_data = market_data_from_js(data)?;
+
+    Ok(volume_oscillators_helper::money_flow_index(
+        &market_data.highs,
+        &market_data.lows,
+        &market_data.closes,
+        &market_data.volumes,
+        period,
+    ))
+}
+
+/// Ease of Movement (EMV).
+///
+/// - `data`: array of candles.
+/// - `period`: SMA period of the smoothed line (default 14).
+/// - `volume_divisor`: scale applied to the volume in the box ratio (default 100 000 000).
+///
+/// Returns `{ raw, smoothed }`.
+#[wasm_bindgen]
+pub fn ease_of_movement(
+    data: JsValue,
+    period: Option<usize>,
+    volume_divisor: Option<f64>,
+) -> Result<JsValue, JsValue> {
+    let period = period.unwrap_or(14);
+    let volume_divisor = volume_divisor.unwrap_or(100_000_000.0);
+    if period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    if volume_divisor <= 0.0 {
+        return Err(create_error("Volume divisor must be greater than 0."));
+    }
+
+    let market_data = market_data_from_js(data)?;
+
+    let (raw, smoothed) = volume_oscillators_helper::ease_of_movement(
+        &market_data.highs,
+        &market_data.lows,
+        &market_data.volumes,
+        period,
+        volume_divisor,
+    );
+    serialize_to_js_value(&EaseOfMovementResult { raw, smoothed })
+}
+
+/// Force Index.
+///
+/// - `data`: array of candles.
+/// - `period`: EMA period of the smoothed line (default 13).
+///
+/// Returns `{ raw, smoothed }`. The EMA is seeded with an SMA, so `smoothed` starts on candle `period`.
+#[wasm_bindgen]
+pub fn force_index(data: JsValue, period: Option<usize>) -> Result<JsValue, JsValue> {
+    let period = period.unwrap_or(13);
+    if period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+
+    let market_data = market_data_from_js(data)?;
+
+    let (raw, smoothed) = volume_oscillators_helper::force_index(&market_data.closes, &market_data.volumes, period);
+    serialize_to_js_value(&ForceIndexResult { raw, smoothed })
+}
+
+/// Klinger Volume Oscillator (KVO).
+///
+/// - `data`: array of candles.
+/// - `fast_period`: fast EMA period (default 34).
+/// - `slow_period`: slow EMA period (default 55).
+/// - `signal_period`: period of the signal line (default 13).
+/// - `signal_type`: moving average of the signal line (default `"ema"`).
+///
+/// Returns `{ kvo, signal }`. The EMAs are seeded with an SMA, so `kvo` is NaN for the first `slow_period` candles.
+#[wasm_bindgen]
+pub fn klinger_volume_oscillator(
+    data: JsValue,
+    fast_period: Option<usize>,
+    slow_period: Option<usize>,
+    signal_period: Option<usize>,
+    signal_type: Option<String>,
+) -> Result<JsValue, JsValue> {
+    let fast_period = fast_period.unwrap_or(34);
+    let slow_period = slow_period.unwrap_or(55);
+    let signal_period = signal_period.unwrap_or(13);
+    if fast_period == 0 || slow_period == 0 || signal_period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    if fast_period >= slow_period {
+        return Err(create_error("Fast period must be lower than slow period."));
+    }
+    let signal_type = parse_signal_type(signal_type)?;
+
+    let market_data = market_data_from_js(data)?;
+
+    let kvo = volume_oscillators_helper::klinger_volume_oscillator(
+        &market_data.highs,
+        &market_data.lows,
+        &market_data.closes,
+        &market_data.volumes,
+        fast_period,
+        slow_period,
+    );
+    let signal = signal_line(&kvo, signal_period, signal_type);
+    serialize_to_js_value(&KlingerOscillatorResult { kvo, signal })
+}
+
+/// Negative Volume Index (NVI).
+///
+/// - `data`: array of candles.
+/// - `signal_period`: optional period of the signal line (255 is the usual choice).
+/// - `signal_type`: moving average of the signal line (default `"ema"`).
+/// - `start`: starting value of the index (default 1000).
+///
+/// Returns `{ values, signal }`.
+#[wasm_bindgen]
+pub fn negative_volume_index(
+    data: JsValue,
+    signal_period: Option<usize>,
+    signal_type: Option<String>,
+    start: Option<f64>,
+) -> Result<JsValue, JsValue> {
+    validate_signal_period(signal_period)?;
+    let signal_type = parse_signal_type(signal_type)?;
+    let market_data = market_data_from_js(data)?;
+
+    let values = volume_oscillators_helper::negative_volume_index(
+        &market_data.closes,
+        &market_data.volumes,
+        start.unwrap_or(1000.0),
+    );
+    let signal = signal_period.map(|period| signal_line(&values, period, signal_type));
+    serialize_to_js_value(&VolumeFlowResult { values, signal })
+}
+
+/// Positive Volume Index (PVI).
+///
+/// - `data`: array of candles.
+/// - `signal_period`: optional period of the signal line (255 is the usual choice).
+/// - `signal_type`: moving average of the signal line (default `"ema"`).
+/// - `start`: starting value of the index (default 1000).
+///
+/// Returns `{ values, signal }`.
+#[wasm_bindgen]
+pub fn positive_volume_index(
+    data: JsValue,
+    signal_period: Option<usize>,
+    signal_type: Option<String>,
+    start: Option<f64>,
+) -> Result<JsValue, JsValue> {
+    validate_signal_period(signal_period)?;
+    let signal_type = parse_signal_type(signal_type)?;
+    let market_data = market_data_from_js(data)?;
+
+    let values = volume_oscillators_helper::positive_volume_index(
+        &market_data.closes,
+        &market_data.volumes,
+        start.unwrap_or(1000.0),
+    );
+    let signal = signal_period.map(|period| signal_line(&values, period, signal_type));
+    serialize_to_js_value(&VolumeFlowResult { values, signal })
+}
+
+/// Percentage Volume Oscillator (PVO).
+///
+/// - `data`: array of candles.
+/// - `fast_period`: fast EMA period (default 12).
+/// - `slow_period`: slow EMA period (default 26).
+/// - `signal_period`: period of the signal line (default 9).
+/// - `signal_type`: moving average of the signal line (default `"ema"`).
+///
+/// Returns `{ pvo, signal, histogram }`. The EMAs are seeded with an SMA, so `pvo` is NaN for the first
+/// `slow_period - 1` candles.
+#[wasm_bindgen]
+pub fn percentage_volume_oscillator(
+    data: JsValue,
+    fast_period: Option<usize>,
+    slow_period: Option<usize>,
+    signal_period: Option<usize>,
+    signal_type: Option<String>,
+) -> Result<JsValue, JsValue> {
+    let fast_period = fast_period.unwrap_or(12);
+    let slow_period = slow_period.unwrap_or(26);
+    let signal_period = signal_period.unwrap_or(9);
+    if fast_period == 0 || slow_period == 0 || signal_period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    if fast_period >= slow_period {
+        return Err(create_error("Fast period must be lower than slow period."));
+    }
+    let signal_type = parse_signal_type(signal_type)?;
+
+    let market_data = market_data_from_js(data)?;
+
+    let (pvo, signal, histogram) = volume_oscillators_helper::percentage_volume_oscillator(
+        &market_data.volumes,
+        fast_period,
+        slow_period,
+        signal_period,
+        signal_type,
+    );
+    serialize_to_js_value(&PercentageVolumeOscillatorResult { pvo, signal, histogram })
+}
@@ -0,0 +1,107 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::date_helper::{parse_timestamps, session_ranges};
+use crate::helpers::volume_profile_helper::build_volume_profile;
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::volume_profile_struct::{RollingVolumeProfileResult, VolumeProfileOptions};
+use crate::{create_error, deserialize_options, serialize_to_js_value};
+
+fn parse_options(options: &JsValue) -> Result<VolumeProfileOptions, JsValue> {
+    let options: VolumeProfileOptions = deserialize_options(options)?;
+
+    if options.bin_count == 0 {
+        return Err(create_error("Bin count must be greater than 0."));
+    }
+    if options.bin_size.is_some_and(|size| size <= 0.0) {
+        return Err(create_error("Bin size must be greater than 0."));
+    }
+    if options.value_area <= 0.0 || options.value_area > 1.0 {
+        return Err(create_error("Value area must be between 0 and 1."));
+    }
+
+    Ok(options)
+}
+
+/// Fixed-range volume profile.
+///
+/// - `data`: array of candles.
+/// - `options`: `{ bin_count, bin_size, value_area, distribution }` (all optional,
+///   `distribution` is `"uniform"` or `"close_weighted"`).
+/// - `start` / `end`: inclusive candle range (defaults to the whole series).
+///
+/// Returns the histogram with its POC, value area and high/low volume nodes.
+#[wasm_bindgen]
+pub fn volume_profile(
+    data: JsValue,
+    options: JsValue,
+    start: Option<usize>,
+    end: Option<usize>,
+) -> Result<JsValue, JsValue> {
+    let options = parse_options(&options)?;
+    let market_data = market_data_from_js(data)?;
+
+    let last = market_data.closes.len() - 1;
+    let start = start.unwrap_or(0);
+    let end = end.unwrap_or(last);
+    if start > end || end > last {
+        return Err(create_error("Invalid range."));
+    }
+
+    let profile = build_volume_profile(&market_data, start, end, &options).map_err(|e| create_error(&e))?;
+    serialize_to_js_value(&profile)
+}
+
+/// One volume profile per session (UTC day, shifted by `options.session_offset_minutes`).
+///
+/// - `data`: array of candles with parseable dates.
+/// - `options`: same as `volume_profile`, plus `session_offset_minutes`.
+///
+/// Returns an array of profiles, in session order.
+#[wasm_bindgen]
+pub fn session_volume_profile(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options = parse_options(&options)?;
+    let market_data = market_data_from_js(data)?;
+    let timestamps = parse_timestamps(&market_data.dates).map_err(|e| create_error(&e))?;
+
+    let profiles = session_ranges(&timestamps, options.session_offset_minutes)
+        .into_iter()
+        .map(|(start, end)| build_volume_profile(&market_data, start, end, &options))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| create_error(&e))?;
+
+    serialize_to_js_value(&profiles)
+}
+
+/// Rolling volume profile over the last `window` candles, evaluated at every candle.
+///
+/// - `data`: array of candles.
+/// - `window`: number of candles in each profile (default 20).
+/// - `options`: same as `volume_profile`.
+///
+/// Returns `{ point_of_control, value_area_high, value_area_low }`, NaN for the first `window - 1` candles.
+#[wasm_bindgen]
+pub fn rolling_volume_profile(data: JsValue, window: Option<usize>, options: JsValue) -> Result<JsValue, JsValue> {
+    let window = window.unwrap_or(20);
+    if window == 0 {
+        return Err(create_error("Window must be greater than 0."));
+    }
+    let options = parse_options(&options)?;
+    let market_data = market_data_from_js(data)?;
+
+    let len = market_data.closes.len();
+    let mut result = RollingVolumeProfileResult {
+        point_of_control: vec![f64::NAN; len],
+        value_area_high: vec![f64::NAN; len],
+        value_area_low: vec![f64::NAN; len],
+    };
+
+    for end in window.saturating_sub(1)..len {
+        let profile =
+            build_volume_profile(&market_data, end + 1 - window, end, &options).map_err(|e| create_error(&e))?;
+        result.point_of_control[end] = profile.point_of_control;
+        result.value_area_high[end] = profile.value_area_high;
+        result.value_area_low[end] = profile.value_area_low;
+    }
+
+    serialize_to_js_value(&result)
+}
@@ -0,0 +1,29 @@
+use wasm_bindgen::prelude::*;
+
+use crate::helpers::zigzag_helper::zigzag_pivots;
+use crate::low_high_open_close_volume_date_to_array_helper::market_data_from_js;
+use crate::structs::zigzag_struct::ZigZagOptions;
+use crate::{create_error, deserialize_options, serialize_to_js_value};
+
+/// ZigZag on the highs and lows.
+///
+/// - `data`: array of candles.
+/// - `options`: `{ mode, threshold, atr_period }`, all optional. `mode` is `"percent"` (default, 5%),
+///   `"absolute"` or `"atr"` (threshold in multiples of a Wilder ATR of `atr_period`, default 14).
+///
+/// Returns the pivots `{ index, date, price, kind, label, confirmed, confirmed_at }`, labelled
+/// HH/LH/HL/LL against the previous pivot of the same kind. The last pivot ends the leg still
+/// being drawn and has `confirmed: false`.
+#[wasm_bindgen]
+pub fn zigzag(data: JsValue, options: JsValue) -> Result<JsValue, JsValue> {
+    let options: ZigZagOptions = deserialize_options(&options)?;
+    if options.threshold <= 0.0 {
+        return Err(create_error("Threshold must be greater than 0."));
+    }
+    if options.atr_period == 0 {
+        return Err(create_error("Period must be greater than 0."));
+    }
+    let market_data = market_data_from_js(data)?;
+
+    serialize_to_js_value(&zigzag_pivots(&market_data, options.mode, options.threshold, options.atr_period))
+}
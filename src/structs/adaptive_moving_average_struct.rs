@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Adaptive moving average and the smoothing factor it applied on every value.
+#[derive(Serialize, Deserialize)]
+pub struct AdaptiveMovingAverageResult {
+    pub values: Vec<f64>,
+    pub alpha: Vec<f64>,
+}
+
+/// Kaufman Adaptive Moving Average, its efficiency ratio and the smoothing factor derived from it.
+#[derive(Serialize, Deserialize)]
+pub struct KamaResult {
+    pub values: Vec<f64>,
+    pub efficiency_ratio: Vec<f64>,
+    pub alpha: Vec<f64>,
+}
@@ -1,6 +1,53 @@
 use serde::{Deserialize, Serialize};
 
-/// Structure de résultat qui sera convertie en objet JS (avec 3 propriétés : middle, upper et lower).
+use crate::structs::moving_average_struct::MovingAverageType;
+use crate::structs::rolling_statistics_struct::DeviationType;
+
+/// Options des Bollinger Bands, passées depuis JS sous forme d'objet.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct BollingerBandsOptions {
+    /// Fenêtre de la bande centrale et de l'écart-type.
+    pub period: usize,
+    /// Largeur des bandes en nombre d'écarts-types.
+    pub multiplier: f64,
+    /// Remplace `multiplier` pour la bande supérieure.
+    pub upper_multiplier: Option<f64>,
+    /// Remplace `multiplier` pour la bande inférieure.
+    pub lower_multiplier: Option<f64>,
+    /// Moyenne mobile de la bande centrale.
+    pub ma_type: MovingAverageType,
+    /// Population (comme chez Bollinger) ou échantillon.
+    pub deviation: DeviationType,
+    /// Nombre de largeurs dont la valeur courante doit être la plus faible pour signaler un squeeze.
+    pub squeeze_period: usize,
+}
+
+impl Default for BollingerBandsOptions {
+    fn default() -> Self {
+        BollingerBandsOptions {
+            period: 20,
+            multiplier: 2.0,
+            upper_multiplier: None,
+            lower_multiplier: None,
+            ma_type: MovingAverageType::Sma,
+            deviation: DeviationType::Population,
+            squeeze_period: 125,
+        }
+    }
+}
+
+impl BollingerBandsOptions {
+    pub fn upper_multiplier(&self) -> f64 {
+        self.upper_multiplier.unwrap_or(self.multiplier)
+    }
+
+    pub fn lower_multiplier(&self) -> f64 {
+        self.lower_multiplier.unwrap_or(self.multiplier)
+    }
+}
+
+/// Structure de résultat qui sera convertie en objet JS, chaque vecteur étant aligné sur les prix.
 #[derive(Serialize, Deserialize)]
 pub struct BollingerBandsResult {
     /// Bande centrale (moyenne mobile)
@@ -9,4 +56,18 @@ pub struct BollingerBandsResult {
     pub upper: Vec<f64>,
     /// Bande inférieure (moyenne mobile - k × écart-type)
     pub lower: Vec<f64>,
-}
\ No newline at end of file
+    /// Position du prix dans les bandes : 0 sur la bande inférieure, 1 sur la bande supérieure
+    pub percent_b: Vec<f64>,
+    /// Largeur des bandes rapportée à la bande centrale
+    pub bandwidth: Vec<f64>,
+    /// `true` quand la largeur est la plus faible des `squeeze_period` dernières valeurs
+    pub squeeze: Vec<bool>,
+}
+
+/// Résultat de l'ancienne signature `(data, period, multiplier)` : les trois bandes, sans le warm-up.
+#[derive(Serialize, Deserialize)]
+pub struct BollingerBandLines {
+    pub middle: Vec<f64>,
+    pub upper: Vec<f64>,
+    pub lower: Vec<f64>,
+}
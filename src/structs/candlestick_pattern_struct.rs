@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::moving_average_struct::MovingAverageType;
+
+/// Options of the candlestick pattern recognition, passed from JS as a plain object.
+///
+/// Ratios are relative to the range (high - low) of the candle unless stated otherwise.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct CandlestickOptions {
+    /// Largest body of a doji.
+    pub doji_body_ratio: f64,
+    /// Smallest long shadow of a hammer or shooting star, in multiples of the body.
+    pub long_shadow_ratio: f64,
+    /// Largest "missing" shadow of a hammer, shooting star or marubozu.
+    pub short_shadow_ratio: f64,
+    /// Largest difference between the highs (lows) of a tweezer top (bottom).
+    pub tweezer_tolerance: f64,
+    /// Period of the moving average giving the trend before a pattern; no trend context when `None`.
+    pub trend_period: Option<usize>,
+    pub trend_type: MovingAverageType,
+}
+
+impl Default for CandlestickOptions {
+    fn default() -> Self {
+        CandlestickOptions {
+            doji_body_ratio: 0.1,
+            long_shadow_ratio: 2.0,
+            short_shadow_ratio: 0.1,
+            tweezer_tolerance: 0.05,
+            trend_period: None,
+            trend_type: MovingAverageType::Sma,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CandlestickPattern {
+    Doji,
+    DragonflyDoji,
+    GravestoneDoji,
+    LongLeggedDoji,
+    Hammer,
+    HangingMan,
+    InvertedHammer,
+    ShootingStar,
+    Marubozu,
+    Engulfing,
+    Harami,
+    PiercingLine,
+    DarkCloudCover,
+    TweezerTop,
+    TweezerBottom,
+    InsideBar,
+    OutsideBar,
+    MorningStar,
+    EveningStar,
+    ThreeWhiteSoldiers,
+    ThreeBlackCrows,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PatternDirection {
+    Bullish,
+    Bearish,
+    Neutral,
+}
+
+/// A pattern completed on the candle `index`, spanning `bars` candles.
+///
+/// `confidence` is between 0 and 1: the shape quality, lowered when the trend before the pattern
+/// is unknown (x0.75) or runs against a reversal (x0.5).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CandlestickHit {
+    pub index: usize,
+    pub pattern: CandlestickPattern,
+    pub direction: PatternDirection,
+    pub bars: usize,
+    pub confidence: f64,
+}
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::candlestick_pattern_struct::PatternDirection;
+use crate::structs::swing_points_struct::SwingPoint;
+
+/// Options of the chart pattern detection, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct ChartPatternOptions {
+    /// Swing detection, as in `extract_important_levels`.
+    pub left: usize,
+    pub right: usize,
+    pub strict: bool,
+    /// Largest difference, in percent, between tops (bottoms) considered equal, and largest change
+    /// of a flat boundary over the pattern.
+    pub tolerance: f64,
+    /// Largest difference, in percent, between the shoulders of a head and shoulders.
+    pub shoulder_tolerance: f64,
+    /// Smallest height of a flag or pennant pole, in multiples of the consolidation height.
+    pub pole_ratio: f64,
+}
+
+impl Default for ChartPatternOptions {
+    fn default() -> Self {
+        ChartPatternOptions {
+            left: 3,
+            right: 3,
+            strict: false,
+            tolerance: 1.5,
+            shoulder_tolerance: 5.0,
+            pole_ratio: 2.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChartPattern {
+    DoubleTop,
+    DoubleBottom,
+    TripleTop,
+    TripleBottom,
+    HeadAndShoulders,
+    InverseHeadAndShoulders,
+    AscendingTriangle,
+    DescendingTriangle,
+    SymmetricalTriangle,
+    RisingWedge,
+    FallingWedge,
+    Flag,
+    Pennant,
+}
+
+/// A boundary `price = intercept + slope * index`, drawn from `start` to `end`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PatternLine {
+    pub slope: f64,
+    pub intercept: f64,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl PatternLine {
+    /// Line through two points.
+    pub fn through(start: usize, start_price: f64, end: usize, end_price: f64) -> Self {
+        let slope = if end == start { 0.0 } else { (end_price - start_price) / (end - start) as f64 };
+        PatternLine { slope, intercept: start_price - slope * start as f64, start, end }
+    }
+
+    /// Horizontal line at `price`.
+    pub fn horizontal(price: f64, start: usize, end: usize) -> Self {
+        PatternLine { slope: 0.0, intercept: price, start, end }
+    }
+
+    pub fn at(&self, index: usize) -> f64 {
+        self.intercept + self.slope * index as f64
+    }
+}
+
+/// A chart pattern found on the swing points.
+///
+/// `upper` and `lower` are the boundaries (the neckline is the lower one of a top, the upper one of
+/// a bottom). `breakout` is the first close outside them after the last pivot; it is `confirmed`
+/// when it goes the pattern's way. `target` is the measured move from the breakout, or from the
+/// boundary on the last candle while the pattern is still forming.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChartPatternHit {
+    pub pattern: ChartPattern,
+    pub direction: PatternDirection,
+    pub pivots: Vec<SwingPoint>,
+    pub upper: PatternLine,
+    pub lower: PatternLine,
+    pub breakout: Option<usize>,
+    pub confirmed: bool,
+    pub target: Option<f64>,
+}
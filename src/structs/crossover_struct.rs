@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+/// Direction of an oscillator crossing one of its overbought/oversold levels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LevelCrossingKind {
+    /// Crossed above the overbought level.
+    EnterOverbought,
+    /// Crossed back below the overbought level.
+    ExitOverbought,
+    /// Crossed below the oversold level.
+    EnterOversold,
+    /// Crossed back above the oversold level.
+    ExitOversold,
+}
+
+/// An overbought/oversold crossing, at the index of the bar that completed it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LevelCrossing {
+    pub index: usize,
+    pub kind: LevelCrossingKind,
+    pub value: f64,
+}
+
+/// Direction of a crossing between two lines, from the point of view of the first one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineCrossingKind {
+    /// The first line crossed above the second.
+    Bullish,
+    /// The first line crossed below the second.
+    Bearish,
+}
+
+/// A crossing between two lines, at the index of the bar that completed it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LineCrossing {
+    pub index: usize,
+    pub kind: LineCrossingKind,
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+/// Options of the divergence detection, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct DivergenceOptions {
+    /// Swing detection on both series, as in `extract_important_levels`.
+    pub left: usize,
+    pub right: usize,
+    /// Largest distance, in bars, between a price pivot and its oscillator pivot.
+    pub max_offset: usize,
+    /// Bounds of the distance, in bars, between the two price pivots.
+    pub min_span: usize,
+    pub max_span: usize,
+    /// Also report hidden divergences.
+    pub hidden: bool,
+}
+
+impl Default for DivergenceOptions {
+    fn default() -> Self {
+        DivergenceOptions {
+            left: 3,
+            right: 3,
+            max_offset: 2,
+            min_span: 5,
+            max_span: 60,
+            hidden: true,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DivergenceKind {
+    /// Lower low in price, higher low in the oscillator.
+    RegularBullish,
+    /// Higher high in price, lower high in the oscillator.
+    RegularBearish,
+    /// Higher low in price, lower low in the oscillator.
+    HiddenBullish,
+    /// Lower high in price, higher high in the oscillator.
+    HiddenBearish,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DivergencePivot {
+    pub index: usize,
+    pub value: f64,
+}
+
+/// A divergence between two consecutive price pivots and the matching oscillator pivots.
+///
+/// `span` is the number of bars between the price pivots. `strength` adds the moves of both series
+/// between their pivots, each relative to the range of the series over the span, so it lies
+/// between 0 and 2.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Divergence {
+    pub kind: DivergenceKind,
+    pub price: [DivergencePivot; 2],
+    pub oscillator: [DivergencePivot; 2],
+    pub span: usize,
+    pub strength: f64,
+}
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+/// Options of the Fibonacci levels, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct FibonacciOptions {
+    /// Index of the first swing point; with `end`, overrides the automatic swing.
+    pub start: Option<usize>,
+    /// Index of the second swing point.
+    pub end: Option<usize>,
+    /// Bars on each side of a swing point, as in `extract_important_levels`.
+    pub window: usize,
+    pub retracements: Vec<f64>,
+    pub extensions: Vec<f64>,
+    /// Distance, in percent of the level's price, within which a bar touches the level.
+    pub tolerance: f64,
+}
+
+impl Default for FibonacciOptions {
+    fn default() -> Self {
+        FibonacciOptions {
+            start: None,
+            end: None,
+            window: 5,
+            retracements: vec![0.236, 0.382, 0.5, 0.618, 0.786],
+            extensions: vec![1.272, 1.618, 2.618],
+            tolerance: 0.1,
+        }
+    }
+}
+
+/// End of the swing the levels are drawn on.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SwingAnchor {
+    pub index: usize,
+    pub price: f64,
+    pub date: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FibonacciLevel {
+    pub ratio: f64,
+    pub price: f64,
+}
+
+/// How price reacted at a level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LevelReactionKind {
+    /// Came down to the level and closed back above it.
+    Support,
+    /// Came up to the level and closed back below it.
+    Resistance,
+}
+
+/// A bar that touched a level and was rejected by it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LevelReaction {
+    pub index: usize,
+    pub ratio: f64,
+    pub price: f64,
+    pub kind: LevelReactionKind,
+}
+
+/// Fibonacci retracements, extensions and time zones of a swing.
+///
+/// `direction` is 1 for a swing up (low to high) and -1 for a swing down.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FibonacciResult {
+    pub start: SwingAnchor,
+    pub end: SwingAnchor,
+    pub direction: i8,
+    pub retracements: Vec<FibonacciLevel>,
+    pub extensions: Vec<FibonacciLevel>,
+    pub time_zones: Vec<usize>,
+    pub reactions: Vec<LevelReaction>,
+}
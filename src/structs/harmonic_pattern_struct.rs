@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::candlestick_pattern_struct::PatternDirection;
+use crate::structs::zigzag_struct::{ZigZagMode, ZigZagPivot};
+
+/// Options of the harmonic pattern detection, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct HarmonicOptions {
+    /// ZigZag giving the X, A, B, C and D points, as in `zigzag`.
+    pub mode: ZigZagMode,
+    pub threshold: f64,
+    pub atr_period: usize,
+    /// Largest distance, in percent, of a ratio outside its range.
+    pub tolerance: f64,
+}
+
+impl Default for HarmonicOptions {
+    fn default() -> Self {
+        HarmonicOptions {
+            mode: ZigZagMode::Percent,
+            threshold: 3.0,
+            atr_period: 14,
+            tolerance: 5.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HarmonicPattern {
+    Gartley,
+    Bat,
+    Butterfly,
+    Crab,
+    Cypher,
+}
+
+/// A Fibonacci ratio of the pattern, e.g. `"AB/XA"`, against its range.
+///
+/// `error` is the distance of `value` outside `[min, max]` relative to the nearest bound, 0 inside.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HarmonicRatio {
+    pub leg: String,
+    pub value: f64,
+    pub min: f64,
+    pub max: f64,
+    pub error: f64,
+}
+
+/// An XABCD pattern matched on five consecutive ZigZag pivots.
+///
+/// A bullish pattern ends on a low D and a bearish one on a high D. `error` is the mean of the
+/// ratio errors. The Potential Reversal Zone spans `prz_low` to `prz_high`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HarmonicHit {
+    pub pattern: HarmonicPattern,
+    pub direction: PatternDirection,
+    pub points: Vec<ZigZagPivot>,
+    pub ratios: Vec<HarmonicRatio>,
+    pub error: f64,
+    pub prz_low: f64,
+    pub prz_high: f64,
+}
@@ -1,5 +1,11 @@
 use serde::{Serialize, Deserialize};
 
+use crate::structs::crossover_struct::LineCrossing;
+
+/// Ichimoku lines, `displacement` bars longer than the input so the projected cloud is kept.
+///
+/// Tenkan, Kijun and Chikou are NaN over the projected bars; `future_dates` holds the dates of
+/// those bars, spaced by the typical bar interval (empty when the input dates can't be parsed).
 #[derive(Serialize, Deserialize)]
 pub struct IchimokuResult {
     pub tenkan_sen: Vec<f64>,
@@ -7,4 +13,51 @@ pub struct IchimokuResult {
     pub senkou_span_a: Vec<f64>,
     pub senkou_span_b: Vec<f64>,
     pub chikou_span: Vec<f64>,
-}
\ No newline at end of file
+    pub future_dates: Vec<String>,
+    pub signals: IchimokuSignals,
+}
+
+/// Where a price sits relative to the cloud plotted on the same bar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CloudPosition {
+    Above,
+    Inside,
+    Below,
+}
+
+/// Strength of a Tenkan/Kijun cross, from the position of the cross relative to the cloud.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignalStrength {
+    /// On the side of the cloud that agrees with the cross.
+    Strong,
+    /// Inside the cloud (or before the cloud is available).
+    Neutral,
+    /// On the side of the cloud that opposes the cross.
+    Weak,
+}
+
+/// A Tenkan-sen / Kijun-sen cross (`"bullish"` when Tenkan crosses above Kijun).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TkCross {
+    #[serde(flatten)]
+    pub crossing: LineCrossing,
+    pub strength: SignalStrength,
+}
+
+/// Signals derived from the lines, for the input bars.
+///
+/// - `cloud_position`: close versus the cloud, `null` while the cloud is not available.
+/// - `chikou_confirmation`: 1 when the close is above the close `displacement` bars earlier,
+///   -1 when below, 0 otherwise.
+/// - `kumo_breakouts`: closes leaving the cloud upward (`"bullish"`) or downward (`"bearish"`).
+/// - `kumo_twists`: Senkou Span A crossing Span B; indices may fall in the projected bars.
+#[derive(Serialize, Deserialize)]
+pub struct IchimokuSignals {
+    pub cloud_position: Vec<Option<CloudPosition>>,
+    pub chikou_confirmation: Vec<i8>,
+    pub tk_crosses: Vec<TkCross>,
+    pub kumo_breakouts: Vec<LineCrossing>,
+    pub kumo_twists: Vec<LineCrossing>,
+}
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::price_source_struct::PriceSource;
+
+/// Least squares line fitted through a window of values, x = 0 on the oldest one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RegressionFit {
+    pub count: usize,
+    pub slope: f64,
+    /// Value of the line on the oldest value of the window.
+    pub intercept: f64,
+    /// Share of the variance explained by the line, 0 on a flat window.
+    pub r_squared: f64,
+    /// Standard error of the estimate, `sqrt(sum of squared residuals / (n - 2))`.
+    pub standard_error: f64,
+}
+
+impl RegressionFit {
+    /// Value of the line `x` values after the oldest one.
+    pub fn at(&self, x: f64) -> f64 {
+        self.intercept + self.slope * x
+    }
+
+    /// Value of the line on the newest value of the window.
+    pub fn end_value(&self) -> f64 {
+        self.at(self.count as f64 - 1.0)
+    }
+}
+
+/// Options of the rolling linear regression, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct LinearRegressionOptions {
+    pub period: usize,
+    pub source: PriceSource,
+}
+
+impl Default for LinearRegressionOptions {
+    fn default() -> Self {
+        LinearRegressionOptions { period: 14, source: PriceSource::Close }
+    }
+}
+
+/// Rolling linear regression, aligned with the candles (NaN during the warm-up).
+#[derive(Serialize, Deserialize)]
+pub struct LinearRegressionResult {
+    pub slope: Vec<f64>,
+    /// Value of the line on the first candle of each window.
+    pub intercept: Vec<f64>,
+    /// Value of the line on the current candle (the least squares moving average).
+    pub value: Vec<f64>,
+    /// Time series forecast: the line extended one candle ahead.
+    pub forecast: Vec<f64>,
+    pub r_squared: Vec<f64>,
+    pub standard_error: Vec<f64>,
+}
+
+/// Options of the linear regression and Raff channels, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct RegressionChannelOptions {
+    /// Number of candles, ending on the last one, the channel is fitted through.
+    pub period: usize,
+    pub source: PriceSource,
+    /// Distance of the regression channel lines, in standard errors.
+    pub deviations: f64,
+}
+
+impl Default for RegressionChannelOptions {
+    fn default() -> Self {
+        RegressionChannelOptions { period: 100, source: PriceSource::Close, deviations: 2.0 }
+    }
+}
+
+/// Linear regression channel and Raff channel over the last `period` candles.
+///
+/// `slope` and `intercept` are expressed on the candle index (`intercept + slope * index`), and
+/// the lines are aligned with the candles, NaN before `start`.
+#[derive(Serialize, Deserialize)]
+pub struct RegressionChannel {
+    pub start: usize,
+    pub end: usize,
+    pub slope: f64,
+    pub intercept: f64,
+    pub r_squared: f64,
+    pub standard_error: f64,
+    pub middle: Vec<f64>,
+    /// Middle line ± `deviations` standard errors.
+    pub upper: Vec<f64>,
+    pub lower: Vec<f64>,
+    /// Middle line ± the distance of the high or low furthest from it.
+    pub raff_upper: Vec<f64>,
+    pub raff_lower: Vec<f64>,
+}
+
+/// Options of the standard error bands, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct StandardErrorBandsOptions {
+    pub period: usize,
+    pub source: PriceSource,
+    /// Distance of the bands, in standard errors.
+    pub deviations: f64,
+    /// Period of the SMA smoothing the middle line and the bands, 1 to disable it.
+    pub smoothing: usize,
+}
+
+impl Default for StandardErrorBandsOptions {
+    fn default() -> Self {
+        StandardErrorBandsOptions { period: 21, source: PriceSource::Close, deviations: 2.0, smoothing: 3 }
+    }
+}
+
+/// Standard error bands, aligned with the candles (NaN during the warm-up).
+#[derive(Serialize, Deserialize)]
+pub struct StandardErrorBandsResult {
+    pub middle: Vec<f64>,
+    pub upper: Vec<f64>,
+    pub lower: Vec<f64>,
+}
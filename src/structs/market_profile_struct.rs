@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+/// Options of the TPO (Market Profile) construction, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct MarketProfileOptions {
+    /// Price increment of a TPO row. Required.
+    pub tick_size: f64,
+    /// Length of a letter period, in minutes.
+    pub period_minutes: i64,
+    /// Number of letter periods forming the initial balance.
+    pub initial_balance_periods: usize,
+    /// Share of the TPOs contained in the value area (0.7 = 70%).
+    pub value_area: f64,
+    /// Shift of the session boundary from midnight UTC, in minutes.
+    pub session_offset_minutes: i64,
+    /// Letters assigned to the periods, reused from the start when a session has more periods.
+    pub letters: String,
+}
+
+impl Default for MarketProfileOptions {
+    fn default() -> Self {
+        MarketProfileOptions {
+            tick_size: 0.0,
+            period_minutes: 30,
+            initial_balance_periods: 2,
+            value_area: 0.7,
+            session_offset_minutes: 0,
+            letters: "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz".to_string(),
+        }
+    }
+}
+
+/// One letter period of a session.
+#[derive(Serialize, Deserialize)]
+pub struct TpoPeriod {
+    pub letter: char,
+    pub start_date: String,
+    pub high: f64,
+    pub low: f64,
+}
+
+/// One price row of a TPO profile.
+#[derive(Serialize, Deserialize)]
+pub struct TpoLevel {
+    pub price: f64,
+    /// Letters of the periods that traded at this price, in period order.
+    pub letters: String,
+    pub tpo_count: usize,
+    pub single_print: bool,
+    pub in_value_area: bool,
+    pub in_initial_balance: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PriceRange {
+    pub low: f64,
+    pub high: f64,
+}
+
+/// TPO profile of one session. `levels` go from the lowest price to the highest.
+#[derive(Serialize, Deserialize)]
+pub struct TpoProfile {
+    pub start_index: usize,
+    pub end_index: usize,
+    pub start_date: String,
+    pub open: f64,
+    pub close: f64,
+    pub high: f64,
+    pub low: f64,
+    pub point_of_control: f64,
+    pub value_area_high: f64,
+    pub value_area_low: f64,
+    pub initial_balance_high: f64,
+    pub initial_balance_low: f64,
+    /// More than one TPO at the session high: the auction did not complete there.
+    pub poor_high: bool,
+    /// More than one TPO at the session low.
+    pub poor_low: bool,
+    /// Runs of single-TPO rows inside the profile (tails at the extremes are excluded).
+    pub single_prints: Vec<PriceRange>,
+    pub periods: Vec<TpoPeriod>,
+    pub levels: Vec<TpoLevel>,
+}
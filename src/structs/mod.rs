@@ -3,7 +3,30 @@ pub mod kagi_struct;
 pub mod bollinger_bands_struct;
 pub mod entry_exit_signals_struct;
 pub mod pivot_points_struct;
-pub mod extract_important_levels_struct;
+pub mod volume_flow_struct;
+pub mod volume_oscillators_struct;
+pub mod volume_profile_struct;
+pub mod market_profile_struct;
+pub mod moving_average_struct;
+pub mod adaptive_moving_average_struct;
+pub mod crossover_struct;
+pub mod stochastic_oscillator_struct;
+pub mod price_source_struct;
+pub mod momentum_oscillators_struct;
+pub mod trend_oscillators_struct;
+pub mod supertrend_struct;
+pub mod fibonacci_struct;
+pub mod swing_points_struct;
+pub mod zigzag_struct;
+pub mod support_resistance_struct;
+pub mod trendline_struct;
+pub mod candlestick_pattern_struct;
+pub mod chart_pattern_struct;
+pub mod harmonic_pattern_struct;
+pub mod divergence_struct;
+pub mod rolling_statistics_struct;
+pub mod linear_regression_struct;
+pub mod volatility_struct;
 
 use serde::{Deserialize, Serialize};
 
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::crossover_struct::LevelCrossing;
+use crate::structs::moving_average_struct::MovingAverageType;
+use crate::structs::price_source_struct::PriceSource;
+
+/// Options of Williams %R, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct WilliamsROptions {
+    pub period: usize,
+    /// Price compared with the high–low range (the close by default).
+    pub source: PriceSource,
+    /// Period of the optional signal line.
+    pub signal_period: Option<usize>,
+    pub signal_type: MovingAverageType,
+    pub overbought: f64,
+    pub oversold: f64,
+}
+
+impl Default for WilliamsROptions {
+    fn default() -> Self {
+        WilliamsROptions {
+            period: 14,
+            source: PriceSource::Close,
+            signal_period: None,
+            signal_type: MovingAverageType::Sma,
+            overbought: -20.0,
+            oversold: -80.0,
+        }
+    }
+}
+
+/// Options of the Commodity Channel Index, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct CciOptions {
+    pub period: usize,
+    /// Price the CCI is computed on (the typical price by default).
+    pub source: PriceSource,
+    /// Lambert's constant, scaling the mean absolute deviation.
+    pub constant: f64,
+    /// Period of the optional signal line.
+    pub signal_period: Option<usize>,
+    pub signal_type: MovingAverageType,
+    pub overbought: f64,
+    pub oversold: f64,
+}
+
+impl Default for CciOptions {
+    fn default() -> Self {
+        CciOptions {
+            period: 20,
+            source: PriceSource::Hlc3,
+            constant: 0.015,
+            signal_period: None,
+            signal_type: MovingAverageType::Sma,
+            overbought: 100.0,
+            oversold: -100.0,
+        }
+    }
+}
+
+/// Oscillator values, their optional signal line and the threshold crossings of the values.
+#[derive(Serialize, Deserialize)]
+pub struct OscillatorResult {
+    pub values: Vec<f64>,
+    pub signal: Option<Vec<f64>>,
+    pub events: Vec<LevelCrossing>,
+}
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+
+/// Moving average kinds available through `moving_average` and as a smoothing choice in other indicators.
+///
+/// Deserialized through `from_name`, so option objects accept any casing ("EMA", "Ema") and "rma".
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase", try_from = "String")]
+pub enum MovingAverageType {
+    /// Simple moving average.
+    #[default]
+    Sma,
+    /// Exponential moving average, seeded with the SMA of the first `period` values.
+    Ema,
+    /// Linearly weighted moving average.
+    Wma,
+    /// Smoothed (Wilder / RMA) moving average.
+    Smma,
+    /// Double exponential moving average.
+    Dema,
+    /// Triple exponential moving average.
+    Tema,
+    /// Triangular moving average (SMA of an SMA).
+    Trima,
+    /// Hull moving average.
+    Hma,
+    /// Zero-lag exponential moving average.
+    Zlema,
+    /// Arnaud Legoux moving average.
+    Alma,
+    /// Least squares moving average (end point of the linear regression).
+    Lsma,
+    /// Tillson T3.
+    T3,
+    /// Volume-weighted moving average.
+    Vwma,
+}
+
+impl MovingAverageType {
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name.to_ascii_lowercase().as_str() {
+            "sma" => Ok(MovingAverageType::Sma),
+            "ema" => Ok(MovingAverageType::Ema),
+            "wma" => Ok(MovingAverageType::Wma),
+            "smma" | "rma" => Ok(MovingAverageType::Smma),
+            "dema" => Ok(MovingAverageType::Dema),
+            "tema" => Ok(MovingAverageType::Tema),
+            "trima" => Ok(MovingAverageType::Trima),
+            "hma" => Ok(MovingAverageType::Hma),
+            "zlema" => Ok(MovingAverageType::Zlema),
+            "alma" => Ok(MovingAverageType::Alma),
+            "lsma" => Ok(MovingAverageType::Lsma),
+            "t3" => Ok(MovingAverageType::T3),
+            "vwma" => Ok(MovingAverageType::Vwma),
+            _ => Err(format!("Unknown moving average type: {}.", name)),
+        }
+    }
+}
+
+impl TryFrom<String> for MovingAverageType {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        MovingAverageType::from_name(&name)
+    }
+}
+
+/// Parameters of the kinds that have more than a period.
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(default)]
+pub struct MovingAverageOptions {
+    /// ALMA: position of the Gaussian peak inside the window (0 = oldest, 1 = newest).
+    pub alma_offset: f64,
+    /// ALMA: width of the Gaussian, larger is sharper.
+    pub alma_sigma: f64,
+    /// T3: volume factor.
+    pub t3_volume_factor: f64,
+}
+
+impl Default for MovingAverageOptions {
+    fn default() -> Self {
+        MovingAverageOptions {
+            alma_offset: 0.85,
+            alma_sigma: 6.0,
+            t3_volume_factor: 0.7,
+        }
+    }
+}
+
+/// MACD line, its signal line and the histogram (macd - signal).
+#[derive(Serialize, Deserialize)]
+pub struct MacdResult {
+    pub macd: Vec<f64>,
+    pub signal: Vec<f64>,
+    pub histogram: Vec<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialization_ignores_the_casing() {
+        let kinds: Vec<MovingAverageType> = serde_json::from_str(r#"["EMA", "Hma", "rma", "t3"]"#).unwrap();
+        assert_eq!(
+            kinds,
+            vec![MovingAverageType::Ema, MovingAverageType::Hma, MovingAverageType::Smma, MovingAverageType::T3]
+        );
+        assert_eq!(serde_json::to_string(&MovingAverageType::Smma).unwrap(), r#""smma""#);
+        assert!(serde_json::from_str::<MovingAverageType>(r#""foo""#).is_err());
+    }
+}
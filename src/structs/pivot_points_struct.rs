@@ -1,26 +1,66 @@
-use crate::helpers::pivot_points_helper::calculate_pivot_point;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
-pub struct PivotLevels {
-    pub pivot_point: f64,
-    pub resistance1: f64,
-    pub resistance2: f64,
-    pub support1: f64,
-    pub support2: f64,
+/// Pivot point formula.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PivotMethod {
+    /// Floor pivots: P = (H + L + C) / 3.
+    #[default]
+    Classic,
+    /// Classic pivot with supports and resistances at Fibonacci ratios of the range.
+    Fibonacci,
+    /// Levels around the close at multiples of range * 1.1.
+    Camarilla,
+    /// P = (H + L + 2C) / 4.
+    Woodie,
+    /// P, R1 and S1 only, weighted by how the period closed relative to its open.
+    Demark,
+}
+
+/// Period whose high, low, open and close the levels are computed from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PivotTimeframe {
+    /// The previous candle.
+    Bar,
+    #[default]
+    Day,
+    /// Weeks start on Monday.
+    Week,
+    Month,
+}
+
+/// Options of the pivot points, passed from JS as a plain object.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct PivotPointsOptions {
+    pub method: PivotMethod,
+    pub timeframe: PivotTimeframe,
+    /// Shift of the day boundary from midnight UTC, in minutes.
+    pub session_offset_minutes: i64,
 }
 
-impl PivotLevels {
-    // Calculer les niveaux de support et résistance
-    pub fn new(high: f64, low: f64, close: f64) -> Self {
-        let pivot_point = calculate_pivot_point(high, low, close);
-        let range = high - low;
+/// Pivot levels; the levels a method doesn't define are `None`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PivotLevels {
+    pub pivot: f64,
+    pub r1: f64,
+    pub s1: f64,
+    pub r2: Option<f64>,
+    pub s2: Option<f64>,
+    pub r3: Option<f64>,
+    pub s3: Option<f64>,
+    pub r4: Option<f64>,
+    pub s4: Option<f64>,
+}
 
-        PivotLevels {
-            pivot_point,
-            resistance1: (2.0 * pivot_point) - low,
-            resistance2: pivot_point + range,
-            support1: (2.0 * pivot_point) - high,
-            support2: pivot_point - range,
-        }
-    }
-}
\ No newline at end of file
+/// Pivot levels in effect on a candle.
+///
+/// `levels` come from the previous completed period (`None` during the first period) and
+/// `developing` from the current period up to and including the candle.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PivotPointsBar {
+    pub date: String,
+    pub levels: Option<PivotLevels>,
+    pub developing: PivotLevels,
+}
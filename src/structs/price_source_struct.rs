@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// Price taken from each candle when an indicator works on a single series.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PriceSource {
+    Open,
+    High,
+    Low,
+    #[default]
+    Close,
+    /// (high + low) / 2
+    Hl2,
+    /// (high + low + close) / 3, the typical price.
+    Hlc3,
+    /// (open + high + low + close) / 4
+    Ohlc4,
+    /// (high + low + 2 * close) / 4, the weighted close.
+    Hlcc4,
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+/// Divisor of the variance: `n` (population) or `n - 1` (sample).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeviationType {
+    #[default]
+    Population,
+    Sample,
+}
+
+/// Statistic computed over the rolling window by `rolling_statistic`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RollingStatistic {
+    Sum,
+    Mean,
+    Variance,
+    #[default]
+    Std,
+    /// Distance of the current value from the window mean, in standard deviations.
+    ZScore,
+    Skewness,
+    /// Excess kurtosis (0 for a normal distribution).
+    Kurtosis,
+    Min,
+    Max,
+    Median,
+    /// Quantile `quantile` of the window, linearly interpolated between the closest ranks.
+    Quantile,
+    /// Share, in percent, of the other values of the window lower than or equal to the current one.
+    PercentRank,
+}
+
+/// Options of `rolling_statistic`, passed from JS as a plain object.
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(default)]
+pub struct RollingStatisticOptions {
+    pub statistic: RollingStatistic,
+    /// Number of (non-NaN) values in the window, current one included.
+    pub period: usize,
+    /// Used by `quantile`, between 0 and 1.
+    pub quantile: f64,
+    /// Used by `variance`, `std` and `z_score`.
+    pub deviation: DeviationType,
+}
+
+impl Default for RollingStatisticOptions {
+    fn default() -> Self {
+        RollingStatisticOptions {
+            statistic: RollingStatistic::Std,
+            period: 20,
+            quantile: 0.5,
+            deviation: DeviationType::Population,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::crossover_struct::LevelCrossing;
+use crate::structs::moving_average_struct::MovingAverageType;
+
+/// Options of the stochastic oscillator, passed from JS as a plain object.
+///
+/// `k_smoothing = 1` gives the Fast stochastic, `k_smoothing = 3` with `d_period = 3` the Slow one,
+/// and any other combination the Full stochastic.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct StochasticOptions {
+    /// Look-back window of the highest high and lowest low, current bar included.
+    pub k_period: usize,
+    /// Smoothing period applied to the raw %K.
+    pub k_smoothing: usize,
+    /// Period of %D, the moving average of %K.
+    pub d_period: usize,
+    /// Moving average used for both the %K smoothing and %D.
+    pub ma_type: MovingAverageType,
+    pub overbought: f64,
+    pub oversold: f64,
+}
+
+impl Default for StochasticOptions {
+    fn default() -> Self {
+        StochasticOptions {
+            k_period: 14,
+            k_smoothing: 1,
+            d_period: 3,
+            ma_type: MovingAverageType::Sma,
+            overbought: 80.0,
+            oversold: 20.0,
+        }
+    }
+}
+
+/// %K, %D and the overbought/oversold crossings of %K.
+#[derive(Serialize, Deserialize)]
+pub struct StochasticResult {
+    pub k: Vec<f64>,
+    pub d: Vec<f64>,
+    pub events: Vec<LevelCrossing>,
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::price_source_struct::PriceSource;
+
+/// Options of the SuperTrend, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct SuperTrendOptions {
+    pub atr_period: usize,
+    /// Distance of the bands from the source, in ATRs.
+    pub multiplier: f64,
+    /// Middle of the bands (hl2 by default).
+    pub source: PriceSource,
+}
+
+impl Default for SuperTrendOptions {
+    fn default() -> Self {
+        SuperTrendOptions {
+            atr_period: 10,
+            multiplier: 3.0,
+            source: PriceSource::Hl2,
+        }
+    }
+}
+
+/// SuperTrend line and trend state.
+///
+/// `direction` is 1 in an uptrend, -1 in a downtrend and 0 during the ATR warm-up. `upper` holds the
+/// line while it trails above the price (downtrend) and `lower` while it trails below (uptrend),
+/// NaN elsewhere. `flips` lists the indices where the direction changed.
+#[derive(Serialize, Deserialize)]
+pub struct SuperTrendResult {
+    pub supertrend: Vec<f64>,
+    pub direction: Vec<i8>,
+    pub flips: Vec<usize>,
+    pub upper: Vec<f64>,
+    pub lower: Vec<f64>,
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+/// Unit of the tolerance within which swing levels are merged into one zone.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ZoneToleranceMode {
+    /// Percentage of the level's price.
+    #[default]
+    Percent,
+    /// Price units.
+    Absolute,
+    /// Multiple of the last Average True Range.
+    Atr,
+}
+
+/// Options of the support/resistance zones, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct SupportResistanceOptions {
+    /// Swing detection, as in `extract_important_levels`.
+    pub left: usize,
+    pub right: usize,
+    pub strict: bool,
+    pub tolerance_mode: ZoneToleranceMode,
+    pub tolerance: f64,
+    /// Period of the ATR in `atr` mode.
+    pub atr_period: usize,
+    /// Number of zones returned, nearest to the last close first.
+    pub max_zones: usize,
+}
+
+impl Default for SupportResistanceOptions {
+    fn default() -> Self {
+        SupportResistanceOptions {
+            left: 5,
+            right: 5,
+            strict: false,
+            tolerance_mode: ZoneToleranceMode::Percent,
+            tolerance: 0.5,
+            atr_period: 14,
+            max_zones: 5,
+        }
+    }
+}
+
+/// Side of the last close a zone sits on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ZoneRole {
+    Support,
+    Resistance,
+}
+
+/// Swing levels merged into a price zone.
+///
+/// `touches` counts the swing points in the zone and `volume` the volume traded inside it, each
+/// candle contributing the share of its range that overlaps the zone. `flipped` is set when the
+/// zone was last touched from the other side than the first time (a support turned resistance or
+/// the other way round). `distance` is 0 when the last close is inside the zone.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SupportResistanceZone {
+    pub low: f64,
+    pub high: f64,
+    pub touches: usize,
+    pub first_touch: String,
+    pub last_touch: String,
+    pub volume: f64,
+    pub role: ZoneRole,
+    pub flipped: bool,
+    pub strength: f64,
+    pub distance: f64,
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::price_source_struct::PriceSource;
+
+/// Whether a swing point is a top or a bottom.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SwingKind {
+    High,
+    Low,
+}
+
+/// Market-structure label of a swing point against the previous one of the same kind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SwingLabel {
+    #[serde(rename = "HH")]
+    HigherHigh,
+    #[serde(rename = "LH")]
+    LowerHigh,
+    #[serde(rename = "HL")]
+    HigherLow,
+    #[serde(rename = "LL")]
+    LowerLow,
+}
+
+/// Options of the swing-point detection, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct SwingPointsOptions {
+    /// Bars before a swing point that must not exceed it.
+    pub left: usize,
+    /// Bars after a swing point that must not exceed it; the swing is confirmed `right` bars later.
+    pub right: usize,
+    /// Require the neighbours to be strictly lower (highs) or higher (lows), so that equal
+    /// extremes are not all reported.
+    pub strict: bool,
+    /// Single series to detect both kinds on; the highs and lows when `None`.
+    pub source: Option<PriceSource>,
+}
+
+impl Default for SwingPointsOptions {
+    fn default() -> Self {
+        SwingPointsOptions {
+            left: 5,
+            right: 5,
+            strict: false,
+            source: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SwingPoint {
+    pub index: usize,
+    pub date: String,
+    pub price: f64,
+    pub kind: SwingKind,
+}
+
+/// Swing highs (resistances) and swing lows (supports) with a summary of their levels.
+///
+/// Without any swing, the highest resistance, lowest support and average pivot fall back to the
+/// maximum, minimum and mean of the data.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ImportantLevels {
+    pub highest_resistance: f64,
+    pub lowest_support: f64,
+    pub average_pivot: f64,
+    pub supports: Vec<SwingPoint>,
+    pub resistances: Vec<SwingPoint>,
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::crossover_struct::LineCrossing;
+
+/// Aroon Up/Down, the Aroon Oscillator (up - down) and the crossings of Up over Down.
+#[derive(Serialize, Deserialize)]
+pub struct AroonResult {
+    pub up: Vec<f64>,
+    pub down: Vec<f64>,
+    pub oscillator: Vec<f64>,
+    pub events: Vec<LineCrossing>,
+}
+
+/// Vortex Indicator lines (VI+ and VI-) and the crossings of VI+ over VI-.
+#[derive(Serialize, Deserialize)]
+pub struct VortexResult {
+    pub plus: Vec<f64>,
+    pub minus: Vec<f64>,
+    pub events: Vec<LineCrossing>,
+}
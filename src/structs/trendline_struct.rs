@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::crossover_struct::LineCrossing;
+use crate::structs::support_resistance_struct::ZoneRole;
+
+/// Options of the trendline detection, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct TrendlineOptions {
+    /// Swing detection, as in `extract_important_levels`.
+    pub left: usize,
+    pub right: usize,
+    pub strict: bool,
+    /// Swing points a line must touch, its two anchors included.
+    pub min_touches: usize,
+    /// Distance from the line, in percent of its price, within which a pivot touches it and a
+    /// close does not violate it.
+    pub tolerance: f64,
+    /// Pivots from one anchor of a line to the other, at most. Only nearby pivots are paired, which
+    /// keeps the search linear in the number of pivots; a longer line is still found through its
+    /// consecutive touches.
+    pub max_anchor_gap: usize,
+}
+
+impl Default for TrendlineOptions {
+    fn default() -> Self {
+        TrendlineOptions {
+            left: 5,
+            right: 5,
+            strict: false,
+            min_touches: 3,
+            tolerance: 0.5,
+            max_anchor_gap: 5,
+        }
+    }
+}
+
+/// A trendline `price = intercept + slope * index`.
+///
+/// Support lines join swing lows and resistance lines swing highs. `start` and `end` are the
+/// anchor pivots; no close between them crosses the line. `broken` is the first close beyond the
+/// line after `end`: bullish through a resistance, bearish through a support.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Trendline {
+    pub kind: ZoneRole,
+    pub slope: f64,
+    pub intercept: f64,
+    pub start: usize,
+    pub end: usize,
+    pub touches: usize,
+    pub touch_indices: Vec<usize>,
+    pub broken: Option<LineCrossing>,
+}
+
+/// A line parallel to `lines[line]` through the opposite swing points, drawing a channel.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TrendChannel {
+    pub line: usize,
+    pub slope: f64,
+    pub intercept: f64,
+    pub touches: usize,
+    pub touch_indices: Vec<usize>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TrendlineResult {
+    pub lines: Vec<Trendline>,
+    pub channels: Vec<TrendChannel>,
+}
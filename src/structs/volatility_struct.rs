@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+/// Realised volatility estimator.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VolatilityEstimator {
+    /// Sample standard deviation of the log returns of the closes.
+    #[default]
+    CloseToClose,
+    /// High-low range, assumes no drift and no opening jump.
+    Parkinson,
+    /// Range and open-to-close move, assumes no drift and no opening jump.
+    GarmanKlass,
+    /// Range relative to the open and close, robust to drift.
+    RogersSatchell,
+    /// Overnight, open-to-close and Rogers-Satchell variances combined, robust to drift and jumps.
+    YangZhang,
+}
+
+/// Options of the historical volatility, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct HistoricalVolatilityOptions {
+    pub estimator: VolatilityEstimator,
+    /// Number of candles of each estimate.
+    pub period: usize,
+    /// Annualisation factor (candles per year). Derived from the candle dates when missing.
+    pub periods_per_year: Option<f64>,
+    /// Trading days per year used to derive the annualisation factor (252 for equities, 365 for crypto).
+    pub trading_days: f64,
+    /// Number of volatility values the percentile and rank are measured against.
+    pub rank_period: usize,
+}
+
+impl Default for HistoricalVolatilityOptions {
+    fn default() -> Self {
+        HistoricalVolatilityOptions {
+            estimator: VolatilityEstimator::CloseToClose,
+            period: 20,
+            periods_per_year: None,
+            trading_days: 252.0,
+            rank_period: 252,
+        }
+    }
+}
+
+/// Annualised volatility with its percentile and rank, aligned with the candles (NaN during the warm-up).
+#[derive(Serialize, Deserialize)]
+pub struct HistoricalVolatilityResult {
+    /// Annualised volatility, as a fraction (0.2 = 20%).
+    pub values: Vec<f64>,
+    /// Share, in percent, of the last `rank_period` volatilities lower than or equal to the current one.
+    pub percentile: Vec<f64>,
+    /// Position of the current volatility between the lowest (0) and highest (100) of the last `rank_period`.
+    pub rank: Vec<f64>,
+    /// Annualisation factor that was applied.
+    pub periods_per_year: f64,
+}
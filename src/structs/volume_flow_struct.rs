@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Result of a volume flow indicator (OBV, A/D line, CMF, Chaikin oscillator, PVT).
+#[derive(Serialize, Deserialize)]
+pub struct VolumeFlowResult {
+    /// Indicator values, one per candle.
+    pub values: Vec<f64>,
+    /// Signal line (moving average of `values`), only present when a signal period is given.
+    pub signal: Option<Vec<f64>>,
+}
+
+/// Latest value returned by a streaming volume flow indicator.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug)]
+pub struct VolumeFlowPoint {
+    pub value: f64,
+    /// NaN when the stream was created without a signal period.
+    pub signal: f64,
+}
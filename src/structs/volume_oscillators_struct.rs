@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+/// Ease of Movement: raw one-bar values and their simple moving average.
+#[derive(Serialize, Deserialize)]
+pub struct EaseOfMovementResult {
+    pub raw: Vec<f64>,
+    pub smoothed: Vec<f64>,
+}
+
+/// Force Index: raw values and their exponential moving average.
+#[derive(Serialize, Deserialize)]
+pub struct ForceIndexResult {
+    pub raw: Vec<f64>,
+    pub smoothed: Vec<f64>,
+}
+
+/// Klinger Volume Oscillator and its signal line.
+#[derive(Serialize, Deserialize)]
+pub struct KlingerOscillatorResult {
+    pub kvo: Vec<f64>,
+    pub signal: Vec<f64>,
+}
+
+/// Percentage Volume Oscillator, its signal line and the histogram (pvo - signal).
+#[derive(Serialize, Deserialize)]
+pub struct PercentageVolumeOscillatorResult {
+    pub pvo: Vec<f64>,
+    pub signal: Vec<f64>,
+    pub histogram: Vec<f64>,
+}
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+/// How the volume of a bar is spread over the price bins its high–low range covers.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VolumeDistribution {
+    /// Evenly across the range.
+    #[default]
+    Uniform,
+    /// Weighted toward the close.
+    CloseWeighted,
+}
+
+/// Options shared by the volume profile functions, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct VolumeProfileOptions {
+    /// Number of price bins (ignored when `bin_size` is set).
+    pub bin_count: usize,
+    /// Fixed height of a price bin, e.g. the tick size.
+    pub bin_size: Option<f64>,
+    /// Share of the volume contained in the value area (0.7 = 70%).
+    pub value_area: f64,
+    pub distribution: VolumeDistribution,
+    /// Shift of the session boundary from midnight UTC, in minutes.
+    pub session_offset_minutes: i64,
+}
+
+impl Default for VolumeProfileOptions {
+    fn default() -> Self {
+        VolumeProfileOptions {
+            bin_count: 24,
+            bin_size: None,
+            value_area: 0.7,
+            distribution: VolumeDistribution::Uniform,
+            session_offset_minutes: 0,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct VolumeProfileBin {
+    pub price_low: f64,
+    pub price_high: f64,
+    pub volume: f64,
+}
+
+/// Volume-at-price histogram of a range of candles.
+#[derive(Serialize, Deserialize)]
+pub struct VolumeProfile {
+    pub start_index: usize,
+    pub end_index: usize,
+    pub start_date: String,
+    pub end_date: String,
+    pub bin_size: f64,
+    pub total_volume: f64,
+    /// Middle of the bin holding the most volume.
+    pub point_of_control: f64,
+    pub value_area_high: f64,
+    pub value_area_low: f64,
+    /// Middle prices of the high volume nodes.
+    pub high_volume_nodes: Vec<f64>,
+    /// Middle prices of the low volume nodes.
+    pub low_volume_nodes: Vec<f64>,
+    /// Bins from the lowest price to the highest.
+    pub bins: Vec<VolumeProfileBin>,
+}
+
+/// Key levels of a rolling volume profile, one value per candle.
+#[derive(Serialize, Deserialize)]
+pub struct RollingVolumeProfileResult {
+    pub point_of_control: Vec<f64>,
+    pub value_area_high: Vec<f64>,
+    pub value_area_low: Vec<f64>,
+}
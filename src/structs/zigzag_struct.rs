@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::structs::swing_points_struct::{SwingKind, SwingLabel};
+
+/// Unit of the ZigZag reversal threshold.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ZigZagMode {
+    /// Percentage of the extreme price.
+    #[default]
+    Percent,
+    /// Price units.
+    Absolute,
+    /// Multiple of the Average True Range.
+    Atr,
+}
+
+/// Options of the ZigZag, passed from JS as a plain object.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct ZigZagOptions {
+    pub mode: ZigZagMode,
+    /// Reversal needed to confirm a pivot, in the unit of `mode`.
+    pub threshold: f64,
+    /// Period of the ATR in `atr` mode.
+    pub atr_period: usize,
+}
+
+impl Default for ZigZagOptions {
+    fn default() -> Self {
+        ZigZagOptions {
+            mode: ZigZagMode::Percent,
+            threshold: 5.0,
+            atr_period: 14,
+        }
+    }
+}
+
+/// A ZigZag pivot.
+///
+/// `confirmed_at` is the index of the bar whose reversal confirmed the pivot; the last pivot is the
+/// end of the leg still being drawn and is not confirmed. `label` is `None` for the first pivot of
+/// each kind.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ZigZagPivot {
+    pub index: usize,
+    pub date: String,
+    pub price: f64,
+    pub kind: SwingKind,
+    pub label: Option<SwingLabel>,
+    pub confirmed: bool,
+    pub confirmed_at: Option<usize>,
+}
@@ -0,0 +1,52 @@
+import { test } from '@japa/runner'
+import {
+    kaufmanAdaptiveMovingAverage,
+    variableIndexDynamicAverage,
+    fractalAdaptiveMovingAverage,
+    mcginleyDynamic,
+} from '../../dist/index.js'
+
+test.group('AdaptiveMovingAverage', () => {
+    test('test kama efficiency ratio is 1 on a straight trend', ({ assert }) => {
+        const data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]
+        const result = kaufmanAdaptiveMovingAverage(data, 3)
+        assert.isTrue(isNaN(result.values[2]))
+        assert.equal(result.efficiency_ratio[3], 1)
+        assert.approximately(result.alpha[3], (2 / 3) ** 2, 0.0001)
+    })
+
+    test('test kama rejects a fast period above the slow period', ({ assert }) => {
+        try {
+            kaufmanAdaptiveMovingAverage([1.0, 2.0, 3.0], 2, 30, 2)
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Fast period must be lower than slow period.')
+        }
+    })
+
+    test('test vidya does not move when momentum is zero', ({ assert }) => {
+        const result = variableIndexDynamicAverage([1.0, 2.0, 3.0, 2.0], 3, 2)
+        assert.equal(result.values[2], 2.5)
+        assert.equal(result.alpha[3], 0)
+        assert.equal(result.values[3], 2.5)
+    })
+
+    test('test frama requires an even period', ({ assert }) => {
+        const data = [
+            { high: 12, low: 10, close: 11, open: 10.5, volume: 1000, date: "2025-01-01" },
+        ]
+        try {
+            fractalAdaptiveMovingAverage(data, 5)
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Period must be an even number.')
+        }
+    })
+
+    test('test mcginley dynamic starts at the sma', ({ assert }) => {
+        const result = mcginleyDynamic([10.0, 10.0, 11.0], 2)
+        assert.isTrue(isNaN(result.values[0]))
+        assert.equal(result.values[1], 10)
+        assert.isTrue(result.values[2] > 10 && result.values[2] < 11)
+    })
+})
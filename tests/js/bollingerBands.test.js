@@ -6,7 +6,7 @@ test.group('BollingerBands', (group) => {
     test('test invalid period zero', ({ assert }) => {
         const data = [1.0, 2.0, 3.0, 4.0, 5.0]
         try {
-            bollingerBands(data, 0)
+            bollingerBands(data, { period: 0 })
             assert.fail()
         } catch (error) {
             assert.equal(error.message, 'Period must be greater than 0.')
@@ -16,7 +16,7 @@ test.group('BollingerBands', (group) => {
     test('test empty data', ({ assert }) => {
         const data = []
         try {
-            bollingerBands(data, 20)
+            bollingerBands(data, { period: 20 })
             assert.fail()
         } catch (error) {
             assert.equal(error.message, 'Prices vector must not be empty.')
@@ -25,46 +25,47 @@ test.group('BollingerBands', (group) => {
 
     test('test valid input', ({ assert }) => {
         const data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]
-        const result = bollingerBands(data, 3, 2)
+        const result = bollingerBands(data, { period: 3, multiplier: 2 })
 
-        assert.deepEqual(result.middle, [2, 3, 4, 5, 6, 7, 8, 9])
-        assert.deepEqual(result.upper, [
-            3.632993161855452,
-            4.6329931618554525,
-            5.6329931618554525,
-            6.6329931618554525,
-            7.6329931618554525,
-            8.632993161855453,
-            9.632993161855453,
-            10.632993161855453
-        ])
-        assert.deepEqual(result.lower, [
-            0.36700683814454793,
-            1.367006838144548,
-            2.367006838144548,
-            3.367006838144548,
-            4.3670068381445475,
-            5.3670068381445475,
-            6.3670068381445475,
-            7.3670068381445475
-        ])
+        assert.isTrue(isNaN(result.middle[0]) && isNaN(result.middle[1]))
+        assert.deepEqual(result.middle.slice(2), [2, 3, 4, 5, 6, 7, 8, 9])
+        const stdev = Math.sqrt(2 / 3)
+        result.upper.slice(2).forEach((value, i) => assert.approximately(value, i + 2 + 2 * stdev, 1e-9))
+        result.lower.slice(2).forEach((value, i) => assert.approximately(value, i + 2 - 2 * stdev, 1e-9))
     })
 
     test('test with default multiplier', ({ assert }) => {
         const data = [1.0, 2.0, 3.0, 4.0, 5.0]
-        const expected = {
-            middle: [2, 3, 4],
-            upper: [3.632993161855452, 4.6329931618554525, 5.6329931618554525],
-            lower: [0.36700683814454793, 1.367006838144548, 2.367006838144548],
-        }
-        const result = bollingerBands(data, 3)
-        assert.deepEqual(result, expected)
+        const result = bollingerBands(data, { period: 3 })
+        assert.approximately(result.upper[4], 4 + 2 * Math.sqrt(2 / 3), 1e-9)
+        assert.approximately(result.lower[4], 4 - 2 * Math.sqrt(2 / 3), 1e-9)
+    })
+
+    test('test percent b and bandwidth', ({ assert }) => {
+        const result = bollingerBands([1.0, 2.0, 3.0], { period: 3 })
+        const stdev = Math.sqrt(2 / 3)
+        assert.approximately(result.percent_b[2], 0.5 + 1 / (4 * stdev), 1e-9)
+        assert.approximately(result.bandwidth[2], 2 * stdev, 1e-9)
+        assert.isFalse(result.squeeze[2])
+    })
+
+    test('test sample deviation and asymmetric multipliers', ({ assert }) => {
+        const options = { period: 3, deviation: 'sample', upper_multiplier: 1, lower_multiplier: 3 }
+        const result = bollingerBands([1.0, 2.0, 3.0], options)
+        assert.approximately(result.upper[2], 3, 1e-9)
+        assert.approximately(result.lower[2], -1, 1e-9)
+    })
+
+    test('test squeeze on a narrowing range', ({ assert }) => {
+        const data = [10.0, 12.0, 10.0, 12.0, 11.0, 11.5, 10.9, 11.2]
+        const result = bollingerBands(data, { period: 2, squeeze_period: 3 })
+        assert.deepEqual(result.squeeze, [false, false, false, true, true, true, false, true])
     })
 
     test('test invalid multiplier', ({ assert }) => {
         const data = [1.0, 2.0, 3.0, 4.0, 5.0]
         try {
-            bollingerBands(data, 3, -1)
+            bollingerBands(data, { period: 3, multiplier: -1 })
             assert.fail()
         } catch (error) {
             assert.equal(error.message, 'Multiplier must be greater than 0.')
@@ -74,10 +75,29 @@ test.group('BollingerBands', (group) => {
     test('test invalid period', ({ assert }) => {
         const data = [1.0, 2.0, 3.0, 4.0, 5.0]
         try {
-            bollingerBands(data, -1)
+            bollingerBands(data, { period: -1 })
+            assert.fail()
+        } catch (error) {
+            assert.equal(error.message, 'Period must be greater than 0.')
+        }
+    })
+
+    test('test positional period and multiplier return the trimmed bands', ({ assert }) => {
+        const data = [1.0, 2.0, 3.0, 4.0, 5.0]
+        const result = bollingerBands(data, 3, 2)
+        assert.deepEqual(Object.keys(result), ['middle', 'upper', 'lower'])
+        assert.deepEqual(result.middle, [2, 3, 4])
+        assert.approximately(result.upper[0], 2 + 2 * Math.sqrt(2 / 3), 1e-9)
+        assert.approximately(result.lower[2], 4 - 2 * Math.sqrt(2 / 3), 1e-9)
+    })
+
+    test('test invalid positional period', ({ assert }) => {
+        const data = [1.0, 2.0, 3.0, 4.0, 5.0]
+        try {
+            bollingerBands(data, 0, 2)
             assert.fail()
         } catch (error) {
             assert.equal(error.message, 'Period must be greater than 0.')
         }
     })
-})
\ No newline at end of file
+})
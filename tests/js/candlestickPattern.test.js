@@ -0,0 +1,34 @@
+import { test } from '@japa/runner'
+import { candlestickPatterns } from '../../dist/index.js'
+
+test.group('CandlestickPatterns', (group) => {
+
+    let data
+
+    group.setup(() => {
+        data = [
+            { open: 10.5, high: 10.6, low: 9.9, close: 10.0, volume: 1000, date: "2025-01-01" },
+            { open: 10.0, high: 10.2, low: 8.8, close: 9.0, volume: 1000, date: "2025-01-02" },
+            { open: 8.9, high: 10.5, low: 8.7, close: 10.4, volume: 1000, date: "2025-01-03" },
+        ]
+    })
+
+    test('test bullish engulfing', ({ assert }) => {
+        const hits = candlestickPatterns(data)
+        const engulfing = hits.find(hit => hit.pattern === 'engulfing')
+        assert.equal(engulfing.index, 2)
+        assert.equal(engulfing.direction, 'bullish')
+        assert.equal(engulfing.bars, 2)
+        // No trend context.
+        assert.equal(engulfing.confidence, 0.75)
+    })
+
+    test('test invalid trend period', ({ assert }) => {
+        try {
+            candlestickPatterns(data, { trend_period: 0 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Period must be greater than 0.')
+        }
+    })
+})
@@ -0,0 +1,33 @@
+import { test } from '@japa/runner'
+import { chartPatterns } from '../../dist/index.js'
+
+test.group('ChartPatterns', (group) => {
+
+    let data
+
+    group.setup(() => {
+        // Two tops at 110 around a trough at 100, then a close below the neckline.
+        const closes = [100, 105, 110, 105, 100, 105, 110, 105, 103, 98, 97]
+        data = closes.map((close, index) => ({
+            high: close, low: close, close, open: close, volume: 1000, date: `2025-01-${String(index + 1).padStart(2, '0')}`,
+        }))
+    })
+
+    test('test double top with confirmed breakout', ({ assert }) => {
+        const hits = chartPatterns(data, { left: 1, right: 1 })
+        const top = hits.find(hit => hit.pattern === 'double_top')
+        assert.deepEqual(top.pivots.map(pivot => pivot.index), [2, 4, 6])
+        assert.equal(top.breakout, 9)
+        assert.isTrue(top.confirmed)
+        assert.equal(top.target, 90)
+    })
+
+    test('test invalid pole ratio', ({ assert }) => {
+        try {
+            chartPatterns(data, { pole_ratio: 0 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Pole ratio must be greater than 0.')
+        }
+    })
+})
@@ -0,0 +1,41 @@
+import { test } from '@japa/runner'
+import { divergence, relativeStrengthIndex } from '../../dist/index.js'
+
+test.group('Divergence', () => {
+    test('test regular bullish divergence', ({ assert }) => {
+        const prices = [10.0, 8.0, 9.0, 10.0, 7.0, 9.0]
+        const oscillator = [50.0, 30.0, 40.0, 45.0, 35.0, 50.0]
+        const result = divergence(prices, oscillator, { left: 1, right: 1, min_span: 2 })
+
+        assert.lengthOf(result, 1)
+        assert.equal(result[0].kind, 'regular_bullish')
+        assert.deepEqual(result[0].price.map(pivot => pivot.index), [1, 4])
+        assert.equal(result[0].span, 3)
+    })
+
+    test('test oscillator warm-up is ignored', ({ assert }) => {
+        const prices = [10.0, 8.0, 9.0, 10.0, 7.0, 9.0]
+        const oscillator = [NaN, NaN, NaN, 45.0, 35.0, 50.0]
+        assert.lengthOf(divergence(prices, oscillator, { left: 1, right: 1, min_span: 2 }), 0)
+    })
+
+    test('test shorter rsi is right-aligned', ({ assert }) => {
+        const prices = [20, 23, 26, 24, 21, 19, 20, 22, 23, 21, 18, 20, 18, 17]
+        const rsi = Array.from(relativeStrengthIndex(prices, 4))
+        const result = divergence(prices, rsi, { left: 1, right: 1, min_span: 2 })
+
+        assert.lengthOf(result, 1)
+        assert.equal(result[0].kind, 'regular_bullish')
+        assert.deepEqual(result[0].price.map(pivot => pivot.index), [5, 10])
+        assert.deepEqual(result[0].oscillator.map(pivot => pivot.index), [6, 11])
+    })
+
+    test('test oscillator longer than the prices', ({ assert }) => {
+        try {
+            divergence([1.0, 2.0], [1.0, 2.0, 3.0])
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Oscillator must not be longer than the prices.')
+        }
+    })
+})
@@ -0,0 +1,29 @@
+import { test } from '@japa/runner'
+import { extractImportantLevels } from '../../dist/index.js'
+
+test.group('ExtractImportantLevels', (group) => {
+
+    let data
+
+    group.setup(() => {
+        data = [1, 3, 2, 0, 2, 1].map((close, index) => ({
+            high: close + 0.5, low: close - 0.5, close, open: close, volume: 1000, date: `2025-01-0${index + 1}`,
+        }))
+    })
+
+    test('test swing points with one bar on each side', ({ assert }) => {
+        const levels = extractImportantLevels(data, { left: 1, right: 1 })
+        assert.deepEqual(levels.resistances.map(point => point.index), [1, 4])
+        assert.deepEqual(levels.supports.map(point => point.index), [3])
+        assert.equal(levels.supports[0].date, '2025-01-04')
+        assert.equal(levels.supports[0].price, -0.5)
+        assert.equal(levels.highest_resistance, 3.5)
+    })
+
+    test('test short input has no swing', ({ assert }) => {
+        const levels = extractImportantLevels(data)
+        assert.lengthOf(levels.supports, 0)
+        assert.lengthOf(levels.resistances, 0)
+        assert.equal(levels.lowest_support, -0.5)
+    })
+})
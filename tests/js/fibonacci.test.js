@@ -0,0 +1,56 @@
+import { test } from '@japa/runner'
+import { fibonacciLevels } from '../../dist/index.js'
+
+test.group('FibonacciLevels', (group) => {
+
+    let data
+
+    group.setup(() => {
+        data = [
+            { high: 2, low: 0, close: 1, open: 1, volume: 1000, date: "2025-01-01" },
+            { high: 10, low: 8, close: 9, open: 9, volume: 1000, date: "2025-01-02" },
+            { high: 9, low: 6.5, close: 7, open: 7, volume: 1000, date: "2025-01-03" },
+            { high: 8.5, low: 6, close: 7, open: 7, volume: 1000, date: "2025-01-04" },
+        ]
+    })
+
+    test('test levels of an explicit swing up', ({ assert }) => {
+        const result = fibonacciLevels(data, { start: 0, end: 1 })
+        assert.equal(result.direction, 1)
+        assert.equal(result.end.date, "2025-01-02")
+        assert.approximately(result.retracements[3].price, 3.82, 0.0001)
+        assert.approximately(result.extensions[1].price, 16.18, 0.0001)
+        assert.deepEqual(result.time_zones, [0, 1, 2, 3])
+        assert.deepEqual(result.reactions.map(reaction => reaction.kind), ['resistance', 'support'])
+    })
+
+    test('test explicit swing up from a wide bar', ({ assert }) => {
+        const wide = [
+            { high: 110, low: 90, close: 95, open: 105, volume: 1000, date: "2025-01-01" },
+            { high: 104, low: 100, close: 103, open: 101, volume: 1000, date: "2025-01-02" },
+            { high: 105, low: 103, close: 104, open: 103, volume: 1000, date: "2025-01-03" },
+        ]
+        const result = fibonacciLevels(wide, { start: 0, end: 2 })
+        assert.equal(result.direction, 1)
+        assert.equal(result.start.price, 90)
+        assert.equal(result.end.price, 105)
+    })
+
+    test('test automatic swing needs confirmed pivots', ({ assert }) => {
+        try {
+            fibonacciLevels(data)
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Not enough swing points to anchor the levels.')
+        }
+    })
+
+    test('test invalid indices', ({ assert }) => {
+        try {
+            fibonacciLevels(data, { start: 2, end: 1 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Start index must be lower than end index.')
+        }
+    })
+})
@@ -0,0 +1,34 @@
+import { test } from '@japa/runner'
+import { harmonicPatterns } from '../../dist/index.js'
+
+test.group('HarmonicPatterns', (group) => {
+
+    let data
+
+    group.setup(() => {
+        // X = 100, A = 200, B = 138.2, C = 169.1, D = 121.4.
+        const closes = [100, 150, 200, 170, 138.2, 155, 169.1, 145, 121.4, 123]
+        data = closes.map((close, index) => ({
+            high: close, low: close, close, open: close, volume: 1000, date: `2025-01-${String(index + 1).padStart(2, '0')}`,
+        }))
+    })
+
+    test('test bullish gartley on the zigzag pivots', ({ assert }) => {
+        const hits = harmonicPatterns(data)
+        assert.lengthOf(hits, 1)
+        assert.equal(hits[0].pattern, 'gartley')
+        assert.equal(hits[0].direction, 'bullish')
+        assert.deepEqual(hits[0].points.map(point => point.index), [0, 2, 4, 6, 8])
+        assert.isFalse(hits[0].points[4].confirmed)
+        assert.approximately(hits[0].prz_low, 121.4, 0.0001)
+    })
+
+    test('test invalid tolerance', ({ assert }) => {
+        try {
+            harmonicPatterns(data, { tolerance: -1 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Tolerance must not be negative.')
+        }
+    })
+})
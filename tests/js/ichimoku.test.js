@@ -14,15 +14,19 @@ test.group('Ichimoku Cloud Indicator', () => {
             'kijun_sen',
             'senkou_span_a',
             'senkou_span_b',
-            'chikou_span'
+            'chikou_span',
+            'future_dates',
+            'signals'
         ])
 
-        // Check array lengths
-        assert.equal(result.tenkan_sen.length, testData.length)
-        assert.equal(result.kijun_sen.length, testData.length)
-        assert.equal(result.senkou_span_a.length, testData.length)
-        assert.equal(result.senkou_span_b.length, testData.length)
-        assert.equal(result.chikou_span.length, testData.length)
+        // Check array lengths: the cloud is projected 26 bars into the future
+        assert.equal(result.tenkan_sen.length, testData.length + 26)
+        assert.equal(result.kijun_sen.length, testData.length + 26)
+        assert.equal(result.senkou_span_a.length, testData.length + 26)
+        assert.equal(result.senkou_span_b.length, testData.length + 26)
+        assert.equal(result.chikou_span.length, testData.length + 26)
+        assert.equal(result.future_dates.length, 26)
+        assert.equal(result.signals.cloud_position.length, testData.length)
     })
 
     test('should handle minimal data set', ({ assert }) => {
@@ -34,6 +38,7 @@ test.group('Ichimoku Cloud Indicator', () => {
         assert.isTrue(isNaN(result.kijun_sen[0]))
         assert.isTrue(isNaN(result.senkou_span_a[0]))
         assert.isTrue(isNaN(result.senkou_span_b[0]))
+        assert.isNull(result.signals.cloud_position[0])
     })
 
     test('should calculate correct Tenkan-sen values', ({ assert }) => {
@@ -73,8 +78,8 @@ test.group('Ichimoku Cloud Indicator', () => {
         const testData = generateTestData(60)
         const result = ichimoku(testData)
 
-        // Last 26 values should be NaN
-        for (let i = testData.length - 26; i < testData.length; i++) {
+        // Last 26 values (and the projected bars) should be NaN
+        for (let i = testData.length - 26; i < testData.length + 26; i++) {
             assert.isTrue(isNaN(result.chikou_span[i]))
         }
 
@@ -83,4 +88,36 @@ test.group('Ichimoku Cloud Indicator', () => {
             assert.equal(result.chikou_span[i], testData[i + 26].close)
         }
     })
-})
\ No newline at end of file
+
+    test('should project the cloud beyond the last bar', ({ assert }) => {
+        const testData = generateTestData(60)
+        const result = ichimoku(testData)
+
+        // The last computed spans are drawn on the last projected bar
+        const last = result.senkou_span_b.length - 1
+        assert.isFalse(isNaN(result.senkou_span_a[last]))
+        assert.isFalse(isNaN(result.senkou_span_b[last]))
+        assert.isTrue(isNaN(result.tenkan_sen[last]))
+
+        // Daily bars: the projected dates continue day by day
+        assert.equal(result.future_dates[0], '2024-03-01')
+        assert.equal(result.future_dates[25], '2024-03-26')
+    })
+
+    test('should use a displacement separate from the kijun period', ({ assert }) => {
+        const testData = generateTestData(80)
+        const result = ichimoku(testData, 20, 30, 60, 10)
+
+        assert.equal(result.senkou_span_a.length, testData.length + 10)
+        assert.equal(result.future_dates.length, 10)
+        assert.equal(result.chikou_span[0], testData[10].close)
+    })
+
+    test('should accept a displacement longer than the data', ({ assert }) => {
+        const testData = generateTestData(60)
+        const result = ichimoku(testData, 9, 26, 52, 100)
+
+        assert.equal(result.chikou_span.length, testData.length + 100)
+        assert.isTrue(result.chikou_span.every(value => isNaN(value)))
+    })
+})
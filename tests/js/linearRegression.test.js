@@ -0,0 +1,64 @@
+import { test } from '@japa/runner'
+import { linearRegression, linearRegressionChannel, standardErrorBands } from '../../dist/index.js'
+
+test.group('LinearRegression', (group) => {
+
+    let data
+
+    group.setup(() => {
+        data = [1, 3, 2, 4, 6].map((close, i) => ({
+            high: close + 0.5,
+            low: close - 0.5,
+            close,
+            open: close,
+            volume: 1000,
+            date: `2025-01-0${i + 1}`,
+        }))
+    })
+
+    test('test invalid period', ({ assert }) => {
+        try {
+            linearRegression(data, { period: 1 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Period must be greater than 1.')
+        }
+    })
+
+    test('test rolling slope, r squared and forecast', ({ assert }) => {
+        const result = linearRegression(data, { period: 4 })
+        assert.isTrue(isNaN(result.slope[2]))
+        assert.approximately(result.slope[3], 0.8, 0.0001)
+        assert.approximately(result.intercept[3], 1.3, 0.0001)
+        assert.approximately(result.value[3], 3.7, 0.0001)
+        assert.approximately(result.forecast[3], 4.5, 0.0001)
+        assert.approximately(result.r_squared[3], 0.64, 0.0001)
+        assert.approximately(result.standard_error[3], Math.sqrt(0.9), 0.0001)
+    })
+
+    test('test channel needs enough candles', ({ assert }) => {
+        try {
+            linearRegressionChannel(data, { period: 10 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Not enough data.')
+        }
+    })
+
+    test('test regression and raff channels', ({ assert }) => {
+        const channel = linearRegressionChannel(data.slice(0, 4), { period: 4 })
+        assert.equal(channel.start, 0)
+        assert.equal(channel.end, 3)
+        assert.approximately(channel.middle[3], 3.7, 0.0001)
+        assert.approximately(channel.upper[3] - channel.middle[3], 2 * Math.sqrt(0.9), 0.0001)
+        // The high of the second candle is 0.9 + 0.5 above the line.
+        assert.approximately(channel.raff_upper[0] - channel.middle[0], 1.4, 0.0001)
+    })
+
+    test('test standard error bands', ({ assert }) => {
+        const bands = standardErrorBands(data, { period: 4, smoothing: 1 })
+        assert.isTrue(isNaN(bands.middle[2]))
+        assert.approximately(bands.middle[3], 3.7, 0.0001)
+        assert.approximately(bands.upper[3], 3.7 + 2 * Math.sqrt(0.9), 0.0001)
+    })
+})
@@ -0,0 +1,56 @@
+import { test } from '@japa/runner'
+import { marketProfile } from '../../dist/index.js'
+
+test.group('MarketProfile', (group) => {
+
+    let data
+
+    group.setup(() => {
+        data = [
+            { high: 102, low: 100, close: 101.5, open: 100.5, volume: 1000, date: "2025-01-02T14:30:00Z" },
+            { high: 103, low: 101, close: 102.5, open: 102, volume: 1000, date: "2025-01-02T15:00:00Z" },
+            { high: 101, low: 100, close: 100.5, open: 100.5, volume: 1000, date: "2025-01-02T15:30:00Z" },
+            { high: 105, low: 104, close: 104.5, open: 104, volume: 1000, date: "2025-01-03T14:30:00Z" },
+        ]
+    })
+
+    test('letters are stacked on every price level of a session', ({ assert }) => {
+        const result = marketProfile(data, { tick_size: 1 })
+        assert.lengthOf(result, 2)
+
+        const profile = result[0]
+        assert.equal(profile.start_index, 0)
+        assert.equal(profile.end_index, 2)
+        assert.deepEqual(profile.levels.map(level => level.letters), ['AC', 'ABC', 'AB', 'B'])
+        assert.deepEqual(profile.periods.map(period => period.letter), ['A', 'B', 'C'])
+        assert.equal(profile.point_of_control, 101)
+        assert.equal(profile.initial_balance_low, 100)
+        assert.equal(profile.initial_balance_high, 103)
+        assert.isTrue(profile.poor_low)
+        assert.isFalse(profile.poor_high)
+        assert.equal(result[1].start_date, "2025-01-03T14:30:00Z")
+    })
+
+    test('custom letters and period length', ({ assert }) => {
+        const result = marketProfile(data.slice(0, 3), { tick_size: 1, period_minutes: 60, letters: 'xy' })
+        assert.deepEqual(result[0].periods.map(period => period.letter), ['x', 'y'])
+    })
+
+    test('tick size is required', ({ assert }) => {
+        try {
+            marketProfile(data, {})
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Tick size must be greater than 0.')
+        }
+    })
+
+    test('invalid value area throws an error', ({ assert }) => {
+        try {
+            marketProfile(data, { tick_size: 1, value_area: 0 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Value area must be between 0 and 1.')
+        }
+    })
+})
@@ -0,0 +1,48 @@
+import { test } from '@japa/runner'
+import { williamsR, commodityChannelIndex } from '../../dist/index.js'
+
+test.group('MomentumOscillators', (group) => {
+
+    let data
+
+    group.setup(() => {
+        data = [
+            { high: 10, low: 8, close: 9, open: 8.5, volume: 1000, date: "2025-01-01" },
+            { high: 12, low: 9, close: 12, open: 9, volume: 1000, date: "2025-01-02" },
+            { high: 11, low: 9, close: 9.5, open: 11, volume: 1000, date: "2025-01-03" },
+        ]
+    })
+
+    test('test williams %R goes from 0 to -100', ({ assert }) => {
+        const result = williamsR(data, { period: 2 })
+        assert.isTrue(isNaN(result.values[0]))
+        assert.equal(result.values[1], 0)
+        assert.approximately(result.values[2], -100 * 2.5 / 3, 0.0001)
+        assert.isNull(result.signal)
+    })
+
+    test('test williams %R threshold events', ({ assert }) => {
+        const result = williamsR(data, { period: 2 })
+        assert.deepEqual(result.events.map((event) => event.kind), ['exit_overbought', 'enter_oversold'])
+    })
+
+    test('test williams %R signal line', ({ assert }) => {
+        const result = williamsR(data, { period: 2, signal_period: 2 })
+        assert.approximately(result.signal[2], (0 + result.values[2]) / 2, 0.0001)
+    })
+
+    test('test cci on the close uses the mean absolute deviation', ({ assert }) => {
+        const closes = [1, 2, 6].map((close, index) => ({ ...data[index], close }))
+        const result = commodityChannelIndex(closes, { period: 3, source: 'close' })
+        assert.approximately(result.values[2], 3 / (0.015 * 2), 0.0001)
+    })
+
+    test('test cci invalid period', ({ assert }) => {
+        try {
+            commodityChannelIndex(data, { period: 0 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Period must be greater than 0.')
+        }
+    })
+})
@@ -0,0 +1,66 @@
+import { test } from '@japa/runner'
+import { movingAverage, macd, MovingAverageStream } from '../../dist/index.js'
+
+test.group('MovingAverage', () => {
+    test('test invalid period zero', ({ assert }) => {
+        try {
+            movingAverage([1.0, 2.0, 3.0], 0)
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Period must be greater than 0.')
+        }
+    })
+
+    test('test unknown moving average type', ({ assert }) => {
+        try {
+            movingAverage([1.0, 2.0, 3.0], 2, 'foo')
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Unknown moving average type: foo.')
+        }
+    })
+
+    test('test sma is the default', ({ assert }) => {
+        const result = movingAverage([1.0, 2.0, 3.0, 4.0], 2)
+        assert.isTrue(isNaN(result[0]))
+        assert.deepEqual(result.slice(1), [1.5, 2.5, 3.5])
+    })
+
+    test('test wma weights the most recent values', ({ assert }) => {
+        const result = movingAverage([1.0, 2.0, 3.0], 3, 'wma')
+        // (1 * 1 + 2 * 2 + 3 * 3) / 6
+        assert.approximately(result[2], 14 / 6, 0.0001)
+    })
+
+    test('test vwma requires volumes', ({ assert }) => {
+        try {
+            movingAverage([1.0, 2.0, 3.0], 2, 'vwma')
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'VWMA requires volumes.')
+        }
+    })
+
+    test('test streaming matches the batch computation', ({ assert }) => {
+        const data = [3.0, 5.0, 4.0, 6.0, 8.0, 7.0, 9.0, 10.0]
+        for (const kind of ['ema', 'hma', 'tema', 't3']) {
+            const batch = movingAverage(data, 3, kind)
+            const stream = new MovingAverageStream(3, kind)
+            data.forEach((value, index) => {
+                const next = stream.next(value)
+                if (isNaN(batch[index])) {
+                    assert.isTrue(isNaN(next))
+                } else {
+                    assert.approximately(next, batch[index], 0.0001)
+                }
+            })
+        }
+    })
+
+    test('test macd histogram is macd minus signal', ({ assert }) => {
+        const data = Array.from({ length: 40 }, (_, i) => 100 + Math.sin(i / 3) * 5)
+        const result = macd(data, 3, 6, 2)
+        const last = data.length - 1
+        assert.approximately(result.histogram[last], result.macd[last] - result.signal[last], 0.0001)
+    })
+})
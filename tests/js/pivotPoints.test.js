@@ -0,0 +1,43 @@
+import { test } from '@japa/runner'
+import { pivotPoints } from '../../dist/index.js'
+
+test.group('PivotPoints', (group) => {
+
+    let data
+
+    group.setup(() => {
+        data = [
+            { high: 12, low: 9, close: 11, open: 10, volume: 1000, date: "2025-01-02T14:00:00Z" },
+            { high: 13, low: 10, close: 10.5, open: 11, volume: 1000, date: "2025-01-02T15:00:00Z" },
+            { high: 11, low: 10, close: 10.8, open: 10.5, volume: 1000, date: "2025-01-03T14:00:00Z" },
+        ]
+    })
+
+    test('test daily levels come from the previous day', ({ assert }) => {
+        const result = pivotPoints(data)
+        assert.lengthOf(result, 3)
+        assert.isNull(result[0].levels)
+        assert.isNull(result[1].levels)
+        // Previous day: H 13, L 9, C 10.5.
+        assert.approximately(result[2].levels.pivot, 32.5 / 3, 0.0001)
+        assert.approximately(result[2].levels.r4, 32.5 / 3 + 12, 0.0001)
+        assert.approximately(result[1].developing.pivot, 32.5 / 3, 0.0001)
+    })
+
+    test('test bar timeframe uses the previous candle', ({ assert }) => {
+        const result = pivotPoints(data, { timeframe: 'bar', method: 'demark' })
+        // Bullish candle: X = 2H + L + C = 44.
+        assert.equal(result[1].levels.pivot, 11)
+        assert.equal(result[1].levels.r1, 13)
+        assert.isNull(result[1].levels.r2)
+    })
+
+    test('test invalid dates', ({ assert }) => {
+        try {
+            pivotPoints([{ ...data[0], date: "not a date" }])
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Invalid date: not a date')
+        }
+    })
+})
@@ -0,0 +1,53 @@
+import { test } from '@japa/runner'
+import { rollingStatistic } from '../../dist/index.js'
+
+test.group('RollingStatistics', () => {
+    test('test invalid period zero', ({ assert }) => {
+        try {
+            rollingStatistic([1.0, 2.0, 3.0], { period: 0 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Period must be greater than 0.')
+        }
+    })
+
+    test('test invalid quantile', ({ assert }) => {
+        try {
+            rollingStatistic([1.0, 2.0, 3.0], { statistic: 'quantile', period: 2, quantile: 1.5 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Quantile must be between 0 and 1.')
+        }
+    })
+
+    test('test std is the default', ({ assert }) => {
+        const result = rollingStatistic([1.0, 2.0, 4.0], { period: 2 })
+        assert.isTrue(isNaN(result[0]))
+        assert.deepEqual(result.slice(1), [0.5, 1])
+    })
+
+    test('test median and quantile', ({ assert }) => {
+        const data = [5.0, 1.0, 4.0, 2.0, 3.0, 3.0]
+        const median = rollingStatistic(data, { statistic: 'median', period: 4 })
+        assert.deepEqual(median.slice(3), [3, 2.5, 3])
+        const quantile = rollingStatistic(data, { statistic: 'quantile', period: 4, quantile: 0.25 })
+        assert.deepEqual(quantile.slice(3), [1.75, 1.75, 2.75])
+    })
+
+    test('test percent rank and z-score', ({ assert }) => {
+        const data = [5.0, 1.0, 4.0, 2.0, 3.0, 3.0]
+        const rank = rollingStatistic(data, { statistic: 'percent_rank', period: 3 })
+        assert.deepEqual(rank.slice(2), [50, 50, 50, 100])
+        const z = rollingStatistic([1.0, 2.0, 3.0], { statistic: 'z_score', period: 3 })
+        assert.approximately(z[2], 1 / Math.sqrt(2 / 3), 0.0001)
+    })
+
+    test('test min and max skip NaN values', ({ assert }) => {
+        const data = [3.0, NaN, 1.0, 4.0, 1.0]
+        const max = rollingStatistic(data, { statistic: 'max', period: 3 })
+        assert.isTrue(isNaN(max[1]) && isNaN(max[2]))
+        assert.deepEqual(max.slice(3), [4, 4])
+        const min = rollingStatistic(data, { statistic: 'min', period: 3 })
+        assert.deepEqual(min.slice(3), [1, 1])
+    })
+})
@@ -0,0 +1,50 @@
+import { test } from '@japa/runner'
+import { stochasticOscillator } from '../../dist/index.js'
+
+test.group('StochasticOscillator', (group) => {
+
+    let data
+
+    group.setup(() => {
+        data = [
+            { high: 10, low: 9, close: 9.5, open: 9.2, volume: 1000, date: "2025-01-01" },
+            { high: 11, low: 10, close: 10.5, open: 10, volume: 1000, date: "2025-01-02" },
+            { high: 12, low: 11, close: 12, open: 11, volume: 1000, date: "2025-01-03" },
+            { high: 12, low: 11, close: 11, open: 12, volume: 1000, date: "2025-01-04" },
+            { high: 12, low: 11, close: 11.2, open: 11, volume: 1000, date: "2025-01-05" },
+        ]
+    })
+
+    test('test %K window includes the current candle', ({ assert }) => {
+        const result = stochasticOscillator(data, { k_period: 2 })
+        assert.lengthOf(result.k, data.length)
+        assert.isTrue(isNaN(result.k[0]))
+        assert.equal(result.k[1], 75)
+        assert.equal(result.k[2], 100)
+    })
+
+    test('test %D is the moving average of %K', ({ assert }) => {
+        const result = stochasticOscillator(data, { k_period: 2, d_period: 2 })
+        assert.approximately(result.d[2], (75 + 100) / 2, 0.0001)
+    })
+
+    test('test flat window yields 50', ({ assert }) => {
+        const flat = data.map((candle) => ({ ...candle, high: 10, low: 10, close: 10 }))
+        const result = stochasticOscillator(flat, { k_period: 2 })
+        assert.equal(result.k[1], 50)
+    })
+
+    test('test overbought crossing events', ({ assert }) => {
+        const result = stochasticOscillator(data, { k_period: 2 })
+        assert.deepEqual(result.events[0], { index: 2, kind: 'enter_overbought', value: 100 })
+    })
+
+    test('test invalid levels', ({ assert }) => {
+        try {
+            stochasticOscillator(data, { overbought: 20, oversold: 80 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Oversold level must be lower than overbought level.')
+        }
+    })
+})
@@ -0,0 +1,35 @@
+import { test } from '@japa/runner'
+import { supertrend } from '../../dist/index.js'
+
+test.group('SuperTrend', (group) => {
+
+    let data
+
+    group.setup(() => {
+        data = [
+            { high: 11, low: 9, close: 10, open: 10, volume: 1000, date: "2025-01-01" },
+            { high: 11, low: 9, close: 10, open: 10, volume: 1000, date: "2025-01-02" },
+            { high: 11, low: 9, close: 10, open: 10, volume: 1000, date: "2025-01-03" },
+            { high: 16, low: 14, close: 15.5, open: 14, volume: 1000, date: "2025-01-04" },
+            { high: 17, low: 15, close: 16.5, open: 15.5, volume: 1000, date: "2025-01-05" },
+        ]
+    })
+
+    test('test supertrend flips to an uptrend on a breakout', ({ assert }) => {
+        const result = supertrend(data, { atr_period: 2, multiplier: 1 })
+        assert.deepEqual(result.direction, [0, -1, -1, 1, 1])
+        assert.deepEqual(result.flips, [3])
+        assert.equal(result.supertrend[1], 12)
+        assert.isTrue(isNaN(result.upper[3]))
+        assert.equal(result.lower[3], result.supertrend[3])
+    })
+
+    test('test invalid multiplier', ({ assert }) => {
+        try {
+            supertrend(data, { multiplier: 0 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Multiplier must be greater than 0.')
+        }
+    })
+})
@@ -0,0 +1,33 @@
+import { test } from '@japa/runner'
+import { supportResistanceZones } from '../../dist/index.js'
+
+test.group('SupportResistanceZones', (group) => {
+
+    let data
+
+    group.setup(() => {
+        const closes = [12, 10, 12, 14, 12, 10.1, 12, 14.05, 12, 11]
+        data = closes.map((close, index) => ({
+            high: close, low: close, close, open: close, volume: 100, date: `2025-01-${String(index + 1).padStart(2, '0')}`,
+        }))
+    })
+
+    test('test swing levels are merged into zones', ({ assert }) => {
+        const zones = supportResistanceZones(data, { left: 1, right: 1, tolerance: 1 })
+        assert.lengthOf(zones, 2)
+        const support = zones.find(zone => zone.role === 'support')
+        assert.equal(support.low, 10)
+        assert.equal(support.high, 10.1)
+        assert.equal(support.touches, 2)
+        assert.equal(support.first_touch, '2025-01-02')
+        assert.equal(support.last_touch, '2025-01-06')
+        assert.isFalse(support.flipped)
+        assert.approximately(support.distance, 0.9, 0.0001)
+    })
+
+    test('test max zones keeps the nearest', ({ assert }) => {
+        const zones = supportResistanceZones(data, { left: 1, right: 1, tolerance: 1, max_zones: 1 })
+        assert.lengthOf(zones, 1)
+        assert.equal(zones[0].role, 'support')
+    })
+})
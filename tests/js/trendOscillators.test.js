@@ -0,0 +1,40 @@
+import { test } from '@japa/runner'
+import { aroon, vortexIndicator } from '../../dist/index.js'
+
+test.group('TrendOscillators', (group) => {
+
+    let data
+
+    group.setup(() => {
+        data = [
+            { high: 1, low: 0.5, close: 0.8, open: 0.6, volume: 1000, date: "2025-01-01" },
+            { high: 3, low: 2.5, close: 2.8, open: 2.6, volume: 1000, date: "2025-01-02" },
+            { high: 2, low: 1, close: 1.5, open: 2, volume: 1000, date: "2025-01-03" },
+            { high: 2, low: 1.5, close: 1.8, open: 1.5, volume: 1000, date: "2025-01-04" },
+        ]
+    })
+
+    test('test aroon up, down and oscillator', ({ assert }) => {
+        const result = aroon(data, 2)
+        assert.isTrue(isNaN(result.up[1]))
+        assert.equal(result.up[2], 50)
+        assert.equal(result.down[2], 0)
+        assert.equal(result.oscillator[3], -50)
+        assert.deepEqual(result.events, [{ index: 3, kind: 'bearish' }])
+    })
+
+    test('test vortex lines are NaN during warm up', ({ assert }) => {
+        const result = vortexIndicator(data, 2)
+        assert.isTrue(isNaN(result.plus[1]))
+        assert.isFalse(isNaN(result.minus[2]))
+    })
+
+    test('test invalid period', ({ assert }) => {
+        try {
+            vortexIndicator(data, 0)
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Period must be greater than 0.')
+        }
+    })
+})
@@ -0,0 +1,50 @@
+import { test } from '@japa/runner'
+import { trendlines } from '../../dist/index.js'
+
+test.group('Trendlines', (group) => {
+
+    let data
+
+    group.setup(() => {
+        // Lows on i every 4 bars, highs on i + 4 two bars later.
+        data = Array.from({ length: 21 }, (_, i) => {
+            const close = i % 4 === 0 ? i : i % 4 === 2 ? i + 4 : i + 2
+            return { high: close, low: close, close, open: close, volume: 1000, date: `bar-${i}` }
+        })
+    })
+
+    test('test rising support and its channel', ({ assert }) => {
+        const result = trendlines(data, { left: 1, right: 1 })
+        const support = result.lines.find(line => line.kind === 'support')
+        assert.approximately(support.slope, 1, 0.0001)
+        assert.deepEqual(support.touch_indices, [4, 8, 12, 16])
+        assert.isNull(support.broken)
+
+        const channel = result.channels.find(channel => result.lines[channel.line].kind === 'support')
+        assert.approximately(channel.intercept, 4, 0.0001)
+    })
+
+    test('test nearby anchors still find the whole support', ({ assert }) => {
+        const result = trendlines(data, { left: 1, right: 1, max_anchor_gap: 1 })
+        const support = result.lines.find(line => line.kind === 'support')
+        assert.deepEqual(support.touch_indices, [4, 8, 12, 16])
+    })
+
+    test('test invalid maximum anchor gap', ({ assert }) => {
+        try {
+            trendlines(data, { max_anchor_gap: 0 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Maximum anchor gap must be greater than 0.')
+        }
+    })
+
+    test('test invalid minimum touches', ({ assert }) => {
+        try {
+            trendlines(data, { min_touches: 1 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Minimum touches must be at least 2.')
+        }
+    })
+})
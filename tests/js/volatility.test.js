@@ -0,0 +1,55 @@
+import { test } from '@japa/runner'
+import { historicalVolatility } from '../../dist/index.js'
+
+test.group('HistoricalVolatility', (group) => {
+
+    let data
+
+    group.setup(() => {
+        data = [100, 101, 100, 104, 100, 100.5].map((close, i) => ({
+            high: close + 1,
+            low: close - 1,
+            close,
+            open: close,
+            volume: 1000,
+            date: `2025-01-0${i + 1}`,
+        }))
+    })
+
+    test('test invalid period', ({ assert }) => {
+        try {
+            historicalVolatility(data, { period: 1 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Period must be greater than 1.')
+        }
+    })
+
+    test('test close to close is annualised from daily candles', ({ assert }) => {
+        const result = historicalVolatility(data, { period: 2, rank_period: 3 })
+        assert.equal(result.periods_per_year, 252)
+        assert.isTrue(isNaN(result.values[1]))
+        const returns = [Math.log(104 / 100), Math.log(100 / 104)]
+        const mean = (returns[0] + returns[1]) / 2
+        const variance = (returns[0] - mean) ** 2 + (returns[1] - mean) ** 2
+        assert.approximately(result.values[4], Math.sqrt(variance * 252), 0.0001)
+    })
+
+    test('test percentile and rank of the widest move', ({ assert }) => {
+        const result = historicalVolatility(data, { period: 2, rank_period: 3 })
+        assert.equal(result.percentile[4], 100)
+        assert.equal(result.rank[4], 100)
+        assert.isBelow(result.rank[5], 100)
+    })
+
+    test('test explicit annualisation and range estimators', ({ assert }) => {
+        const result = historicalVolatility(data, { estimator: 'parkinson', period: 2, periods_per_year: 365 })
+        assert.equal(result.periods_per_year, 365)
+        const ranges = [Math.log(101 / 99) ** 2, Math.log(102 / 100) ** 2]
+        const variance = (ranges[0] + ranges[1]) / 2 / (4 * Math.LN2)
+        assert.approximately(result.values[1], Math.sqrt(variance * 365), 0.0001)
+        const yangZhang = historicalVolatility(data, { estimator: 'yang_zhang', period: 2 })
+        assert.isTrue(isNaN(yangZhang.values[1]))
+        assert.isFalse(isNaN(yangZhang.values[2]))
+    })
+})
@@ -0,0 +1,77 @@
+import { test } from '@japa/runner'
+import {
+    onBalanceVolume,
+    chaikinMoneyFlow,
+    chaikinOscillator,
+    OnBalanceVolumeStream,
+} from '../../dist/index.js'
+
+test.group('VolumeFlow', (group) => {
+
+    let data
+
+    group.setup(() => {
+        data = [
+            { high: 12, low: 10, close: 11, open: 10.5, volume: 1000, date: "2025-01-01" },
+            { high: 13, low: 11, close: 12.5, open: 11, volume: 1500, date: "2025-01-02" },
+            { high: 13, low: 11.5, close: 12, open: 12.5, volume: 800, date: "2025-01-03" },
+            { high: 12.5, low: 11, close: 12, open: 12, volume: 900, date: "2025-01-04" },
+            { high: 14, low: 12, close: 13.8, open: 12, volume: 2000, date: "2025-01-05" },
+        ]
+    })
+
+    test('on balance volume adds volume on up closes and subtracts it on down closes', ({ assert }) => {
+        const result = onBalanceVolume(data)
+        assert.deepEqual(result.values, [0, 1500, 700, 700, 2700])
+        assert.isNull(result.signal)
+    })
+
+    test('signal line is returned when a signal period is given', ({ assert }) => {
+        const result = onBalanceVolume(data, 3)
+        assert.lengthOf(result.signal, data.length)
+        assert.isTrue(isNaN(result.signal[0]))
+        assert.isTrue(isNaN(result.signal[1]))
+        assert.approximately(result.signal[2], (0 + 1500 + 700) / 3, 0.0001)
+    })
+
+    test('signal line uses the requested moving average type', ({ assert }) => {
+        const result = onBalanceVolume(data, 3, 'sma')
+        assert.approximately(result.signal[3], (1500 + 700 + 700) / 3, 0.0001)
+    })
+
+    test('chaikin money flow is NaN during warm up', ({ assert }) => {
+        const result = chaikinMoneyFlow(data, 3)
+        assert.isTrue(isNaN(result.values[0]))
+        assert.isTrue(isNaN(result.values[1]))
+        assert.isFalse(isNaN(result.values[2]))
+    })
+
+    test('chaikin oscillator is NaN until the slow EMA is seeded', ({ assert }) => {
+        const result = chaikinOscillator(data, 2, 3)
+        assert.isTrue(isNaN(result.values[1]))
+        assert.isFalse(isNaN(result.values[2]))
+    })
+
+    test('chaikin oscillator rejects a fast period above the slow period', ({ assert }) => {
+        try {
+            chaikinOscillator(data, 10, 3)
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Fast period must be lower than slow period.')
+        }
+    })
+
+    test('streaming OBV matches the batch computation', ({ assert }) => {
+        const batch = onBalanceVolume(data, 3)
+        const stream = new OnBalanceVolumeStream(3)
+        data.forEach((candle, index) => {
+            const point = stream.next(candle.close, candle.volume)
+            assert.equal(point.value, batch.values[index])
+            if (isNaN(batch.signal[index])) {
+                assert.isTrue(isNaN(point.signal))
+            } else {
+                assert.approximately(point.signal, batch.signal[index], 0.0001)
+            }
+        })
+    })
+})
@@ -0,0 +1,100 @@
+import { test } from '@japa/runner'
+import {
+    moneyFlowIndex,
+    easeOfMovement,
+    forceIndex,
+    klingerVolumeOscillator,
+    negativeVolumeIndex,
+    positiveVolumeIndex,
+    percentageVolumeOscillator,
+} from '../../dist/index.js'
+
+test.group('VolumeOscillators', (group) => {
+
+    let data
+
+    group.setup(() => {
+        data = [
+            { high: 12, low: 10, close: 11, open: 10.5, volume: 1000, date: "2025-01-01" },
+            { high: 13, low: 11, close: 12.5, open: 11, volume: 1500, date: "2025-01-02" },
+            { high: 13, low: 11.5, close: 12, open: 12.5, volume: 800, date: "2025-01-03" },
+            { high: 12.5, low: 11, close: 12, open: 12, volume: 900, date: "2025-01-04" },
+            { high: 14, low: 12, close: 13.8, open: 12, volume: 2000, date: "2025-01-05" },
+        ]
+    })
+
+    test('money flow index weights the typical price moves by volume', ({ assert }) => {
+        const result = moneyFlowIndex(data, 2)
+        assert.lengthOf(result, data.length)
+        assert.isTrue(isNaN(result[1]))
+        assert.equal(result[2], 100)
+        assert.equal(result[3], 0)
+        const positive = (14 + 12 + 13.8) / 3 * 2000
+        const negative = (12.5 + 11 + 12) / 3 * 900
+        assert.approximately(result[4], 100 - 100 / (1 + positive / negative), 0.0001)
+    })
+
+    test('ease of movement divides the midpoint move by the box ratio', ({ assert }) => {
+        const result = easeOfMovement(data, 2, 1000)
+        assert.isTrue(isNaN(result.raw[0]))
+        // Midpoint up 1 on a range of 2 with 1.5 scaled volume.
+        assert.approximately(result.raw[1], 4 / 3, 0.0001)
+        assert.approximately(result.smoothed[2], (4 / 3 + 0.25 / (0.8 / 1.5)) / 2, 0.0001)
+    })
+
+    test('force index smooths the raw force with a SMA-seeded EMA', ({ assert }) => {
+        const result = forceIndex(data, 2)
+        assert.isTrue(isNaN(result.raw[0]))
+        assert.equal(result.raw[1], 2250)
+        assert.equal(result.raw[2], -400)
+        assert.isTrue(isNaN(result.smoothed[1]))
+        assert.approximately(result.smoothed[2], 925, 0.0001)
+        assert.approximately(result.smoothed[3], 925 - 925 * 2 / 3, 0.0001)
+    })
+
+    test('klinger volume oscillator waits for the slow EMA', ({ assert }) => {
+        const result = klingerVolumeOscillator(data, 2, 3, 2)
+        assert.lengthOf(result.kvo, data.length)
+        assert.isTrue(isNaN(result.kvo[2]))
+        assert.isFalse(isNaN(result.kvo[3]))
+        assert.isTrue(isNaN(result.signal[3]))
+        assert.isFalse(isNaN(result.signal[4]))
+    })
+
+    test('klinger volume oscillator rejects a fast period above the slow period', ({ assert }) => {
+        try {
+            klingerVolumeOscillator(data, 55, 34)
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Fast period must be lower than slow period.')
+        }
+    })
+
+    test('negative and positive volume indexes follow the close on their own bars', ({ assert }) => {
+        const nvi = negativeVolumeIndex(data)
+        assert.deepEqual(nvi.values.map(value => Math.round(value * 100) / 100), [1000, 1000, 960, 960, 960])
+        assert.isNull(nvi.signal)
+
+        const pvi = positiveVolumeIndex(data, 2, 'sma', 100)
+        assert.approximately(pvi.values[1], 100 * 12.5 / 11, 0.0001)
+        assert.approximately(pvi.values[4], 100 * 12.5 / 11 * 13.8 / 12, 0.0001)
+        assert.approximately(pvi.signal[1], (100 + pvi.values[1]) / 2, 0.0001)
+    })
+
+    test('percentage volume oscillator compares the fast and slow volume EMAs', ({ assert }) => {
+        const result = percentageVolumeOscillator(data, 2, 3, 2)
+        assert.isTrue(isNaN(result.pvo[1]))
+        // Fast EMA 1250 + (800 - 1250) * 2/3 = 950, slow SMA seed 1100.
+        assert.approximately(result.pvo[2], (950 - 1100) / 1100 * 100, 0.0001)
+        assert.approximately(result.histogram[3], result.pvo[3] - result.signal[3], 0.0001)
+    })
+
+    test('invalid period', ({ assert }) => {
+        try {
+            moneyFlowIndex(data, 0)
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Period must be greater than 0.')
+        }
+    })
+})
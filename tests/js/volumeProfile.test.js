@@ -0,0 +1,57 @@
+import { test } from '@japa/runner'
+import { volumeProfile, sessionVolumeProfile, rollingVolumeProfile } from '../../dist/index.js'
+
+test.group('VolumeProfile', (group) => {
+
+    let data
+
+    group.setup(() => {
+        data = [
+            { high: 11, low: 10, close: 10.5, open: 10.2, volume: 1000, date: "2025-01-02T09:00:00Z" },
+            { high: 12, low: 11, close: 11.5, open: 11, volume: 3000, date: "2025-01-02T10:00:00Z" },
+            { high: 12, low: 11, close: 11.8, open: 11.5, volume: 2000, date: "2025-01-02T11:00:00Z" },
+            { high: 13, low: 12, close: 12.5, open: 12, volume: 500, date: "2025-01-03T09:00:00Z" },
+            { high: 13, low: 11, close: 11.2, open: 12.5, volume: 1000, date: "2025-01-03T10:00:00Z" },
+        ]
+    })
+
+    test('point of control is the bin with the most volume', ({ assert }) => {
+        const result = volumeProfile(data, { bin_size: 1 })
+        assert.lengthOf(result.bins, 4)
+        assert.equal(result.point_of_control, 11.5)
+        assert.equal(result.total_volume, 7500)
+        assert.isAtMost(result.value_area_low, result.point_of_control)
+        assert.isAtLeast(result.value_area_high, result.point_of_control)
+    })
+
+    test('session mode returns one profile per day', ({ assert }) => {
+        const result = sessionVolumeProfile(data, { bin_count: 4 })
+        assert.lengthOf(result, 2)
+        assert.equal(result[0].start_index, 0)
+        assert.equal(result[0].end_index, 2)
+        assert.equal(result[1].start_date, "2025-01-03T09:00:00Z")
+    })
+
+    test('rolling mode is NaN until the window is full', ({ assert }) => {
+        const result = rollingVolumeProfile(data, 3, { bin_size: 1 })
+        assert.isTrue(isNaN(result.point_of_control[1]))
+        assert.equal(result.point_of_control[2], 11.5)
+    })
+
+    test('invalid value area throws an error', ({ assert }) => {
+        try {
+            volumeProfile(data, { value_area: 1.5 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Value area must be between 0 and 1.')
+        }
+    })
+    test('a bin size too small for the range throws an error', ({ assert }) => {
+        try {
+            volumeProfile(data, { bin_size: 0.00000001 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Too many price bins.')
+        }
+    })
+})
@@ -0,0 +1,38 @@
+import { test } from '@japa/runner'
+import { zigzag } from '../../dist/index.js'
+
+test.group('ZigZag', (group) => {
+
+    let data
+
+    group.setup(() => {
+        data = [10, 12, 11.5, 15, 11, 13, 9, 10, 14, 13.5].map((close, index) => ({
+            high: close, low: close, close, open: close, volume: 1000, date: `2025-01-${String(index + 1).padStart(2, '0')}`,
+        }))
+    })
+
+    test('test absolute threshold pivots and labels', ({ assert }) => {
+        const pivots = zigzag(data, { mode: 'absolute', threshold: 3 })
+        assert.deepEqual(pivots.map(pivot => pivot.index), [0, 3, 6, 8])
+        assert.deepEqual(pivots.map(pivot => pivot.kind), ['low', 'high', 'low', 'high'])
+        assert.equal(pivots[2].label, 'LL')
+        assert.equal(pivots[3].label, 'LH')
+        assert.equal(pivots[1].confirmed_at, 4)
+    })
+
+    test('test last leg is unconfirmed', ({ assert }) => {
+        const pivots = zigzag(data, { threshold: 20 })
+        const last = pivots[pivots.length - 1]
+        assert.isFalse(last.confirmed)
+        assert.isNotOk(last.confirmed_at)
+    })
+
+    test('test invalid threshold', ({ assert }) => {
+        try {
+            zigzag(data, { threshold: 0 })
+            assert.fail('Expected error was not thrown')
+        } catch (error) {
+            assert.equal(error.message, 'Threshold must be greater than 0.')
+        }
+    })
+})